@@ -0,0 +1,222 @@
+//! Derive macros for [ManyVecs](https://docs.rs/manyvecs).
+//!
+//! Use these through the `derive` feature of `manyvecs` instead of depending on this crate
+//! directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Generics, Ident, Index,
+    Member, Type, WhereClause,
+};
+
+/// Implements the operators and conversions of the wrapped vector for a single-field newtype,
+/// such as `struct ScreenPos(Vec2f32)`.
+///
+/// This generates:
+///
+/// - [`Add`], [`Sub`], [`Mul`], [`Div`], and [`Rem`] with another newtype, plus their assigning
+///   versions.
+/// - [`From`] conversions between the newtype and the wrapped vector.
+///
+/// The `vec2` attribute adds more:
+///
+/// - `#[vec2(scalar = f32)]` adds the same operators with a scalar of the given type.
+/// - `#[vec2(neg)]` adds [`Neg`].
+///
+/// Every operator is forwarded to the wrapped vector, which must implement it.
+///
+/// [`Add`]: std::ops::Add
+/// [`Sub`]: std::ops::Sub
+/// [`Mul`]: std::ops::Mul
+/// [`Div`]: std::ops::Div
+/// [`Rem`]: std::ops::Rem
+/// [`Neg`]: std::ops::Neg
+#[proc_macro_derive(Vec2Like, attributes(vec2))]
+pub fn derive_vec2_like(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options from the `vec2` attribute.
+#[derive(Default)]
+struct Options {
+    scalar: Option<Type>,
+    neg: bool,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("vec2")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("scalar") {
+                    options.scalar = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("neg") {
+                    options.neg = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `scalar = <type>` or `neg`"))
+                }
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = Options::parse(input)?;
+    let (member, inner) = wrapped_field(input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Each operator is only implemented when the wrapped vector supports it, which matters for
+    // generic newtypes
+    let bound = |bound: TokenStream2| with_bound(&input.generics, quote!(#inner: #bound));
+
+    // Builds `Self(value)` or `Self { field: value }`
+    let wrap = |value: TokenStream2| match &member {
+        Member::Unnamed(_) => quote!(#name(#value)),
+        Member::Named(field) => quote!(#name { #field: #value }),
+    };
+
+    let mut tokens = TokenStream2::new();
+
+    for (op, method, assign_op, assign_method) in OPERATORS {
+        let (op, method) = (ident(op), ident(method));
+        let (assign_op, assign_method) = (ident(assign_op), ident(assign_method));
+        let result = wrap(quote!(::core::ops::#op::#method(self.#member, rhs.#member)));
+        let op_bound = bound(quote!(::core::ops::#op<Output = #inner>));
+        let assign_bound = bound(quote!(::core::ops::#assign_op));
+
+        tokens.extend(quote! {
+            impl #impl_generics ::core::ops::#op for #name #ty_generics #op_bound {
+                type Output = Self;
+
+                fn #method(self, rhs: Self) -> Self {
+                    #result
+                }
+            }
+
+            impl #impl_generics ::core::ops::#assign_op for #name #ty_generics #assign_bound {
+                fn #assign_method(&mut self, rhs: Self) {
+                    ::core::ops::#assign_op::#assign_method(&mut self.#member, rhs.#member);
+                }
+            }
+        });
+
+        if let Some(scalar) = &options.scalar {
+            let result = wrap(quote!(::core::ops::#op::#method(self.#member, rhs)));
+            let op_bound = bound(quote!(::core::ops::#op<#scalar, Output = #inner>));
+            let assign_bound = bound(quote!(::core::ops::#assign_op<#scalar>));
+
+            tokens.extend(quote! {
+                impl #impl_generics ::core::ops::#op<#scalar> for #name #ty_generics #op_bound {
+                    type Output = Self;
+
+                    fn #method(self, rhs: #scalar) -> Self {
+                        #result
+                    }
+                }
+
+                impl #impl_generics ::core::ops::#assign_op<#scalar> for #name #ty_generics #assign_bound {
+                    fn #assign_method(&mut self, rhs: #scalar) {
+                        ::core::ops::#assign_op::#assign_method(&mut self.#member, rhs);
+                    }
+                }
+            });
+        }
+    }
+
+    if options.neg {
+        let result = wrap(quote!(::core::ops::Neg::neg(self.#member)));
+        let neg_bound = bound(quote!(::core::ops::Neg<Output = #inner>));
+
+        tokens.extend(quote! {
+            impl #impl_generics ::core::ops::Neg for #name #ty_generics #neg_bound {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    #result
+                }
+            }
+        });
+    }
+
+    let wrapped = wrap(quote!(v));
+
+    tokens.extend(quote! {
+        impl #impl_generics ::core::convert::From<#inner> for #name #ty_generics #where_clause {
+            fn from(v: #inner) -> Self {
+                #wrapped
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #inner #where_clause {
+            fn from(v: #name #ty_generics) -> Self {
+                v.#member
+            }
+        }
+    });
+
+    Ok(tokens)
+}
+
+/// The operator traits and methods to forward, along with their assigning versions.
+const OPERATORS: [(&str, &str, &str, &str); 5] = [
+    ("Add", "add", "AddAssign", "add_assign"),
+    ("Sub", "sub", "SubAssign", "sub_assign"),
+    ("Mul", "mul", "MulAssign", "mul_assign"),
+    ("Div", "div", "DivAssign", "div_assign"),
+    ("Rem", "rem", "RemAssign", "rem_assign"),
+];
+
+/// Returns the where clause of `generics` with one more predicate.
+fn with_bound(generics: &Generics, predicate: TokenStream2) -> WhereClause {
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| parse_quote!(where));
+    where_clause.predicates.push(parse_quote!(#predicate));
+    where_clause
+}
+
+fn ident(name: &str) -> Ident {
+    Ident::new(name, Span::call_site())
+}
+
+/// Returns how to access the only field of a struct, and its type.
+fn wrapped_field(input: &DeriveInput) -> syn::Result<(Member, &Type)> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Vec2Like can only be derived for structs",
+        ));
+    };
+
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Vec2Like can only be derived for structs with exactly one field",
+            ))
+        }
+    };
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+
+    Ok((member, &field.ty))
+}
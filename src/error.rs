@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error returned when converting a slice that doesn't have exactly two elements into a
+/// vector.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SliceLengthError {
+    /// The length of the slice that was given.
+    pub found: usize,
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Expected a slice of length 2, but instead found length '{}'",
+            self.found
+        )
+    }
+}
+
+impl Error for SliceLengthError {}
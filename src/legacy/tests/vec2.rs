@@ -17,6 +17,17 @@ fn x_and_y() {
     assert_eq!(*v.y(), 6.8);
 }
 
+#[test]
+fn const_new() {
+    const V: Vec2<u8> = Vec2::new(1, 2);
+    const A: [u8; 2] = V.to_array();
+    const T: Vec2<u8> = Vec2::from_tuple((A[1], A[0]));
+
+    assert_eq!(*T.x(), 2);
+    assert_eq!(T.to_tuple(), (2, 1));
+    assert_eq!(Vec2::from_array(A), V);
+}
+
 #[test]
 fn mut_x_and_y() {
     let mut v = Vec2::new(6, 8);
@@ -43,7 +54,7 @@ fn types() {
     Vec2::<isize>::new(-2, 4);
 
     Vec2::<f32>::new(5.32, -8.9);
-    Vec2::<f64>::new(3.14159, 8.0);
+    Vec2::<f64>::new(1.25, 8.0);
 }
 
 #[test]
@@ -73,6 +84,13 @@ fn mag() {
     assert_eq!(Vec2::new(6.0, 8.0).mag(), 10.0);
 }
 
+#[test]
+fn mag_precise() {
+    assert_eq!(Vec2::new(6.0, 8.0).mag_precise(), 10.0);
+    assert_eq!(Vec2::new(3e30, 4e30).mag_precise(), 5e30);
+    assert_eq!(Vec2::new(3e30, 4e30).mag(), f32::INFINITY);
+}
+
 #[test]
 fn norm() {
     // Difficult to test with exact float, this works for now
@@ -81,12 +99,12 @@ fn norm() {
 
 #[test]
 fn floor() {
-    assert_eq!(Vec2::new(3.14159, 2.0).floor(), Vec2::new(3.0, 2.0));
+    assert_eq!(Vec2::new(3.25, 2.0).floor(), Vec2::new(3.0, 2.0));
 }
 
 #[test]
 fn ceil() {
-    assert_eq!(Vec2::new(3.14159, 6.0).ceil(), Vec2::new(4.0, 6.0));
+    assert_eq!(Vec2::new(3.25, 6.0).ceil(), Vec2::new(4.0, 6.0));
 }
 
 //////////////////
@@ -120,6 +138,22 @@ fn perp() {
     assert_eq!(v.perp(), Vec2::<isize>::new(-6, 4));
 }
 
+#[test]
+fn manhattan() {
+    let v = Vec2::new(-3, 4);
+
+    assert_eq!(v.manhattan_length(), 7);
+    assert_eq!(v.manhattan_distance(&Vec2::new(2, 2)), 7);
+}
+
+#[test]
+fn chebyshev() {
+    let v = Vec2::new(-3, 4);
+
+    assert_eq!(v.chebyshev_length(), 4);
+    assert_eq!(v.chebyshev_distance(&Vec2::new(2, 2)), 5);
+}
+
 ////////////////
 // Operators //
 //////////////
@@ -194,6 +228,17 @@ fn rem() {
     assert_eq!(v1, out);
 }
 
+#[test]
+fn scalar_lhs() {
+    let v = Vec2::new(2.0, 4.0);
+
+    assert_eq!(2.0 * v, v * 2.0);
+    assert_eq!(1.0 + v, Vec2::new(3.0, 5.0));
+    assert_eq!(8.0 - v, Vec2::new(6.0, 4.0));
+    assert_eq!(8.0 / v, Vec2::new(4.0, 2.0));
+    assert_eq!(5u8 % Vec2::new(2, 3), Vec2::new(1, 2));
+}
+
 #[test]
 fn neg() {
     let v = Vec2::<isize>::new(5, -8);
@@ -241,6 +286,21 @@ fn conv_array() {
     assert_eq!(a1, a2);
 }
 
+#[test]
+fn conv_slice() {
+    let components = [1, 2, 3];
+
+    assert_eq!(Vec2::try_from(&components[..2]), Ok(Vec2::new(1, 2)));
+    assert_eq!(
+        Vec2::<i32>::try_from(&components[..]),
+        Err(crate::SliceLengthError { found: 3 })
+    );
+    assert_eq!(
+        crate::SliceLengthError { found: 0 }.to_string(),
+        "Expected a slice of length 2, but instead found length '0'"
+    );
+}
+
 #[test]
 fn conv_std_vec() {
     let std_vec1: Vec<u8> = vec![4, 19];
@@ -260,6 +320,186 @@ fn conv_std_vec_err() {
     Vec2::try_from(std_vec).unwrap();
 }
 
+//////////////////
+// Fixed Point //
+////////////////
+
+#[cfg(feature = "fixed")]
+#[test]
+fn fixed_point() {
+    use fixed::types::I16F16;
+
+    fn fx(n: f64) -> I16F16 {
+        I16F16::from_num(n)
+    }
+
+    let a = Vec2::new(fx(3.0), fx(-4.0));
+    let b = Vec2::new(fx(0.5), fx(1.0));
+
+    assert_eq!(a.mag2(), 25);
+    assert_eq!(a + b, Vec2::new(fx(3.5), fx(-3.0)));
+    assert_eq!(a * fx(0.5), Vec2::new(fx(1.5), fx(-2.0)));
+    assert_eq!(a.max_v(&b), Vec2::new(fx(3.0), fx(1.0)));
+    assert_eq!(a.clamp(fx(-1.0), fx(1.0)), Vec2::new(fx(1.0), fx(-1.0)));
+    assert_eq!(a.manhattan_distance(&b), 7.5);
+    assert_eq!(-a, Vec2::new(fx(-3.0), fx(4.0)));
+}
+
+////////////////
+// Rationals //
+//////////////
+
+#[test]
+fn rational() {
+    use num_rational::Ratio;
+
+    let r = Ratio::new;
+    let a = Vec2::new(r(1, 3), r(-1, 2));
+    let b = Vec2::new(r(2, 3), r(1, 4));
+
+    // Results are exact, with no rounding
+    assert_eq!(a + b, Vec2::new(r(1, 1), r(-1, 4)));
+    assert_eq!(a * b, Vec2::new(r(2, 9), r(-1, 8)));
+    assert_eq!(a / b, Vec2::new(r(1, 2), r(-2, 1)));
+    assert_eq!(a * r(3, 1), Vec2::new(r(1, 1), r(-3, 2)));
+    assert_eq!(a.mag2(), r(13, 36));
+
+    assert_eq!(a.max_v(&b), b);
+    assert_eq!(a.clamp(r(0, 1), r(1, 4)), Vec2::new(r(1, 4), r(0, 1)));
+    assert_eq!(a.perp(), Vec2::new(r(1, 2), r(1, 3)));
+    assert_eq!(a.manhattan_distance(&b), r(13, 12));
+    assert_eq!(a.chebyshev_length(), r(1, 2));
+
+    assert_eq!(format!("{}", a), "Vec2(1/3, -1/2)");
+    assert_eq!(<[Ratio<i64>; 2]>::from(a), [r(1, 3), r(-1, 2)]);
+    assert_eq!(Vec2::<Ratio<i64>>::default(), Vec2::new(r(0, 1), r(0, 1)));
+}
+
+////////////////
+// Big Ints //
+//////////////
+
+#[test]
+fn big_int() {
+    use num_bigint::BigInt;
+
+    let big = |n: i64| BigInt::from(n) * BigInt::from(u64::MAX);
+    let a = Vec2::new(big(2), big(-3));
+    let b = Vec2::new(big(1), big(1));
+
+    assert_eq!(a.clone() + b.clone(), Vec2::new(big(3), big(-2)));
+    assert_eq!(a.clone() * BigInt::from(2), Vec2::new(big(4), big(-6)));
+    assert_eq!(
+        a.mag2(),
+        BigInt::from(13) * BigInt::from(u64::MAX) * BigInt::from(u64::MAX)
+    );
+    assert_eq!(a.max_v(&b), Vec2::new(big(2), big(1)));
+    assert_eq!(a.perp(), Vec2::new(big(3), big(2)));
+    assert_eq!(a.manhattan_distance(&b), big(5));
+
+    let mut c = a.clone();
+    c += BigInt::from(1);
+    c -= b;
+    assert_eq!(c, a + BigInt::from(1) - Vec2::new(big(1), big(1)));
+
+    let [x, y] = <[BigInt; 2]>::from(c.clone());
+    assert_eq!(Vec2::from((x, y)), c);
+    assert!(Vec2::try_from(vec![big(1)]).is_err());
+    assert_eq!(
+        Vec2::try_from(vec![big(1), big(2)]),
+        Ok(Vec2::new(big(1), big(2)))
+    );
+}
+
+//////////////////////
+// Wrapping Numbers //
+////////////////////
+
+#[test]
+fn wrapping() {
+    use std::num::Wrapping;
+
+    let w = Wrapping::<u32>;
+    let a = Vec2::new(w(u32::MAX), w(5));
+    let b = Vec2::new(w(2), w(10));
+
+    // Arithmetic wraps around instead of overflowing
+    assert_eq!(a + b, Vec2::new(w(1), w(15)));
+    assert_eq!(b - a, Vec2::new(w(3), w(5)));
+    assert_eq!(a * w(2), Vec2::new(w(u32::MAX - 1), w(10)));
+    assert_eq!(-b, Vec2::new(w(u32::MAX - 1), w(u32::MAX - 9)));
+    assert_eq!(a.mag2(), w(26));
+
+    let mut c = a;
+    c += b;
+    c *= w(3);
+    assert_eq!(c, Vec2::new(w(3), w(45)));
+
+    assert_eq!(a.max_v(&b), Vec2::new(w(u32::MAX), w(10)));
+    assert_eq!(format!("{}", b), "Vec2(2, 10)");
+    assert_eq!(<(Wrapping<u32>, Wrapping<u32>)>::from(b), (w(2), w(10)));
+    assert_eq!(Vec2::<Wrapping<u32>>::default(), Vec2::new(w(0), w(0)));
+
+    // Signed wrapping numbers also get the signed methods
+    let s = Vec2::new(Wrapping(-100i8), Wrapping(100));
+    assert_eq!(s.perp(), Vec2::new(Wrapping(-100), Wrapping(-100)));
+    assert_eq!(s.manhattan_length(), Wrapping(-56));
+}
+
+////////////////////
+// Vector2 Trait //
+//////////////////
+
+fn sum_of_squares<V: crate::Vector2<S>, S: Copy>(v: V) -> V {
+    v * v
+}
+
+#[test]
+fn vector2_trait() {
+    let v: Vec2<i32> = crate::Vector2::new(3, -4);
+
+    assert_eq!(crate::Vector2::x(&v), 3);
+    assert_eq!(crate::Vector2::mag2(&v), 25);
+    assert_eq!(crate::Vector2::to_array(&v), [3, -4]);
+    assert_eq!(sum_of_squares(v), Vec2::new(9, 16));
+    assert_eq!(
+        <Vec2<f32> as crate::Vector2<f32>>::splat(0.5),
+        Vec2::new(0.5, 0.5)
+    );
+}
+
+///////////////////
+// Zero and One //
+/////////////////
+
+#[test]
+fn zero() {
+    use num_traits::Zero;
+
+    let v: Vec2<i32> = Zero::zero();
+
+    assert_eq!(v, Vec2::new(0, 0));
+    assert!(v.is_zero());
+    assert!(!Vec2::new(0.0, 0.5).is_zero());
+
+    // Works as an accumulator in generic code
+    let sum = [Vec2::new(1, 2), Vec2::new(3, 4)]
+        .into_iter()
+        .fold(Vec2::zero(), |a, b| a + b);
+    assert_eq!(sum, Vec2::new(4, 6));
+}
+
+#[test]
+fn one() {
+    use num_traits::One;
+
+    let v = Vec2::new(2.5, -3.0);
+
+    assert_eq!(Vec2::<u8>::one(), Vec2::new(1, 1));
+    assert_eq!(v * Vec2::one(), v);
+    assert!(Vec2::<f64>::one().is_one());
+}
+
 //////////////////////
 // Display + Debug //
 ////////////////////
@@ -271,12 +511,22 @@ fn display() {
     assert_eq!(format!("{}", v), "Vec2(4.1, 8.8)");
 }
 
+#[test]
+fn display_flags() {
+    let v = Vec2::new(4.125, -8.0);
+
+    assert_eq!(format!("{:.2}", v), "Vec2(4.12, -8.00)");
+    assert_eq!(format!("{:>6}", v), "Vec2( 4.125,     -8)");
+    assert_eq!(format!("{:#}", v), "4.125, -8");
+    assert_eq!(format!("{:#.1}", v), "4.1, -8.0");
+}
+
 #[test]
 fn debug() {
     let v = Vec2::new(7, 3);
 
     // Output may change (because derived), but it
     // should at least still work
-    format!("{:?}", v);
-    format!("{:#?}", v);
+    let _ = format!("{:?}", v);
+    let _ = format!("{:#?}", v);
 }
@@ -1,4 +1,4 @@
-use num_traits::{real::Real, sign::Signed, Num};
+use num_traits::{real::Real, sign::Signed, Num, One, Zero};
 
 use std::cmp::PartialEq;
 use std::fmt;
@@ -18,6 +18,17 @@ use std::ops::{
 /// - [Vec2::mag]
 /// - [Vec2::max], [Vec2::min], and [Vec2::clamp]
 ///
+/// # Scalar Types
+///
+/// Any [Num] type that is [Clone] can be used, not just primitives,
+/// so big numbers like `num_bigint::BigInt` work too. [Copy] types
+/// additionally get `const` conversions and are copied instead of
+/// cloned.
+///
+/// Exact types like `num_rational::Ratio<i64>` support everything
+/// except the methods that need a square root or rounding, such as
+/// [Vec2::mag] and [Vec2::floor], which require [Real].
+///
 /// # Example
 ///
 /// ```
@@ -27,8 +38,7 @@ use std::ops::{
 #[derive(Copy, Clone, Debug)]
 pub struct Vec2<T>
 where
-    // There should be a better way than requiring Copy
-    T: Num + Copy,
+    T: Num + Clone,
 {
     x: T,
     y: T,
@@ -40,15 +50,33 @@ where
 
 impl<T> Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     /// Creates a new [Vec2].
-    pub fn new(x: T, y: T) -> Vec2<T> {
+    ///
+    /// This is a `const fn`, so it can be used for constants.
+    ///
+    /// ```
+    /// # use manyvecs::legacy::Vec2;
+    /// const ORIGIN: Vec2<i32> = Vec2::new(0, 0);
+    /// const X: i32 = *ORIGIN.x();
+    /// ```
+    pub const fn new(x: T, y: T) -> Vec2<T> {
         Vec2 { x, y }
     }
 
+    /// Consumes the vector, returning an array of `[x, y]`.
+    pub fn into_array(self) -> [T; 2] {
+        [self.x, self.y]
+    }
+
+    /// Consumes the vector, returning a tuple of `(x, y)`.
+    pub fn into_tuple(self) -> (T, T) {
+        (self.x, self.y)
+    }
+
     /// Returns a reference of X.
-    pub fn x(&self) -> &T {
+    pub const fn x(&self) -> &T {
         &self.x
     }
 
@@ -58,7 +86,7 @@ where
     }
 
     /// Returns a reference of Y.
-    pub fn y(&self) -> &T {
+    pub const fn y(&self) -> &T {
         &self.y
     }
 
@@ -69,7 +97,62 @@ where
 
     /// Returns the X squared plus Y squared. (`x^2 + y^2`.)
     pub fn mag2(&self) -> T {
-        self.x * self.x + self.y * self.y
+        let (x, y) = (self.x.clone(), self.y.clone());
+        x.clone() * x + y.clone() * y
+    }
+}
+
+///////////////////
+// Copy Numbers //
+/////////////////
+
+impl<T> Vec2<T>
+where
+    T: Num + Copy,
+{
+    /// Creates a new [Vec2] from an array of `[x, y]`.
+    pub const fn from_array(v: [T; 2]) -> Vec2<T> {
+        Vec2::new(v[0], v[1])
+    }
+
+    /// Returns the vector as an array of `[x, y]`.
+    pub const fn to_array(&self) -> [T; 2] {
+        [self.x, self.y]
+    }
+
+    /// Creates a new [Vec2] from a tuple of `(x, y)`.
+    pub const fn from_tuple(v: (T, T)) -> Vec2<T> {
+        Vec2::new(v.0, v.1)
+    }
+
+    /// Returns the vector as a tuple of `(x, y)`.
+    pub const fn to_tuple(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+////////////////////
+// Vector2 Trait //
+//////////////////
+
+impl<T> crate::Vector2<T> for Vec2<T>
+where
+    T: Num + Copy + fmt::Debug,
+{
+    fn new(x: T, y: T) -> Self {
+        Vec2::new(x, y)
+    }
+
+    fn x(&self) -> T {
+        self.x
+    }
+
+    fn y(&self) -> T {
+        self.y
+    }
+
+    fn mag2(&self) -> T {
+        Vec2::mag2(self)
     }
 }
 
@@ -89,6 +172,13 @@ where
         self.mag2().sqrt()
     }
 
+    /// Returns the magnitude of X and Y, like [Vec2::mag], but
+    /// without overflowing for huge values or losing precision
+    /// for tiny ones.
+    pub fn mag_precise(&self) -> T {
+        self.x.hypot(self.y)
+    }
+
     /// Normalizes the values of the vector.
     ///
     /// Returns a new [Vec2] as if the [magnitude](Vec2.mag)
@@ -126,7 +216,7 @@ where
 
 impl<T> Vec2<T>
 where
-    T: Num + Copy + PartialOrd,
+    T: Num + Clone + PartialOrd,
 {
     /// Finds the greater value of X and Y compared to
     /// another number.
@@ -134,10 +224,10 @@ where
     where
         Self: Sized,
     {
-        let x = if self.x > v { self.x } else { v };
-        let y = if self.y > v { self.y } else { v };
+        let x = if self.x > v { &self.x } else { &v };
+        let y = if self.y > v { &self.y } else { &v };
 
-        Self::new(x, y)
+        Self::new(x.clone(), y.clone())
     }
 
     /// Finds the greater value of X and Y compared to
@@ -147,10 +237,10 @@ where
     where
         Self: Sized,
     {
-        let x = if self.x > v.x { self.x } else { v.x };
-        let y = if self.y > v.y { self.y } else { v.y };
+        let x = if self.x > v.x { &self.x } else { &v.x };
+        let y = if self.y > v.y { &self.y } else { &v.y };
 
-        Self::new(x, y)
+        Self::new(x.clone(), y.clone())
     }
 
     /// Finds the lesser value of X and Y compared to
@@ -159,10 +249,10 @@ where
     where
         Self: Sized,
     {
-        let x = if self.x < v { self.x } else { v };
-        let y = if self.y < v { self.y } else { v };
+        let x = if self.x < v { &self.x } else { &v };
+        let y = if self.y < v { &self.y } else { &v };
 
-        Self::new(x, y)
+        Self::new(x.clone(), y.clone())
     }
 
     /// Finds the lesser value of X and Y compared to
@@ -172,10 +262,10 @@ where
     where
         Self: Sized,
     {
-        let x = if self.x < v.x { self.x } else { v.x };
-        let y = if self.y < v.y { self.y } else { v.y };
+        let x = if self.x < v.x { &self.x } else { &v.x };
+        let y = if self.y < v.y { &self.y } else { &v.y };
 
-        Self::new(x, y)
+        Self::new(x.clone(), y.clone())
     }
 
     /// Constrains X and Y to be between min and max.
@@ -202,14 +292,49 @@ where
 
 impl<T> Vec2<T>
 where
-    T: Num + Copy + Signed,
+    T: Num + Clone + Signed,
 {
     /// Finds the perpendicular slope of X and Y.
     pub fn perp(&self) -> Self
     where
         Self: Sized,
     {
-        Self::new(-self.y, self.x)
+        Self::new(-self.y.clone(), self.x.clone())
+    }
+
+    /// Finds the Manhattan (taxicab) length of X and Y.
+    /// (`|x| + |y|`.)
+    pub fn manhattan_length(&self) -> T {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Finds the Manhattan (taxicab) distance to another
+    /// [Vec2].
+    pub fn manhattan_distance(&self, v: &Self) -> T {
+        (self.clone() - v.clone()).manhattan_length()
+    }
+
+    /// Finds the Chebyshev length of X and Y, which is the
+    /// greater of `|x|` and `|y|`.
+    pub fn chebyshev_length(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let (x, y) = (self.x.abs(), self.y.abs());
+
+        if x > y {
+            x
+        } else {
+            y
+        }
+    }
+
+    /// Finds the Chebyshev distance to another [Vec2].
+    pub fn chebyshev_distance(&self, v: &Self) -> T
+    where
+        T: PartialOrd,
+    {
+        (self.clone() - v.clone()).chebyshev_length()
     }
 }
 
@@ -220,7 +345,7 @@ where
 // Addition
 impl<T> Add for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -231,18 +356,18 @@ where
 
 impl<T> Add<T> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
     fn add(self, rhs: T) -> Self {
-        Self::new(self.x + rhs, self.y + rhs)
+        Self::new(self.x + rhs.clone(), self.y + rhs)
     }
 }
 
 impl<T> AddAssign for Vec2<T>
 where
-    T: Num + Copy + AddAssign,
+    T: Num + Clone + AddAssign,
 {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
@@ -252,10 +377,10 @@ where
 
 impl<T> AddAssign<T> for Vec2<T>
 where
-    T: Num + Copy + AddAssign,
+    T: Num + Clone + AddAssign,
 {
     fn add_assign(&mut self, rhs: T) {
-        self.x += rhs;
+        self.x += rhs.clone();
         self.y += rhs;
     }
 }
@@ -263,7 +388,7 @@ where
 // Subtraction
 impl<T> Sub for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -274,18 +399,18 @@ where
 
 impl<T> Sub<T> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
     fn sub(self, rhs: T) -> Self {
-        Self::new(self.x - rhs, self.y - rhs)
+        Self::new(self.x - rhs.clone(), self.y - rhs)
     }
 }
 
 impl<T> SubAssign for Vec2<T>
 where
-    T: Num + Copy + SubAssign,
+    T: Num + Clone + SubAssign,
 {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
@@ -295,10 +420,10 @@ where
 
 impl<T> SubAssign<T> for Vec2<T>
 where
-    T: Num + Copy + SubAssign,
+    T: Num + Clone + SubAssign,
 {
     fn sub_assign(&mut self, rhs: T) {
-        self.x -= rhs;
+        self.x -= rhs.clone();
         self.y -= rhs;
     }
 }
@@ -306,7 +431,7 @@ where
 // Multiplications
 impl<T> Mul for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -317,18 +442,18 @@ where
 
 impl<T> Mul<T> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self {
-        Self::new(self.x * rhs, self.y * rhs)
+        Self::new(self.x * rhs.clone(), self.y * rhs)
     }
 }
 
 impl<T> MulAssign for Vec2<T>
 where
-    T: Num + Copy + MulAssign,
+    T: Num + Clone + MulAssign,
 {
     fn mul_assign(&mut self, rhs: Self) {
         self.x *= rhs.x;
@@ -338,10 +463,10 @@ where
 
 impl<T> MulAssign<T> for Vec2<T>
 where
-    T: Num + Copy + MulAssign,
+    T: Num + Clone + MulAssign,
 {
     fn mul_assign(&mut self, rhs: T) {
-        self.x *= rhs;
+        self.x *= rhs.clone();
         self.y *= rhs;
     }
 }
@@ -349,7 +474,7 @@ where
 // Division
 impl<T> Div for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -360,18 +485,18 @@ where
 
 impl<T> Div<T> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
     fn div(self, rhs: T) -> Self {
-        Self::new(self.x / rhs, self.y / rhs)
+        Self::new(self.x / rhs.clone(), self.y / rhs)
     }
 }
 
 impl<T> DivAssign for Vec2<T>
 where
-    T: Num + Copy + DivAssign,
+    T: Num + Clone + DivAssign,
 {
     fn div_assign(&mut self, rhs: Self) {
         self.x /= rhs.x;
@@ -381,10 +506,10 @@ where
 
 impl<T> DivAssign<T> for Vec2<T>
 where
-    T: Num + Copy + DivAssign,
+    T: Num + Clone + DivAssign,
 {
     fn div_assign(&mut self, rhs: T) {
-        self.x /= rhs;
+        self.x /= rhs.clone();
         self.y /= rhs;
     }
 }
@@ -392,7 +517,7 @@ where
 // Remainder / Modulus
 impl<T> Rem for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -403,18 +528,18 @@ where
 
 impl<T> Rem<T> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Output = Self;
 
     fn rem(self, rhs: T) -> Self {
-        Self::new(self.x % rhs, self.y % rhs)
+        Self::new(self.x % rhs.clone(), self.y % rhs)
     }
 }
 
 impl<T> RemAssign for Vec2<T>
 where
-    T: Num + Copy + RemAssign,
+    T: Num + Clone + RemAssign,
 {
     fn rem_assign(&mut self, rhs: Self) {
         self.x %= rhs.x;
@@ -424,18 +549,19 @@ where
 
 impl<T> RemAssign<T> for Vec2<T>
 where
-    T: Num + Copy + RemAssign,
+    T: Num + Clone + RemAssign,
 {
     fn rem_assign(&mut self, rhs: T) {
-        self.x %= rhs;
+        self.x %= rhs.clone();
         self.y %= rhs;
     }
 }
 
 // Negating the Value
+// Only needs negation, not `Signed`, so wrapping unsigned numbers can be negated
 impl<T> Neg for Vec2<T>
 where
-    T: Num + Copy + Signed,
+    T: Num + Clone + Neg<Output = T>,
 {
     type Output = Self;
 
@@ -445,13 +571,38 @@ where
     }
 }
 
+// Scalar on the Left
+// Rust's orphan rules only allow these for concrete scalars, so they are limited to primitives
+macro_rules! impl_scalar_lhs {
+    ($($type_:ty),*) => {
+        $(
+            impl_scalar_lhs!(@op $type_, Add, add, +);
+            impl_scalar_lhs!(@op $type_, Sub, sub, -);
+            impl_scalar_lhs!(@op $type_, Mul, mul, *);
+            impl_scalar_lhs!(@op $type_, Div, div, /);
+            impl_scalar_lhs!(@op $type_, Rem, rem, %);
+        )*
+    };
+    (@op $type_:ty, $trait_name:ident, $trait_fn:ident, $op:tt) => {
+        impl $trait_name<Vec2<$type_>> for $type_ {
+            type Output = Vec2<$type_>;
+
+            fn $trait_fn(self, rhs: Vec2<$type_>) -> Vec2<$type_> {
+                Vec2::new(self $op rhs.x, self $op rhs.y)
+            }
+        }
+    };
+}
+
+impl_scalar_lhs!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 //////////////////
 // Equivalence //
 ////////////////
 
 impl<T> PartialEq for Vec2<T>
 where
-    T: Num + Copy + PartialEq,
+    T: Num + Clone + PartialEq,
 {
     fn eq(&self, rhs: &Self) -> bool {
         self.x == rhs.x && self.y == rhs.y
@@ -465,62 +616,79 @@ where
 // Tuple
 impl<T> From<(T, T)> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     fn from(v: (T, T)) -> Vec2<T> {
-        Vec2::new(v.0, v.1)
+        let (x, y) = v;
+        Vec2::new(x, y)
     }
 }
 
 impl<T> From<Vec2<T>> for (T, T)
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     fn from(v: Vec2<T>) -> (T, T) {
-        (v.x, v.y)
+        v.into_tuple()
     }
 }
 
 // Array
 impl<T> From<[T; 2]> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     fn from(v: [T; 2]) -> Vec2<T> {
-        Vec2::new(v[0], v[1])
+        let [x, y] = v;
+        Vec2::new(x, y)
     }
 }
 
 impl<T> From<Vec2<T>> for [T; 2]
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     fn from(v: Vec2<T>) -> [T; 2] {
-        [v.x, v.y]
+        v.into_array()
     }
 }
 
 // Vec
 impl<T> TryFrom<Vec<T>> for Vec2<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     type Error = String;
 
     fn try_from(v: Vec<T>) -> Result<Vec2<T>, Self::Error> {
-        let len = v.len();
+        match <[T; 2]>::try_from(v) {
+            Ok([x, y]) => Ok(Vec2::new(x, y)),
+            Err(v) => Err(format!(
+                "Given vec must have size 2, has size '{}'",
+                v.len()
+            )),
+        }
+    }
+}
 
-        if len == 2 {
-            Ok(Vec2::new(v[0], v[1]))
-        } else {
-            Err(format!("Given vec must have size 2, has size '{}'", len))
+// Slice
+impl<T> TryFrom<&[T]> for Vec2<T>
+where
+    T: Num + Clone,
+{
+    type Error = crate::SliceLengthError;
+
+    fn try_from(s: &[T]) -> Result<Vec2<T>, Self::Error> {
+        match s {
+            [x, y] => Ok(Vec2::new(x.clone(), y.clone())),
+            _ => Err(crate::SliceLengthError { found: s.len() }),
         }
     }
 }
 
 impl<T> From<Vec2<T>> for Vec<T>
 where
-    T: Num + Copy,
+    T: Num + Clone,
 {
     fn from(v: Vec2<T>) -> Vec<T> {
         vec![v.x, v.y]
@@ -531,12 +699,27 @@ where
 // Display //
 ////////////
 
+/// Formats the vector as `Vec2(x, y)`, or as just `x, y` with the
+/// alternate flag (`{:#}`). Precision, width, and fill are applied to
+/// each component.
 impl<T> fmt::Display for Vec2<T>
 where
-    T: Num + Copy + fmt::Display,
+    T: Num + Clone + fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Vec2({}, {})", self.x, self.y)
+        if !f.alternate() {
+            write!(f, "Vec2(")?;
+        }
+
+        fmt::Display::fmt(&self.x, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+
+        if !f.alternate() {
+            write!(f, ")")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -546,9 +729,36 @@ where
 
 impl<T> Default for Vec2<T>
 where
-    T: Num + Copy + Default,
+    T: Num + Clone + Default,
 {
     fn default() -> Self {
         Vec2::new(T::default(), T::default())
     }
 }
+
+///////////////////
+// Zero and One //
+/////////////////
+
+impl<T> Zero for Vec2<T>
+where
+    T: Num + Clone,
+{
+    fn zero() -> Self {
+        Vec2::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+// Multiplication is component-wise, so `(1, 1)` is its identity
+impl<T> One for Vec2<T>
+where
+    T: Num + Clone,
+{
+    fn one() -> Self {
+        Vec2::new(T::one(), T::one())
+    }
+}
@@ -1,5 +1,15 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(any(feature = "legacy", feature = "macroed"))]
+mod error;
+#[cfg(any(feature = "legacy", feature = "macroed"))]
+mod vector;
+
+#[cfg(any(feature = "legacy", feature = "macroed"))]
+pub use self::error::SliceLengthError;
+#[cfg(any(feature = "legacy", feature = "macroed"))]
+pub use self::vector::Vector2;
+
 #[cfg(feature = "legacy")]
 pub mod legacy;
 
@@ -0,0 +1,203 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::math::Transcendental;
+use super::Vec2f32;
+
+/// An angle, stored in radians.
+///
+/// Angles can only be created by explicitly naming their unit, which keeps degrees from being
+/// passed where radians are expected and vice versa.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::Angle;
+/// let a = Angle::from_degrees(180.0);
+///
+/// assert_eq!(a, Angle::HALF_TURN);
+/// assert_eq!(a.radians(), std::f32::consts::PI);
+/// assert_eq!((a + a).normalized(), Angle::ZERO);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Angle(f32);
+
+impl Angle {
+    /// An angle of 0.
+    pub const ZERO: Self = Angle(0.0);
+
+    /// A quarter turn, 90 degrees.
+    pub const QUARTER_TURN: Self = Angle(FRAC_PI_2);
+
+    /// A half turn, 180 degrees.
+    pub const HALF_TURN: Self = Angle(PI);
+
+    /// A full turn, 360 degrees.
+    pub const FULL_TURN: Self = Angle(TAU);
+
+    /// Creates an angle from radians.
+    pub const fn from_radians(radians: f32) -> Self {
+        Angle(radians)
+    }
+
+    /// Creates an angle from degrees.
+    pub fn from_degrees(degrees: f32) -> Self {
+        Angle(degrees.to_radians())
+    }
+
+    /// Returns the angle in radians.
+    pub const fn radians(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the angle in degrees.
+    pub fn degrees(self) -> f32 {
+        self.0.to_degrees()
+    }
+
+    /// Returns the same angle wrapped into the range (-π, π].
+    pub fn normalized(self) -> Self {
+        let wrapped = self.0.rem_euclid(TAU);
+
+        if wrapped > PI {
+            Angle(wrapped - TAU)
+        } else {
+            Angle(wrapped)
+        }
+    }
+
+    /// Returns the shortest signed rotation from this angle to `target`, in the range (-π, π].
+    ///
+    /// Positive results are counterclockwise. Subtracting angles directly can give a rotation
+    /// the long way around, like turning 358° to get from 359° to 1°.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Angle;
+    /// let heading = Angle::from_degrees(350.0);
+    /// let turn = heading.shortest_to(Angle::from_degrees(10.0));
+    ///
+    /// assert!((turn.degrees() - 20.0).abs() < 1e-4);
+    /// ```
+    pub fn shortest_to(self, target: Angle) -> Angle {
+        (target - self).normalized()
+    }
+
+    /// Returns the sine of the angle.
+    pub fn sin(self) -> f32 {
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine of the angle.
+    pub fn cos(self) -> f32 {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of the angle, in that order.
+    pub fn sin_cos(self) -> (f32, f32) {
+        Transcendental::sin_cos(self.0)
+    }
+}
+
+// Operators //
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Angle(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Angle(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<f32> for Angle {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Angle(self.0 * rhs)
+    }
+}
+
+impl MulAssign<f32> for Angle {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.0 *= rhs;
+    }
+}
+
+impl Div<f32> for Angle {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Angle(self.0 / rhs)
+    }
+}
+
+impl DivAssign<f32> for Angle {
+    fn div_assign(&mut self, rhs: f32) {
+        self.0 /= rhs;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Angle(-self.0)
+    }
+}
+
+// Vectors //
+
+impl Vec2f32 {
+    /// Creates a vector of length 1 pointing at `angle`, measured counterclockwise from +x.
+    pub fn from_angle(angle: Angle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Vec2f32::new(cos, sin)
+    }
+
+    /// Returns the angle of the vector, measured counterclockwise from +x, in the range
+    /// [-π, π].
+    pub fn angle(self) -> Angle {
+        Angle(Transcendental::atan2(self.y, self.x))
+    }
+
+    /// Returns the shortest signed rotation from this vector's direction to `other`'s, in the
+    /// range (-π, π].
+    ///
+    /// Positive results are counterclockwise. The result is zero if either vector is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Vec2f32;
+    /// let a = Vec2f32::new(1.0, -0.01);
+    /// let b = Vec2f32::new(1.0, 0.01);
+    ///
+    /// // Crossing the +x axis is a small turn, not a full circle
+    /// assert!(a.angle_to(b).radians() > 0.0 && a.angle_to(b).radians() < 0.1);
+    /// ```
+    pub fn angle_to(self, other: Vec2f32) -> Angle {
+        let cross = self.x * other.y - self.y * other.x;
+        Angle(Transcendental::atan2(cross, self.dot(other))).normalized()
+    }
+}
@@ -0,0 +1,258 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+/// Which way three points turn, as returned by [`orientation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The points turn clockwise, assuming `+y` is up.
+    Clockwise,
+    /// The points turn counterclockwise, assuming `+y` is up.
+    Counterclockwise,
+    /// The points are in a line.
+    Collinear,
+}
+
+/// A vector type that can be used with [`signed_area`], [`parallelogram_area`], and
+/// [`orientation`].
+///
+/// Integer products are taken exactly, with 128-bit magnitudes, so they never overflow, and
+/// integer areas are returned as `f64` since triangles can have half-unit areas. Float products
+/// are taken in `f64`. This is implemented for every primitive vector in this module except the
+/// 128-bit ones, and cannot be implemented outside of it.
+pub trait SignedArea: Copy + sealed::Sealed {
+    /// The type areas are returned as.
+    type Area: Copy;
+
+    #[doc(hidden)]
+    fn parallelogram_area_of(u: Self, v: Self) -> Self::Area;
+
+    #[doc(hidden)]
+    fn signed_area_of(a: Self, b: Self, c: Self) -> Self::Area;
+
+    #[doc(hidden)]
+    fn orientation_of(a: Self, b: Self, c: Self) -> Orientation;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Returns the area of the triangle `a`, `b`, `c`, which is positive if the vertices go around
+/// counterclockwise and negative if they go around clockwise.
+///
+/// This is the same as [`Triangle::signed_area`], for any vector type.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{signed_area, Vec2i32};
+/// let a = Vec2i32::new(0, 0);
+/// let b = Vec2i32::new(3, 0);
+/// let c = Vec2i32::new(0, 1);
+///
+/// assert_eq!(signed_area(a, b, c), 1.5);
+/// assert_eq!(signed_area(a, c, b), -1.5);
+/// ```
+pub fn signed_area<V: SignedArea>(a: V, b: V, c: V) -> V::Area {
+    V::signed_area_of(a, b, c)
+}
+
+/// Returns the signed area of the parallelogram with sides `u` and `v`, which is the z component
+/// of their cross product.
+///
+/// The area is positive when `v` is counterclockwise from `u`, and zero when they are parallel.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{parallelogram_area, Vec2i8};
+/// let u = Vec2i8::new(100, 0);
+/// let v = Vec2i8::new(-50, 120);
+///
+/// // The product doesn't fit in an i8
+/// assert_eq!(parallelogram_area(u, v), 12000.0);
+/// assert_eq!(parallelogram_area(v, u), -12000.0);
+/// ```
+pub fn parallelogram_area<V: SignedArea>(u: V, v: V) -> V::Area {
+    V::parallelogram_area_of(u, v)
+}
+
+/// Returns whether going from `a` to `b` to `c` turns clockwise, counterclockwise, or goes
+/// straight.
+///
+/// The integer test is exact. The float test treats points as collinear when the turn is within
+/// rounding error of the component type, which keeps nearly straight lines from flipping
+/// between clockwise and counterclockwise.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{orientation, Orientation, Vec2f32, Vec2i32};
+/// let a = Vec2i32::new(0, 0);
+/// let b = Vec2i32::new(4, 0);
+///
+/// assert_eq!(orientation(a, b, Vec2i32::new(5, 1)), Orientation::Counterclockwise);
+/// assert_eq!(orientation(a, b, Vec2i32::new(5, -1)), Orientation::Clockwise);
+/// assert_eq!(orientation(a, b, Vec2i32::new(-8, 0)), Orientation::Collinear);
+///
+/// let a = Vec2f32::new(0.1, 0.1);
+/// let b = Vec2f32::new(0.2, 0.2);
+///
+/// assert_eq!(orientation(a, b, Vec2f32::new(0.3, 0.3)), Orientation::Collinear);
+/// ```
+pub fn orientation<V: SignedArea>(a: V, b: V, c: V) -> Orientation {
+    V::orientation_of(a, b, c)
+}
+
+/// The cross product `u.x * v.y - u.y * v.x` of two integer vectors, kept as its two products.
+///
+/// Each product is stored as a sign and a `u128` magnitude, which holds any product of
+/// components up to `2^64` in size, so the differences of 64-bit coordinates never overflow.
+#[derive(Copy, Clone)]
+struct ExactCross {
+    left: (bool, u128),
+    right: (bool, u128),
+}
+
+impl ExactCross {
+    fn new(u: (i128, i128), v: (i128, i128)) -> Self {
+        ExactCross {
+            left: Self::product(u.0, v.1),
+            right: Self::product(u.1, v.0),
+        }
+    }
+
+    /// Returns whether `a * b` is negative, and its magnitude.
+    fn product(a: i128, b: i128) -> (bool, u128) {
+        let magnitude = a.unsigned_abs() * b.unsigned_abs();
+        (magnitude != 0 && (a < 0) != (b < 0), magnitude)
+    }
+
+    /// Returns the sign of the cross product, which is exact.
+    fn sign(self) -> Ordering {
+        let ((left_neg, left), (right_neg, right)) = (self.left, self.right);
+
+        match (left_neg, right_neg) {
+            (false, false) => left.cmp(&right),
+            (true, true) => right.cmp(&left),
+            // Negative products are never zero, so they are always the smaller one
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+
+    /// Returns the cross product rounded to the nearest `f64`.
+    fn to_f64(self) -> f64 {
+        let ((left_neg, left), (right_neg, right)) = (self.left, self.right);
+
+        let (negative, magnitude) = if left_neg == right_neg {
+            // Subtracting magnitudes with the same sign is exact
+            if left >= right {
+                (left_neg, (left - right) as f64)
+            } else {
+                (!left_neg, (right - left) as f64)
+            }
+        } else {
+            // The sum can need 129 bits, so it is only summed once rounded
+            (left_neg, left as f64 + right as f64)
+        };
+
+        if negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// A macro for implementing [`SignedArea`] on [`Vec2`] structs.
+macro_rules! impl_signed_area {
+    // Integers are exact, and always give `f64` areas
+    ($name:ident) => {
+        impl sealed::Sealed for $name {}
+
+        impl SignedArea for $name {
+            type Area = f64;
+
+            fn parallelogram_area_of(u: Self, v: Self) -> f64 {
+                ExactCross::new((u.x as i128, u.y as i128), (v.x as i128, v.y as i128)).to_f64()
+            }
+
+            fn signed_area_of(a: Self, b: Self, c: Self) -> f64 {
+                let (ax, ay) = (a.x as i128, a.y as i128);
+                let u = (b.x as i128 - ax, b.y as i128 - ay);
+                let v = (c.x as i128 - ax, c.y as i128 - ay);
+
+                ExactCross::new(u, v).to_f64() / 2.0
+            }
+
+            fn orientation_of(a: Self, b: Self, c: Self) -> Orientation {
+                let (ax, ay) = (a.x as i128, a.y as i128);
+                let u = (b.x as i128 - ax, b.y as i128 - ay);
+                let v = (c.x as i128 - ax, c.y as i128 - ay);
+
+                match ExactCross::new(u, v).sign() {
+                    Ordering::Greater => Orientation::Counterclockwise,
+                    Ordering::Less => Orientation::Clockwise,
+                    Ordering::Equal => Orientation::Collinear,
+                }
+            }
+        }
+    };
+    ($name:ident, $acc:ty, $area:ty, $epsilon:expr) => {
+        impl sealed::Sealed for $name {}
+
+        impl SignedArea for $name {
+            type Area = $area;
+
+            fn parallelogram_area_of(u: Self, v: Self) -> $area {
+                let cross = u.x as $acc * v.y as $acc - u.y as $acc * v.x as $acc;
+                cross as $area
+            }
+
+            fn signed_area_of(a: Self, b: Self, c: Self) -> $area {
+                let (ax, ay) = (a.x as $acc, a.y as $acc);
+                let (u, v) = (
+                    (b.x as $acc - ax, b.y as $acc - ay),
+                    (c.x as $acc - ax, c.y as $acc - ay),
+                );
+
+                (u.0 * v.1 - u.1 * v.0) as $area / 2.0
+            }
+
+            fn orientation_of(a: Self, b: Self, c: Self) -> Orientation {
+                let (ax, ay) = (a.x as $acc, a.y as $acc);
+                let u = (b.x as $acc - ax, b.y as $acc - ay);
+                let v = (c.x as $acc - ax, c.y as $acc - ay);
+                let (left, right) = (u.0 * v.1, u.1 * v.0);
+
+                // The rounding error grows with the size of the products being subtracted
+                let tolerance = $epsilon * (left.abs() + right.abs());
+
+                if left - right > tolerance {
+                    Orientation::Counterclockwise
+                } else if right - left > tolerance {
+                    Orientation::Clockwise
+                } else {
+                    Orientation::Collinear
+                }
+            }
+        }
+    };
+}
+
+impl_signed_area!(Vec2f32, f64, f32, f32::EPSILON as f64);
+impl_signed_area!(Vec2f64, f64, f64, f64::EPSILON);
+
+impl_signed_area!(Vec2u8);
+impl_signed_area!(Vec2u16);
+impl_signed_area!(Vec2u32);
+impl_signed_area!(Vec2u64);
+impl_signed_area!(Vec2usize);
+
+impl_signed_area!(Vec2i8);
+impl_signed_area!(Vec2i16);
+impl_signed_area!(Vec2i32);
+impl_signed_area!(Vec2i64);
+impl_signed_area!(Vec2isize);
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::Vec2f32;
+
+/// A [`Vec2f32`] that can be shared between threads without a lock.
+///
+/// Both components are packed into a single [`AtomicU64`], so a load never sees `x` from one
+/// store and `y` from another. Because of this, it is only available on targets with 64-bit
+/// atomics.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{AtomicVec2f32, Vec2f32};
+/// # use std::sync::atomic::Ordering;
+/// let position = AtomicVec2f32::new(Vec2f32::new(1.0, 2.0));
+///
+/// position.store(Vec2f32::new(3.0, 4.0), Ordering::Release);
+/// assert_eq!(position.load(Ordering::Acquire), Vec2f32::new(3.0, 4.0));
+///
+/// let old = position.fetch_update(Ordering::AcqRel, Ordering::Acquire, |v| Some(v * 2.0));
+/// assert_eq!(old, Ok(Vec2f32::new(3.0, 4.0)));
+/// assert_eq!(position.load(Ordering::Acquire), Vec2f32::new(6.0, 8.0));
+/// ```
+pub struct AtomicVec2f32 {
+    bits: AtomicU64,
+}
+
+/// Packs a vector into a `u64`, with `x` in the low half and `y` in the high half.
+fn pack(v: Vec2f32) -> u64 {
+    (v.x.to_bits() as u64) | ((v.y.to_bits() as u64) << 32)
+}
+
+/// The inverse of [`pack`].
+fn unpack(bits: u64) -> Vec2f32 {
+    Vec2f32::new(
+        f32::from_bits(bits as u32),
+        f32::from_bits((bits >> 32) as u32),
+    )
+}
+
+impl AtomicVec2f32 {
+    /// Creates a new AtomicVec2f32.
+    pub fn new(v: Vec2f32) -> Self {
+        AtomicVec2f32 {
+            bits: AtomicU64::new(pack(v)),
+        }
+    }
+
+    /// Returns the current value.
+    ///
+    /// See [`AtomicU64::load`] for the meaning of `order`.
+    pub fn load(&self, order: Ordering) -> Vec2f32 {
+        unpack(self.bits.load(order))
+    }
+
+    /// Replaces the current value.
+    ///
+    /// See [`AtomicU64::store`] for the meaning of `order`.
+    pub fn store(&self, v: Vec2f32, order: Ordering) {
+        self.bits.store(pack(v), order);
+    }
+
+    /// Replaces the current value, returning the previous one.
+    ///
+    /// See [`AtomicU64::swap`] for the meaning of `order`.
+    pub fn swap(&self, v: Vec2f32, order: Ordering) -> Vec2f32 {
+        unpack(self.bits.swap(pack(v), order))
+    }
+
+    /// Applies `f` to the current value until it succeeds without another thread changing the
+    /// value in between. Returns the previous value, or `Err` with the current value if `f`
+    /// returned [`None`].
+    ///
+    /// Values are compared by their bits, so this works even when a component is NaN.
+    ///
+    /// See [`AtomicU64::fetch_update`] for the meaning of the orderings.
+    pub fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<Vec2f32, Vec2f32>
+    where
+        F: FnMut(Vec2f32) -> Option<Vec2f32>,
+    {
+        self.bits
+            .fetch_update(set_order, fetch_order, |bits| f(unpack(bits)).map(pack))
+            .map(unpack)
+            .map_err(unpack)
+    }
+
+    /// Consumes the atomic and returns the value.
+    pub fn into_inner(self) -> Vec2f32 {
+        unpack(self.bits.into_inner())
+    }
+}
+
+impl From<Vec2f32> for AtomicVec2f32 {
+    fn from(v: Vec2f32) -> Self {
+        AtomicVec2f32::new(v)
+    }
+}
+
+impl fmt::Debug for AtomicVec2f32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
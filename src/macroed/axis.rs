@@ -0,0 +1,41 @@
+/// One of the two axes of a vector.
+///
+/// Vectors can be read and written by axis with [`get`](super::Vec2f32::get),
+/// [`set`](super::Vec2f32::set), and [`axis_mut`](super::Vec2f32::axis_mut), so algorithms that
+/// treat both axes the same way don't have to repeat themselves.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Axis2, Vec2f32};
+/// let a = Vec2f32::new(0.0, 0.0);
+/// let b = Vec2f32::new(3.0, 5.0);
+///
+/// // Find the axis with the largest gap
+/// let widest = Axis2::ALL
+///     .into_iter()
+///     .max_by(|&i, &j| (b.get(i) - a.get(i)).total_cmp(&(b.get(j) - a.get(j))))
+///     .unwrap();
+///
+/// assert_eq!(widest, Axis2::Y);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis2 {
+    /// The horizontal axis.
+    X,
+    /// The vertical axis.
+    Y,
+}
+
+impl Axis2 {
+    /// Both axes, in order.
+    pub const ALL: [Axis2; 2] = [Axis2::X, Axis2::Y];
+
+    /// Returns the other axis.
+    pub const fn other(self) -> Self {
+        match self {
+            Axis2::X => Axis2::Y,
+            Axis2::Y => Axis2::X,
+        }
+    }
+}
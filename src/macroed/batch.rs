@@ -0,0 +1,454 @@
+//! Functions for working with many vectors at once.
+//!
+//! Vectors can be stored either interleaved (`[x0, y0, x1, y1, ...]`, an "array of structs") or
+//! as separate lists of `x`s and `y`s (a "struct of arrays"). Plotting libraries and GPU buffers
+//! often want one or the other, so these functions convert between the two.
+//!
+//! # Example
+//!
+//! ```
+//! # use manyvecs::macroed::{batch, Vec2f32};
+//! let points: Vec<Vec2f32> = batch::interleave(&[1.0, 2.0], &[3.0, 4.0]);
+//!
+//! assert_eq!(points, [Vec2f32::new(1.0, 3.0), Vec2f32::new(2.0, 4.0)]);
+//! assert_eq!(batch::flatten(&points), [1.0, 3.0, 2.0, 4.0]);
+//! assert_eq!(batch::deinterleave(&points), (vec![1.0, 2.0], vec![3.0, 4.0]));
+//! ```
+//!
+//! There are also functions for applying math to a whole slice of vectors at once, such as
+//! [`translate_slice`] and [`add_assign_slices`]. These work on the flattened components, which
+//! lets the compiler auto-vectorize them.
+//!
+//! For large sets of vectors that are mostly processed in bulk, [`Vec2Buffer`] keeps them in
+//! struct of arrays form the whole time.
+
+use std::ops::*;
+use std::slice;
+
+use super::*;
+
+/// A vector type made of two components of the same scalar type.
+///
+/// This is implemented for every vector in this module, and cannot be implemented outside of it.
+pub trait Components: Copy + sealed::Sealed {
+    /// The type of each component.
+    type Scalar: Copy;
+
+    /// Creates a vector from its components.
+    fn from_components(x: Self::Scalar, y: Self::Scalar) -> Self;
+
+    /// Returns the components of a vector.
+    fn components(self) -> (Self::Scalar, Self::Scalar);
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Combines a list of `x`s and a list of `y`s into a list of vectors.
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths.
+pub fn interleave<V: Components>(xs: &[V::Scalar], ys: &[V::Scalar]) -> Vec<V> {
+    assert_eq!(xs.len(), ys.len(), "xs and ys must have the same length");
+
+    xs.iter()
+        .zip(ys)
+        .map(|(&x, &y)| V::from_components(x, y))
+        .collect()
+}
+
+/// Splits a list of vectors into a list of `x`s and a list of `y`s.
+pub fn deinterleave<V: Components>(vs: &[V]) -> (Vec<V::Scalar>, Vec<V::Scalar>) {
+    vs.iter().map(|v| v.components()).unzip()
+}
+
+/// Views a list of vectors as a flat list of their components, without copying.
+pub fn flatten<V: Components>(vs: &[V]) -> &[V::Scalar] {
+    // SAFETY: Every vector is `#[repr(C)]` with exactly two fields of type `V::Scalar`, so it has
+    // the same layout as `[V::Scalar; 2]`.
+    unsafe { slice::from_raw_parts(vs.as_ptr().cast(), vs.len() * 2) }
+}
+
+/// Mutably views a list of vectors as a flat list of their components, without copying.
+pub fn flatten_mut<V: Components>(vs: &mut [V]) -> &mut [V::Scalar] {
+    // SAFETY: See `flatten`.
+    unsafe { slice::from_raw_parts_mut(vs.as_mut_ptr().cast(), vs.len() * 2) }
+}
+
+/// Views a flat list of components as a list of vectors, without copying.
+///
+/// Returns [`None`] if the list has an odd length.
+pub fn unflatten<V: Components>(components: &[V::Scalar]) -> Option<&[V]> {
+    if components.len() % 2 != 0 {
+        return None;
+    }
+
+    // SAFETY: See `flatten`. Vectors have the same alignment as their scalar.
+    Some(unsafe { slice::from_raw_parts(components.as_ptr().cast(), components.len() / 2) })
+}
+
+/// Mutably views a flat list of components as a list of vectors, without copying.
+///
+/// Returns [`None`] if the list has an odd length.
+pub fn unflatten_mut<V: Components>(components: &mut [V::Scalar]) -> Option<&mut [V]> {
+    if components.len() % 2 != 0 {
+        return None;
+    }
+
+    // SAFETY: See `unflatten`.
+    Some(unsafe { slice::from_raw_parts_mut(components.as_mut_ptr().cast(), components.len() / 2) })
+}
+
+// Slice Operations //
+
+/// Applies `op` to each pair of components in `dst` and `src`.
+fn zip_components<V: Components>(dst: &mut [V], src: &[V], op: impl Fn(&mut V::Scalar, V::Scalar)) {
+    assert_eq!(dst.len(), src.len(), "slices must have the same length");
+
+    for (a, &b) in flatten_mut(dst).iter_mut().zip(flatten(src)) {
+        op(a, b);
+    }
+}
+
+/// Adds each vector in `src` to the vector at the same index in `dst`.
+///
+/// # Panics
+///
+/// Panics if `dst` and `src` have different lengths.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{batch, Vec2f32};
+/// let mut positions = [Vec2f32::new(0.0, 0.0), Vec2f32::new(5.0, 5.0)];
+/// let velocities = [Vec2f32::new(1.0, 2.0), Vec2f32::new(-1.0, 0.0)];
+///
+/// batch::add_assign_slices(&mut positions, &velocities);
+///
+/// assert_eq!(positions, [Vec2f32::new(1.0, 2.0), Vec2f32::new(4.0, 5.0)]);
+/// ```
+pub fn add_assign_slices<V: Components>(dst: &mut [V], src: &[V])
+where
+    V::Scalar: AddAssign,
+{
+    zip_components(dst, src, |a, b| *a += b);
+}
+
+/// Subtracts each vector in `src` from the vector at the same index in `dst`.
+///
+/// # Panics
+///
+/// Panics if `dst` and `src` have different lengths.
+pub fn sub_assign_slices<V: Components>(dst: &mut [V], src: &[V])
+where
+    V::Scalar: SubAssign,
+{
+    zip_components(dst, src, |a, b| *a -= b);
+}
+
+/// Multiplies each vector in `dst` by the vector at the same index in `src`, component-wise.
+///
+/// # Panics
+///
+/// Panics if `dst` and `src` have different lengths.
+pub fn mul_assign_slices<V: Components>(dst: &mut [V], src: &[V])
+where
+    V::Scalar: MulAssign,
+{
+    zip_components(dst, src, |a, b| *a *= b);
+}
+
+/// Multiplies every vector in a slice by `factor`.
+pub fn scale_slice<V: Components>(vs: &mut [V], factor: V::Scalar)
+where
+    V::Scalar: MulAssign,
+{
+    for c in flatten_mut(vs) {
+        *c *= factor;
+    }
+}
+
+/// Adds `offset` to every vector in a slice.
+pub fn translate_slice<V: Components>(vs: &mut [V], offset: V)
+where
+    V::Scalar: AddAssign,
+{
+    let (x, y) = offset.components();
+
+    for c in flatten_mut(vs).chunks_exact_mut(2) {
+        c[0] += x;
+        c[1] += y;
+    }
+}
+
+/// Multiplies every vector in a slice by `scale` component-wise, then adds `offset`.
+pub fn scale_translate_slice<V: Components>(vs: &mut [V], scale: V, offset: V)
+where
+    V::Scalar: Mul<Output = V::Scalar> + Add<Output = V::Scalar>,
+{
+    let (sx, sy) = scale.components();
+    let (ox, oy) = offset.components();
+
+    for c in flatten_mut(vs).chunks_exact_mut(2) {
+        c[0] = c[0] * sx + ox;
+        c[1] = c[1] * sy + oy;
+    }
+}
+
+// Buffer //
+
+/// A growable list of vectors, stored as separate lists of `x`s and `y`s.
+///
+/// This "struct of arrays" layout is faster than a `Vec` of vectors when the same math is applied
+/// to every element, such as in particle systems.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{batch::Vec2Buffer, Vec2f32};
+/// let mut particles: Vec2Buffer<Vec2f32> = Vec2Buffer::new();
+///
+/// particles.push(Vec2f32::new(1.0, 2.0));
+/// particles.push(Vec2f32::new(3.0, 4.0));
+/// particles.translate(Vec2f32::new(1.0, 0.0));
+///
+/// assert_eq!(particles.get(1), Some(Vec2f32::new(4.0, 4.0)));
+/// assert_eq!(particles.xs(), [2.0, 4.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vec2Buffer<V: Components> {
+    xs: Vec<V::Scalar>,
+    ys: Vec<V::Scalar>,
+}
+
+impl<V: Components> Vec2Buffer<V> {
+    /// Creates a new, empty Vec2Buffer.
+    pub const fn new() -> Self {
+        Vec2Buffer {
+            xs: Vec::new(),
+            ys: Vec::new(),
+        }
+    }
+
+    /// Creates a new, empty Vec2Buffer with space for at least `capacity` vectors.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Vec2Buffer {
+            xs: Vec::with_capacity(capacity),
+            ys: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a Vec2Buffer from a list of `x`s and a list of `y`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs` and `ys` have different lengths.
+    pub fn from_components(xs: Vec<V::Scalar>, ys: Vec<V::Scalar>) -> Self {
+        assert_eq!(xs.len(), ys.len(), "xs and ys must have the same length");
+
+        Vec2Buffer { xs, ys }
+    }
+
+    /// Returns the number of vectors in the buffer.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Returns true if the buffer has no vectors.
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Adds a vector to the end of the buffer.
+    pub fn push(&mut self, v: V) {
+        let (x, y) = v.components();
+
+        self.xs.push(x);
+        self.ys.push(y);
+    }
+
+    /// Removes the last vector from the buffer and returns it, or [`None`] if it is empty.
+    pub fn pop(&mut self) -> Option<V> {
+        Some(V::from_components(self.xs.pop()?, self.ys.pop()?))
+    }
+
+    /// Removes every vector from the buffer.
+    pub fn clear(&mut self) {
+        self.xs.clear();
+        self.ys.clear();
+    }
+
+    /// Returns the vector at `index`, or [`None`] if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<V> {
+        Some(V::from_components(
+            *self.xs.get(index)?,
+            *self.ys.get(index)?,
+        ))
+    }
+
+    /// Replaces the vector at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, v: V) {
+        let (x, y) = v.components();
+
+        self.xs[index] = x;
+        self.ys[index] = y;
+    }
+
+    /// Returns an iterator over copies of the vectors in the buffer.
+    pub fn iter(&self) -> impl Iterator<Item = V> + '_ {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(&x, &y)| V::from_components(x, y))
+    }
+
+    /// Returns the `x` component of every vector.
+    pub fn xs(&self) -> &[V::Scalar] {
+        &self.xs
+    }
+
+    /// Returns the `y` component of every vector.
+    pub fn ys(&self) -> &[V::Scalar] {
+        &self.ys
+    }
+
+    /// Mutably returns the `x` and `y` components of every vector.
+    pub fn components_mut(&mut self) -> (&mut [V::Scalar], &mut [V::Scalar]) {
+        (&mut self.xs, &mut self.ys)
+    }
+
+    /// Splits the buffer into its list of `x`s and list of `y`s.
+    pub fn into_components(self) -> (Vec<V::Scalar>, Vec<V::Scalar>) {
+        (self.xs, self.ys)
+    }
+
+    /// Adds `offset` to every vector.
+    pub fn translate(&mut self, offset: V)
+    where
+        V::Scalar: AddAssign,
+    {
+        let (x, y) = offset.components();
+
+        self.xs.iter_mut().for_each(|c| *c += x);
+        self.ys.iter_mut().for_each(|c| *c += y);
+    }
+
+    /// Multiplies every vector by `factor`.
+    pub fn scale(&mut self, factor: V::Scalar)
+    where
+        V::Scalar: MulAssign,
+    {
+        self.xs.iter_mut().for_each(|c| *c *= factor);
+        self.ys.iter_mut().for_each(|c| *c *= factor);
+    }
+
+    /// Adds each vector in `other` to the vector at the same index in this buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths.
+    pub fn add_assign_buffer(&mut self, other: &Self)
+    where
+        V::Scalar: AddAssign,
+    {
+        assert_eq!(self.len(), other.len(), "buffers must have the same length");
+
+        self.xs
+            .iter_mut()
+            .zip(&other.xs)
+            .for_each(|(a, &b)| *a += b);
+        self.ys
+            .iter_mut()
+            .zip(&other.ys)
+            .for_each(|(a, &b)| *a += b);
+    }
+
+    /// Adds each vector in `other`, multiplied by `factor`, to the vector at the same index in
+    /// this buffer. This is the usual `position += velocity * dt` step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths.
+    pub fn add_scaled_buffer(&mut self, other: &Self, factor: V::Scalar)
+    where
+        V::Scalar: AddAssign + Mul<Output = V::Scalar>,
+    {
+        assert_eq!(self.len(), other.len(), "buffers must have the same length");
+
+        self.xs
+            .iter_mut()
+            .zip(&other.xs)
+            .for_each(|(a, &b)| *a += b * factor);
+        self.ys
+            .iter_mut()
+            .zip(&other.ys)
+            .for_each(|(a, &b)| *a += b * factor);
+    }
+}
+
+// Vectors don't implement `Default`, so this can't be derived
+impl<V: Components> Default for Vec2Buffer<V> {
+    fn default() -> Self {
+        Vec2Buffer::new()
+    }
+}
+
+impl<V: Components> FromIterator<V> for Vec2Buffer<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut buffer = Vec2Buffer::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl<V: Components> Extend<V> for Vec2Buffer<V> {
+    fn extend<I: IntoIterator<Item = V>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+/// A macro for implementing [`Components`] on [`Vec2`] structs.
+macro_rules! impl_components {
+    ($name:ident, $type_:ty) => {
+        impl sealed::Sealed for $name {}
+
+        impl Components for $name {
+            type Scalar = $type_;
+
+            fn from_components(x: $type_, y: $type_) -> Self {
+                Self::new(x, y)
+            }
+
+            fn components(self) -> ($type_, $type_) {
+                (self.x, self.y)
+            }
+        }
+    };
+}
+
+impl_components!(Vec2f32, f32);
+impl_components!(Vec2f64, f64);
+#[cfg(feature = "extended")]
+impl_components!(Vec2dd, DoubleDouble);
+
+impl_components!(Vec2u8, u8);
+impl_components!(Vec2u16, u16);
+impl_components!(Vec2u32, u32);
+impl_components!(Vec2u64, u64);
+impl_components!(Vec2u128, u128);
+impl_components!(Vec2usize, usize);
+
+impl_components!(Vec2i8, i8);
+impl_components!(Vec2i16, i16);
+impl_components!(Vec2i32, i32);
+impl_components!(Vec2i64, i64);
+impl_components!(Vec2i128, i128);
+impl_components!(Vec2isize, isize);
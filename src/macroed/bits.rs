@@ -0,0 +1,152 @@
+use super::*;
+
+macro_rules! impl_float_bits {
+    ($name:ident, $type_:ident, $bits:ident, $bits_type:ident) => {
+        impl $name {
+            /// Returns the raw bits of each component, like
+            #[doc = concat!("[`", stringify!($type_), "::to_bits`].")]
+            ///
+            /// Two vectors have the same bits only if they are exactly the same, so this can be
+            /// used for hashing and bit-level comparisons where `-0.0` and `0.0` differ and NaNs
+            /// are equal to themselves.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::{", stringify!($name), ", ", stringify!($bits), "};")]
+            #[doc = concat!("let v = ", stringify!($name), "::new(1.0, -0.0);")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_bits(v.to_bits()), v);")]
+            #[doc = concat!("assert_ne!(v.to_bits(), ", stringify!($name), "::new(1.0, 0.0).to_bits());")]
+            /// ```
+            #[inline]
+            pub const fn to_bits(self) -> $bits {
+                $bits::new(self.x.to_bits(), self.y.to_bits())
+            }
+
+            /// Creates a vector from the raw bits of each component, like
+            #[doc = concat!("[`", stringify!($type_), "::from_bits`].")]
+            #[inline]
+            pub const fn from_bits(v: $bits) -> Self {
+                $name::new($type_::from_bits(v.x), $type_::from_bits(v.y))
+            }
+
+            /// Returns `true` if each component is at most `max_ulps` representable numbers
+            /// away from `other`'s.
+            ///
+            /// This is stricter than [`approx_eq`](Self::approx_eq) for small numbers and
+            /// looser for large ones, since the gap between floats grows with their magnitude.
+            /// `0.0` and `-0.0` are equal, numbers with different signs otherwise never are, and
+            /// NaNs are never equal to anything.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+            #[doc = concat!("let v = ", stringify!($name), "::new(1.0, 1e20);")]
+            #[doc = concat!("let next = ", stringify!($type_), "::from_bits(1.0", stringify!($type_), ".to_bits() + 1);")]
+            ///
+            /// assert!(v.ulps_eq((next, 1e20), 1));
+            /// assert!(!v.ulps_eq((next, 1e20), 0));
+            /// ```
+            pub fn ulps_eq<V: Into<Self>>(&self, other: V, max_ulps: $bits_type) -> bool {
+                let other: Self = other.into();
+                let ulps_eq = |a: $type_, b: $type_| {
+                    if a == b {
+                        true
+                    } else if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+                        false
+                    } else {
+                        a.to_bits().abs_diff(b.to_bits()) <= max_ulps
+                    }
+                };
+
+                ulps_eq(self.x, other.x) && ulps_eq(self.y, other.y)
+            }
+        }
+    };
+}
+
+impl_float_bits!(Vec2f32, f32, Vec2u32, u32);
+impl_float_bits!(Vec2f64, f64, Vec2u64, u64);
+
+macro_rules! impl_bytes {
+    ($($(#[$meta:meta])* $name:ident, $type_:ty, $size:expr);* $(;)?) => {
+        $(
+            $(#[$meta])*
+            impl $name {
+                /// Returns the memory representation of the vector as bytes, with `x` first and
+                /// each component in little-endian byte order.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use manyvecs::macroed::Vec2u16;
+                /// let v = Vec2u16::new(0x1234, 0x5678);
+                ///
+                /// assert_eq!(v.to_le_bytes(), [0x34, 0x12, 0x78, 0x56]);
+                /// assert_eq!(Vec2u16::from_le_bytes(v.to_le_bytes()), v);
+                /// ```
+                pub fn to_le_bytes(self) -> [u8; $size] {
+                    Self::join_bytes(self.x.to_le_bytes(), self.y.to_le_bytes())
+                }
+
+                /// Returns the memory representation of the vector as bytes, with `x` first and
+                /// each component in big-endian byte order.
+                pub fn to_be_bytes(self) -> [u8; $size] {
+                    Self::join_bytes(self.x.to_be_bytes(), self.y.to_be_bytes())
+                }
+
+                /// Creates a vector from its little-endian representation, as written by
+                /// [`to_le_bytes`](Self::to_le_bytes).
+                pub fn from_le_bytes(bytes: [u8; $size]) -> Self {
+                    let (x, y) = Self::split_bytes(bytes);
+                    Self::new(<$type_>::from_le_bytes(x), <$type_>::from_le_bytes(y))
+                }
+
+                /// Creates a vector from its big-endian representation, as written by
+                /// [`to_be_bytes`](Self::to_be_bytes).
+                pub fn from_be_bytes(bytes: [u8; $size]) -> Self {
+                    let (x, y) = Self::split_bytes(bytes);
+                    Self::new(<$type_>::from_be_bytes(x), <$type_>::from_be_bytes(y))
+                }
+
+                fn join_bytes(x: [u8; $size / 2], y: [u8; $size / 2]) -> [u8; $size] {
+                    let mut bytes = [0; $size];
+                    bytes[..$size / 2].copy_from_slice(&x);
+                    bytes[$size / 2..].copy_from_slice(&y);
+                    bytes
+                }
+
+                fn split_bytes(bytes: [u8; $size]) -> ([u8; $size / 2], [u8; $size / 2]) {
+                    let mut x = [0; $size / 2];
+                    let mut y = [0; $size / 2];
+                    x.copy_from_slice(&bytes[..$size / 2]);
+                    y.copy_from_slice(&bytes[$size / 2..]);
+                    (x, y)
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes!(
+    Vec2f32, f32, 8;
+    Vec2f64, f64, 16;
+    #[cfg(feature = "half")]
+    Vec2f16, half::f16, 4;
+    #[cfg(feature = "fixed")]
+    Vec2Fixed, fixed::types::I16F16, 8;
+    Vec2u8, u8, 2;
+    Vec2u16, u16, 4;
+    Vec2u32, u32, 8;
+    Vec2u64, u64, 16;
+    Vec2u128, u128, 32;
+    Vec2usize, usize, 2 * std::mem::size_of::<usize>();
+    Vec2i8, i8, 2;
+    Vec2i16, i16, 4;
+    Vec2i32, i32, 8;
+    Vec2i64, i64, 16;
+    Vec2i128, i128, 32;
+    Vec2isize, isize, 2 * std::mem::size_of::<isize>();
+);
@@ -0,0 +1,54 @@
+use std::f32::consts::PI;
+
+use super::{point_in_circle, Segment, Shape2, ShapeRef, Vec2f32};
+
+/// A line segment with a radius around it, shaped like a pill.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Capsule, Segment, Shape2, Vec2f32};
+/// let pill = Capsule::new(Segment::new(Vec2f32::new(0.0, 0.0), Vec2f32::new(4.0, 0.0)), 1.0);
+///
+/// assert!(pill.contains_point(Vec2f32::new(4.5, 0.5)));
+/// assert!(!pill.contains_point(Vec2f32::new(2.0, 1.5)));
+/// assert_eq!(pill.centroid(), Vec2f32::new(2.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule {
+    /// The segment running through the middle of the capsule.
+    pub segment: Segment,
+    /// The distance from the segment to the edge of the capsule.
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// Creates a new Capsule.
+    pub const fn new(segment: Segment, radius: f32) -> Self {
+        Capsule { segment, radius }
+    }
+
+    /// Returns true if `point` is inside the capsule or on its edge.
+    pub fn contains_point(&self, point: Vec2f32) -> bool {
+        point_in_circle(point, self.segment.closest_point(point), self.radius)
+    }
+}
+
+impl Shape2 for Capsule {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Capsule(*self)
+    }
+
+    fn area(&self) -> f32 {
+        // A rectangle along the segment, plus a half circle at each end
+        PI * self.radius * self.radius + 2.0 * self.radius * self.segment.length()
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * PI * self.radius + 2.0 * self.segment.length()
+    }
+
+    fn centroid(&self) -> Vec2f32 {
+        self.segment.midpoint()
+    }
+}
@@ -0,0 +1,188 @@
+use std::f32::consts::FRAC_PI_4;
+
+use super::math::Transcendental;
+use super::{Axis2, Vec2f32, Vec2isize};
+
+/// One of the 4 orthogonal grid directions, with `+y` as north.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Direction4, Vec2f32, Vec2isize};
+/// let stick = Vec2f32::new(0.2, -0.9);
+/// let facing = stick.to_direction4().unwrap();
+///
+/// assert_eq!(facing, Direction4::South);
+/// assert_eq!(Vec2isize::new(4, 4) + facing.to_vec2(), Vec2isize::new(4, 3));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    /// `+x`
+    East,
+    /// `+y`
+    North,
+    /// `-x`
+    West,
+    /// `-y`
+    South,
+}
+
+impl Direction4 {
+    /// Every direction, counterclockwise starting from [`East`](Direction4::East).
+    pub const ALL: [Direction4; 4] = [
+        Direction4::East,
+        Direction4::North,
+        Direction4::West,
+        Direction4::South,
+    ];
+
+    /// Returns the unit grid step in this direction.
+    pub const fn to_vec2(self) -> Vec2isize {
+        match self {
+            Direction4::East => Vec2isize::new(1, 0),
+            Direction4::North => Vec2isize::new(0, 1),
+            Direction4::West => Vec2isize::new(-1, 0),
+            Direction4::South => Vec2isize::new(0, -1),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    pub const fn opposite(self) -> Self {
+        match self {
+            Direction4::East => Direction4::West,
+            Direction4::North => Direction4::South,
+            Direction4::West => Direction4::East,
+            Direction4::South => Direction4::North,
+        }
+    }
+}
+
+/// One of the 8 grid directions, including diagonals, with `+y` as north.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    /// `+x`
+    East,
+    /// `+x`, `+y`
+    NorthEast,
+    /// `+y`
+    North,
+    /// `-x`, `+y`
+    NorthWest,
+    /// `-x`
+    West,
+    /// `-x`, `-y`
+    SouthWest,
+    /// `-y`
+    South,
+    /// `+x`, `-y`
+    SouthEast,
+}
+
+impl Direction8 {
+    /// Every direction, counterclockwise starting from [`East`](Direction8::East).
+    pub const ALL: [Direction8; 8] = [
+        Direction8::East,
+        Direction8::NorthEast,
+        Direction8::North,
+        Direction8::NorthWest,
+        Direction8::West,
+        Direction8::SouthWest,
+        Direction8::South,
+        Direction8::SouthEast,
+    ];
+
+    /// Returns the unit grid step in this direction, where diagonals move along both axes.
+    pub const fn to_vec2(self) -> Vec2isize {
+        match self {
+            Direction8::East => Vec2isize::new(1, 0),
+            Direction8::NorthEast => Vec2isize::new(1, 1),
+            Direction8::North => Vec2isize::new(0, 1),
+            Direction8::NorthWest => Vec2isize::new(-1, 1),
+            Direction8::West => Vec2isize::new(-1, 0),
+            Direction8::SouthWest => Vec2isize::new(-1, -1),
+            Direction8::South => Vec2isize::new(0, -1),
+            Direction8::SouthEast => Vec2isize::new(1, -1),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    pub const fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns `true` for the four diagonal directions.
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(d: Direction4) -> Self {
+        Direction8::ALL[d as usize * 2]
+    }
+}
+
+impl Vec2f32 {
+    /// Returns the orthogonal direction closest to this vector, or [`None`] if it is zero or not
+    /// finite.
+    ///
+    /// Exact diagonals snap to [`East`](Direction4::East) or [`West`](Direction4::West), like
+    /// [`major_axis`](Self::major_axis).
+    pub fn to_direction4(&self) -> Option<Direction4> {
+        if *self == Vec2f32::new(0.0, 0.0) || !(self.x.is_finite() && self.y.is_finite()) {
+            return None;
+        }
+
+        Some(match self.major_axis() {
+            Axis2::X if self.x > 0.0 => Direction4::East,
+            Axis2::X => Direction4::West,
+            Axis2::Y if self.y > 0.0 => Direction4::North,
+            Axis2::Y => Direction4::South,
+        })
+    }
+
+    /// Returns the direction closest to this vector's angle, or [`None`] if it is zero or not
+    /// finite.
+    ///
+    /// Each direction covers an equal 45° slice of angles centered on it.
+    pub fn to_direction8(&self) -> Option<Direction8> {
+        if *self == Vec2f32::new(0.0, 0.0) || !(self.x.is_finite() && self.y.is_finite()) {
+            return None;
+        }
+
+        let angle = Transcendental::atan2(self.y, self.x);
+        let sector = (angle / FRAC_PI_4).round() as isize;
+
+        Some(Direction8::ALL[sector.rem_euclid(8) as usize])
+    }
+}
+
+impl Vec2isize {
+    /// Returns the orthogonal direction closest to this vector, or [`None`] if it is zero.
+    ///
+    /// Exact diagonals snap to [`East`](Direction4::East) or [`West`](Direction4::West), like
+    /// [`major_axis`](Self::major_axis).
+    pub fn to_direction4(&self) -> Option<Direction4> {
+        if *self == Vec2isize::new(0, 0) {
+            return None;
+        }
+
+        Some(match self.major_axis() {
+            Axis2::X if self.x > 0 => Direction4::East,
+            Axis2::X => Direction4::West,
+            Axis2::Y if self.y > 0 => Direction4::North,
+            Axis2::Y => Direction4::South,
+        })
+    }
+
+    /// Returns the direction of the grid step with the same signs as this vector, or [`None`] if
+    /// it is zero.
+    ///
+    /// This only looks at the signs, so `(5, 1)` is [`NorthEast`](Direction8::NorthEast), while
+    /// the angle-based [`Vec2f32::to_direction8`] would pick [`East`](Direction8::East).
+    pub fn sign_direction8(&self) -> Option<Direction8> {
+        Direction8::ALL
+            .into_iter()
+            .find(|d| d.to_vec2() == Vec2isize::new(self.x.signum(), self.y.signum()))
+    }
+}
@@ -0,0 +1,236 @@
+use super::*;
+
+/// A primitive number with a matching vector in this crate, such as [`f32`] and [`Vec2f32`].
+pub trait Primitive: Copy {
+    /// The vector with this number as its scalar.
+    type Vec2;
+
+    /// Creates the matching vector from two components.
+    fn vec2(x: Self, y: Self) -> Self::Vec2;
+}
+
+/// Converts a primitive number into this one with an `as` cast.
+///
+/// This is what powers [`Vec2f32::cast`] and the other `cast` methods. Floats are truncated
+/// toward zero and saturate at the bounds of an integer type, with NaN becoming `0`. Integers
+/// that don't fit wrap around, and large integers are rounded to the nearest float.
+pub trait CastFrom<T>: Primitive {
+    /// Casts `v` into this type, exactly like `v as Self`.
+    fn cast_from(v: T) -> Self;
+}
+
+macro_rules! impl_primitive {
+    ($($type_:ident => $vec:ident),*) => {
+        $(
+            impl Primitive for $type_ {
+                type Vec2 = $vec;
+
+                #[inline]
+                fn vec2(x: Self, y: Self) -> $vec {
+                    $vec::new(x, y)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_cast_from {
+    ([$($from:ident),*] $all:tt) => {
+        $(impl_cast_from!(@from $from => $all);)*
+    };
+    (@from $from:ident => [$($to:ident),*]) => {
+        $(
+            impl CastFrom<$from> for $to {
+                #[inline]
+                fn cast_from(v: $from) -> $to {
+                    v as $to
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_cast_methods {
+    ($($name:ident, $type_:ident);*) => {
+        $(
+            impl $name {
+                /// Converts each component to another primitive type with an `as` cast.
+                ///
+                /// Floats are truncated toward zero and saturate at the bounds of an integer
+                /// type, with NaN becoming `0`. Integers that don't fit wrap around. See
+                /// [`CastFrom`] for the details.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use manyvecs::macroed::{Vec2f32, Vec2usize};
+                /// let tile = Vec2usize::new(3, 4);
+                /// assert_eq!(tile.cast::<f32>(), Vec2f32::new(3.0, 4.0));
+                ///
+                /// let pos = Vec2f32::new(2.9, -1.5);
+                /// assert_eq!(pos.cast::<i32>().x, 2);
+                /// assert_eq!(pos.cast::<u8>().y, 0);
+                /// ```
+                #[inline]
+                pub fn cast<U: CastFrom<$type_>>(self) -> U::Vec2 {
+                    U::vec2(U::cast_from(self.x), U::cast_from(self.y))
+                }
+
+                /// Casts each component to [`f32`], like [`cast`](Self::cast).
+                #[inline]
+                pub fn as_f32(self) -> Vec2f32 {
+                    self.cast::<f32>()
+                }
+
+                /// Casts each component to [`f64`], like [`cast`](Self::cast).
+                #[inline]
+                pub fn as_f64(self) -> Vec2f64 {
+                    self.cast::<f64>()
+                }
+
+                /// Casts each component to [`i32`], like [`cast`](Self::cast).
+                #[inline]
+                pub fn as_i32(self) -> Vec2i32 {
+                    self.cast::<i32>()
+                }
+
+                /// Casts each component to [`u32`], like [`cast`](Self::cast).
+                #[inline]
+                pub fn as_u32(self) -> Vec2u32 {
+                    self.cast::<u32>()
+                }
+
+                /// Casts each component to [`isize`], like [`cast`](Self::cast).
+                #[inline]
+                pub fn as_isize(self) -> Vec2isize {
+                    self.cast::<isize>()
+                }
+
+                /// Casts each component to [`usize`], like [`cast`](Self::cast).
+                #[inline]
+                pub fn as_usize(self) -> Vec2usize {
+                    self.cast::<usize>()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_round_cast {
+    ($($name:ident, $type_:ident);*) => {
+        $(
+            impl $name {
+                /// Rounds each component to the nearest integer, with halfway cases rounded away
+                /// from zero, then casts it like [`cast`](Self::cast).
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use manyvecs::macroed::{Vec2f32, Vec2i32};
+                /// let pos = Vec2f32::new(2.5, -1.2);
+                ///
+                /// assert_eq!(pos.round_cast::<i32>(), Vec2i32::new(3, -1));
+                /// assert_eq!(pos.floor_cast::<i32>(), Vec2i32::new(2, -2));
+                /// assert_eq!(pos.ceil_cast::<i32>(), Vec2i32::new(3, -1));
+                /// ```
+                #[inline]
+                pub fn round_cast<U: CastFrom<$type_>>(self) -> U::Vec2 {
+                    U::vec2(U::cast_from(self.x.round()), U::cast_from(self.y.round()))
+                }
+
+                /// Rounds each component down, then casts it like [`cast`](Self::cast).
+                #[inline]
+                pub fn floor_cast<U: CastFrom<$type_>>(self) -> U::Vec2 {
+                    U::vec2(U::cast_from(self.x.floor()), U::cast_from(self.y.floor()))
+                }
+
+                /// Rounds each component up, then casts it like [`cast`](Self::cast).
+                #[inline]
+                pub fn ceil_cast<U: CastFrom<$type_>>(self) -> U::Vec2 {
+                    U::vec2(U::cast_from(self.x.ceil()), U::cast_from(self.y.ceil()))
+                }
+
+                /// Rounds each component to the nearest [`i32`], like
+                /// [`round_cast`](Self::round_cast).
+                #[inline]
+                pub fn to_i32_round(self) -> Vec2i32 {
+                    self.round_cast::<i32>()
+                }
+
+                /// Rounds each component down to an [`i32`], like
+                /// [`floor_cast`](Self::floor_cast).
+                #[inline]
+                pub fn to_i32_floor(self) -> Vec2i32 {
+                    self.floor_cast::<i32>()
+                }
+
+                /// Rounds each component up to an [`i32`], like [`ceil_cast`](Self::ceil_cast).
+                #[inline]
+                pub fn to_i32_ceil(self) -> Vec2i32 {
+                    self.ceil_cast::<i32>()
+                }
+
+                /// Rounds each component to the nearest [`u32`], like
+                /// [`round_cast`](Self::round_cast). Negative components become `0`.
+                #[inline]
+                pub fn to_u32_round(self) -> Vec2u32 {
+                    self.round_cast::<u32>()
+                }
+
+                /// Rounds each component down to a [`u32`], like
+                /// [`floor_cast`](Self::floor_cast). Negative components become `0`.
+                #[inline]
+                pub fn to_u32_floor(self) -> Vec2u32 {
+                    self.floor_cast::<u32>()
+                }
+
+                /// Rounds each component up to a [`u32`], like [`ceil_cast`](Self::ceil_cast).
+                /// Negative components become `0`.
+                #[inline]
+                pub fn to_u32_ceil(self) -> Vec2u32 {
+                    self.ceil_cast::<u32>()
+                }
+
+                /// Rounds each component to the nearest [`usize`], like
+                /// [`round_cast`](Self::round_cast). Negative components become `0`.
+                #[inline]
+                pub fn to_usize_round(self) -> Vec2usize {
+                    self.round_cast::<usize>()
+                }
+
+                /// Rounds each component down to a [`usize`], like
+                /// [`floor_cast`](Self::floor_cast). Negative components become `0`.
+                #[inline]
+                pub fn to_usize_floor(self) -> Vec2usize {
+                    self.floor_cast::<usize>()
+                }
+
+                /// Rounds each component up to a [`usize`], like [`ceil_cast`](Self::ceil_cast).
+                /// Negative components become `0`.
+                #[inline]
+                pub fn to_usize_ceil(self) -> Vec2usize {
+                    self.ceil_cast::<usize>()
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(
+    f32 => Vec2f32, f64 => Vec2f64,
+    u8 => Vec2u8, u16 => Vec2u16, u32 => Vec2u32, u64 => Vec2u64, u128 => Vec2u128, usize => Vec2usize,
+    i8 => Vec2i8, i16 => Vec2i16, i32 => Vec2i32, i64 => Vec2i64, i128 => Vec2i128, isize => Vec2isize
+);
+
+impl_cast_from!(
+    [f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+    [f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+);
+
+impl_cast_methods!(
+    Vec2f32, f32; Vec2f64, f64;
+    Vec2u8, u8; Vec2u16, u16; Vec2u32, u32; Vec2u64, u64; Vec2u128, u128; Vec2usize, usize;
+    Vec2i8, i8; Vec2i16, i16; Vec2i32, i32; Vec2i64, i64; Vec2i128, i128; Vec2isize, isize
+);
+
+impl_round_cast!(Vec2f32, f32; Vec2f64, f64);
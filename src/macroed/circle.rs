@@ -0,0 +1,90 @@
+use std::f32::consts::PI;
+
+use super::{point_in_circle, Rect, Shape2, ShapeRef, Vec2f32};
+
+/// A circle, including its inside.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Circle, Rect, Vec2f32};
+/// let ball = Circle::new(Vec2f32::new(0.0, 0.0), 2.0);
+/// let wall = Rect::new(Vec2f32::new(1.5, -5.0), Vec2f32::new(3.0, 5.0));
+///
+/// assert!(ball.contains_point(Vec2f32::new(1.0, 1.0)));
+/// assert!(ball.intersects_rect(&wall));
+/// assert_eq!(ball.closest_point(Vec2f32::new(10.0, 0.0)), Vec2f32::new(2.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: Vec2f32,
+    /// The distance from the center to the edge of the circle.
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Creates a new Circle.
+    pub const fn new(center: Vec2f32, radius: f32) -> Self {
+        Circle { center, radius }
+    }
+
+    /// Returns true if `point` is inside the circle or on its edge.
+    pub fn contains_point(&self, point: Vec2f32) -> bool {
+        point_in_circle(point, self.center, self.radius)
+    }
+
+    /// Returns true if the circles overlap or touch.
+    pub fn intersects_circle(&self, other: &Circle) -> bool {
+        let reach = self.radius + other.radius;
+        (other.center - self.center).mag2() <= reach * reach
+    }
+
+    /// Returns true if the circle overlaps or touches a rectangle.
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.contains_point(rect.clamp(self.center))
+    }
+
+    /// Returns the point in the circle closest to `point`, which is `point` itself if it is
+    /// already inside.
+    pub fn closest_point(&self, point: Vec2f32) -> Vec2f32 {
+        if self.contains_point(point) {
+            point
+        } else {
+            self.closest_edge_point(point)
+        }
+    }
+
+    /// Returns the point on the edge of the circle closest to `point`.
+    ///
+    /// If `point` is the center, every edge point is equally close and the one in the `+x`
+    /// direction is returned.
+    pub fn closest_edge_point(&self, point: Vec2f32) -> Vec2f32 {
+        let offset = point - self.center;
+        let distance = offset.mag();
+
+        if distance == 0.0 {
+            self.center + Vec2f32::new(self.radius, 0.0)
+        } else {
+            self.center + offset * (self.radius / distance)
+        }
+    }
+}
+
+impl Shape2 for Circle {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Circle(*self)
+    }
+
+    fn area(&self) -> f32 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * PI * self.radius
+    }
+
+    fn centroid(&self) -> Vec2f32 {
+        self.center
+    }
+}
@@ -0,0 +1,135 @@
+//! Helpers for using vectors and arrays as colors.
+//!
+//! Hues are angles, so averaging or interpolating them as plain numbers goes wrong when they wrap
+//! around (the average of 350° and 10° is 0°, not 180°). Turning them into points on the color
+//! wheel with [`Vec2f32::from_hue`] and back with [`Vec2f32::hue`] avoids this.
+//!
+//! Colors are stored as `[f32; 3]` (RGB) or `[f32; 4]` (RGBA), with components from `0` to `1`.
+
+use super::math::Transcendental;
+use super::Vec2f32;
+
+// Hue //
+
+impl Vec2f32 {
+    /// Creates a point on the color wheel from a hue in degrees and a chroma (saturation), where
+    /// a hue of `0` points along the positive x axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Vec2f32;
+    /// let a = Vec2f32::from_hue(350.0, 1.0);
+    /// let b = Vec2f32::from_hue(10.0, 1.0);
+    /// let mid = (a + b) / 2.0;
+    ///
+    /// assert!(mid.hue() < 0.001 || mid.hue() > 359.999);
+    /// ```
+    pub fn from_hue(hue: f32, chroma: f32) -> Self {
+        let (sin, cos) = Transcendental::sin_cos(hue.to_radians());
+        Vec2f32::new(cos * chroma, sin * chroma)
+    }
+
+    /// Returns the hue of a point on the color wheel in degrees, from `0` up to but not including
+    /// `360`.
+    ///
+    /// The chroma is the point's [`mag`](Vec2f32::mag).
+    pub fn hue(&self) -> f32 {
+        let hue = Transcendental::atan2(self.y, self.x)
+            .to_degrees()
+            .rem_euclid(360.0);
+
+        // `rem_euclid` can round up to exactly 360 for tiny negative angles
+        if hue >= 360.0 {
+            0.0
+        } else {
+            hue
+        }
+    }
+}
+
+// sRGB //
+
+/// Converts an sRGB color component into linear light.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        Transcendental::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a linear light color component into sRGB.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * Transcendental::powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// An sRGB color that can be interpolated by [`lerp_color`].
+///
+/// This is implemented for `[f32; 3]` (RGB) and `[f32; 4]` (RGBA), and cannot be implemented
+/// outside of this module. Other lengths have no meaning as colors, so they are rejected:
+///
+/// ```compile_fail
+/// # use manyvecs::macroed::lerp_color;
+/// let _ = lerp_color([0.0; 5], [1.0; 5], 0.5);
+/// ```
+pub trait Color: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn lerp_linear(self, other: Self, t: f32) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for [f32; 3] {}
+impl sealed::Sealed for [f32; 4] {}
+
+impl Color for [f32; 3] {
+    fn lerp_linear(self, other: Self, t: f32) -> Self {
+        let mut out = [0.0; 3];
+
+        for ((c, a), b) in out.iter_mut().zip(self).zip(other) {
+            let a = srgb_to_linear(a);
+            let b = srgb_to_linear(b);
+
+            *c = linear_to_srgb(a + (b - a) * t);
+        }
+
+        out
+    }
+}
+
+impl Color for [f32; 4] {
+    fn lerp_linear(self, other: Self, t: f32) -> Self {
+        let [r, g, b] = [self[0], self[1], self[2]].lerp_linear([other[0], other[1], other[2]], t);
+
+        // Alpha isn't gamma encoded, so it is interpolated as is
+        [r, g, b, self[3] + (other[3] - self[3]) * t]
+    }
+}
+
+/// Interpolates between two sRGB colors by `t`, where `0` returns `a` and `1` returns `b`.
+///
+/// The interpolation is done in linear light, which avoids the dark bands that appear when
+/// blending sRGB values directly. The alpha of RGBA colors is interpolated as is.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::lerp_color;
+/// let red = [1.0, 0.0, 0.0, 1.0];
+/// let green = [0.0, 1.0, 0.0, 0.0];
+/// let mid = lerp_color(red, green, 0.5);
+///
+/// // Halfway in linear light is brighter than 0.5 in sRGB
+/// assert!(mid[0] > 0.7 && mid[1] > 0.7);
+/// assert_eq!(mid[3], 0.5);
+/// ```
+pub fn lerp_color<C: Color>(a: C, b: C, t: f32) -> C {
+    a.lerp_linear(b, t)
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+macro_rules! impl_bounds {
+    ($($(#[$meta:meta])* $name:ident, $type_:ty);* $(;)?) => {
+        $(
+            $(#[$meta])*
+            impl $name {
+                /// A vector with the smallest value of the scalar type in both components.
+                pub const MIN: Self = Self::new(<$type_>::MIN, <$type_>::MIN);
+
+                /// A vector with the largest value of the scalar type in both components.
+                ///
+                /// This is the starting point for the minimum corner of a bounding box that is
+                /// grown to fit a set of points, with [`MIN`](Self::MIN) as the maximum corner.
+                pub const MAX: Self = Self::new(<$type_>::MAX, <$type_>::MAX);
+            }
+        )*
+    };
+}
+
+macro_rules! impl_infinity {
+    ($($(#[$meta:meta])* $name:ident, $type_:ty);* $(;)?) => {
+        $(
+            $(#[$meta])*
+            impl $name {
+                /// A vector with positive infinity in both components.
+                pub const INFINITY: Self = Self::new(<$type_>::INFINITY, <$type_>::INFINITY);
+
+                /// A vector with negative infinity in both components.
+                pub const NEG_INFINITY: Self =
+                    Self::new(<$type_>::NEG_INFINITY, <$type_>::NEG_INFINITY);
+            }
+        )*
+    };
+}
+
+impl_bounds!(
+    Vec2f32, f32;
+    Vec2f64, f64;
+    #[cfg(feature = "half")]
+    Vec2f16, half::f16;
+    #[cfg(feature = "fixed")]
+    Vec2Fixed, fixed::types::I16F16;
+    Vec2u8, u8;
+    Vec2u16, u16;
+    Vec2u32, u32;
+    Vec2u64, u64;
+    Vec2u128, u128;
+    Vec2usize, usize;
+    Vec2i8, i8;
+    Vec2i16, i16;
+    Vec2i32, i32;
+    Vec2i64, i64;
+    Vec2i128, i128;
+    Vec2isize, isize;
+);
+
+impl_infinity!(
+    Vec2f32, f32;
+    Vec2f64, f64;
+    #[cfg(feature = "half")]
+    Vec2f16, half::f16;
+);
@@ -0,0 +1,104 @@
+use super::*;
+
+/// Implements `From` between vectors whose scalars have a lossless conversion.
+macro_rules! impl_from {
+    ($from:ident => [$(($to:ident, $to_type:ident)),*]) => {
+        $(
+            impl From<$from> for $to {
+                fn from(v: $from) -> Self {
+                    $to::new($to_type::from(v.x), $to_type::from(v.y))
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `TryFrom` between vectors whose scalars only have a fallible conversion.
+macro_rules! impl_try_from {
+    ($from:ident, $from_type:ident => [$(($to:ident, $to_type:ident)),*]) => {
+        $(
+            impl TryFrom<$from> for $to {
+                type Error = TryFromVec2Error;
+
+                /// Converts each component, failing if either one is out of range for the
+                /// target type.
+                fn try_from(v: $from) -> Result<Self, Self::Error> {
+                    match ($to_type::try_from(v.x), $to_type::try_from(v.y)) {
+                        (Ok(x), Ok(y)) => Ok($to::new(x, y)),
+                        _ => Err(TryFromVec2Error),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(Vec2u8, u8 => [(Vec2i8, i8)]);
+impl_try_from!(Vec2u16, u16 => [(Vec2u8, u8), (Vec2i8, i8), (Vec2i16, i16), (Vec2isize, isize)]);
+impl_try_from!(Vec2u32, u32 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2usize, usize),
+    (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2isize, isize)
+]);
+impl_try_from!(Vec2u64, u64 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2usize, usize),
+    (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64), (Vec2isize, isize)
+]);
+impl_try_from!(Vec2u128, u128 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2usize, usize),
+    (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64), (Vec2i128, i128),
+    (Vec2isize, isize)
+]);
+impl_try_from!(Vec2usize, usize => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64), (Vec2i128, i128),
+    (Vec2isize, isize)
+]);
+impl_try_from!(Vec2i8, i8 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2usize, usize)
+]);
+impl_try_from!(Vec2i16, i16 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2usize, usize), (Vec2i8, i8)
+]);
+impl_try_from!(Vec2i32, i32 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2usize, usize), (Vec2i8, i8), (Vec2i16, i16), (Vec2isize, isize)
+]);
+impl_try_from!(Vec2i64, i64 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2usize, usize), (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2isize, isize)
+]);
+impl_try_from!(Vec2i128, i128 => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2usize, usize), (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64),
+    (Vec2isize, isize)
+]);
+impl_try_from!(Vec2isize, isize => [
+    (Vec2u8, u8), (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128),
+    (Vec2usize, usize), (Vec2i8, i8), (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64),
+    (Vec2i128, i128)
+]);
+
+impl_from!(Vec2u8 => [
+    (Vec2u16, u16), (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128), (Vec2usize, usize),
+    (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64), (Vec2i128, i128), (Vec2isize, isize),
+    (Vec2f32, f32), (Vec2f64, f64)
+]);
+impl_from!(Vec2u16 => [
+    (Vec2u32, u32), (Vec2u64, u64), (Vec2u128, u128), (Vec2usize, usize),
+    (Vec2i32, i32), (Vec2i64, i64), (Vec2i128, i128), (Vec2f32, f32), (Vec2f64, f64)
+]);
+impl_from!(Vec2u32 => [(Vec2u64, u64), (Vec2u128, u128), (Vec2i64, i64), (Vec2i128, i128), (Vec2f64, f64)]);
+impl_from!(Vec2u64 => [(Vec2u128, u128), (Vec2i128, i128)]);
+impl_from!(Vec2i8 => [
+    (Vec2i16, i16), (Vec2i32, i32), (Vec2i64, i64), (Vec2i128, i128), (Vec2isize, isize),
+    (Vec2f32, f32), (Vec2f64, f64)
+]);
+impl_from!(Vec2i16 => [
+    (Vec2i32, i32), (Vec2i64, i64), (Vec2i128, i128), (Vec2isize, isize), (Vec2f32, f32),
+    (Vec2f64, f64)
+]);
+impl_from!(Vec2i32 => [(Vec2i64, i64), (Vec2i128, i128), (Vec2f64, f64)]);
+impl_from!(Vec2i64 => [(Vec2i128, i128)]);
+impl_from!(Vec2f32 => [(Vec2f64, f64)]);
@@ -0,0 +1,113 @@
+use std::f32::consts::TAU;
+
+use super::math::Transcendental;
+use super::Vec2f32;
+
+// Unit Vector //
+
+/// A [`Vec2f32`] with a magnitude of 1.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{UnitVec2, Vec2f32};
+/// let up = UnitVec2::new(Vec2f32::new(0.0, 5.0)).unwrap();
+///
+/// assert_eq!(up.get(), Vec2f32::new(0.0, 1.0));
+/// assert_eq!(UnitVec2::new(Vec2f32::new(0.0, 0.0)), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitVec2(Vec2f32);
+
+impl UnitVec2 {
+    /// Creates a UnitVec2 pointing in the same direction as `v`.
+    ///
+    /// Returns [`None`] if `v` is zero or not finite, since it has no direction.
+    pub fn new(v: Vec2f32) -> Option<Self> {
+        // `mag` squares the components first, which overflows for large vectors
+        let length = v.mag_precise();
+
+        if length == 0.0 || !length.is_finite() {
+            return None;
+        }
+
+        Some(UnitVec2(v / length))
+    }
+
+    /// Returns the underlying vector.
+    pub const fn get(self) -> Vec2f32 {
+        self.0
+    }
+}
+
+impl From<UnitVec2> for Vec2f32 {
+    fn from(v: UnitVec2) -> Self {
+        v.0
+    }
+}
+
+// Analysis //
+
+/// Returns the average direction of a list of movements, or [`None`] if they cancel out.
+///
+/// Each non-zero movement counts equally, no matter how long it is.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{dominant_direction, Vec2f32};
+/// let swipe = [Vec2f32::new(3.0, 1.0), Vec2f32::new(6.0, -2.0), Vec2f32::new(0.0, 0.0)];
+///
+/// assert_eq!(dominant_direction(&swipe).unwrap().get(), Vec2f32::new(1.0, 0.0));
+/// ```
+pub fn dominant_direction(deltas: &[Vec2f32]) -> Option<UnitVec2> {
+    let sum = deltas
+        .iter()
+        .filter_map(|&d| UnitVec2::new(d))
+        .fold(Vec2f32::new(0.0, 0.0), |sum, d| sum + d.get());
+
+    UnitVec2::new(sum)
+}
+
+/// Sorts a list of movements into `BINS` evenly sized angle ranges, adding up the length of the
+/// movements in each one.
+///
+/// Bin `0` is centered on the positive `x` axis, and the bins continue counterclockwise (towards
+/// positive `y`). Zero-length movements are ignored.
+///
+/// # Panics
+///
+/// Panics if `BINS` is zero.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{orientation_histogram, Vec2f32};
+/// let deltas = [Vec2f32::new(2.0, 0.0), Vec2f32::new(0.0, 3.0), Vec2f32::new(1.0, 1.0)];
+/// let bins = orientation_histogram::<8>(&deltas);
+///
+/// assert_eq!(bins[0], 2.0);
+/// assert_eq!(bins[1], 2.0_f32.sqrt());
+/// assert_eq!(bins[2], 3.0);
+/// ```
+pub fn orientation_histogram<const BINS: usize>(deltas: &[Vec2f32]) -> [f32; BINS] {
+    assert!(BINS > 0, "orientation_histogram needs at least one bin");
+
+    let mut bins = [0.0; BINS];
+    let width = TAU / BINS as f32;
+
+    for d in deltas {
+        let length = d.mag();
+
+        if length == 0.0 {
+            continue;
+        }
+
+        let angle = Transcendental::atan2(d.y, d.x).rem_euclid(TAU);
+        let bin = (angle / width).round() as usize % BINS;
+
+        bins[bin] += length;
+    }
+
+    bins
+}
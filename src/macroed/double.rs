@@ -0,0 +1,438 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::*;
+use std::str::FromStr;
+
+use super::math::Real;
+
+/// A "double-double" floating point number, storing a value as the unevaluated sum of two
+/// [`f64`]s.
+///
+/// This gives roughly 106 bits of precision (about 32 decimal digits) with the same range as
+/// `f64`, at a fraction of the cost of arbitrary-precision numbers. It is used as the scalar of
+/// [`Vec2dd`](crate::macroed::Vec2dd).
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::DoubleDouble;
+/// let tenth: DoubleDouble = "0.1".parse().unwrap();
+/// let sum = tenth + tenth + tenth;
+///
+/// // 0.1 + 0.1 + 0.1 != 0.3 with f64, but the error is far smaller here.
+/// assert!((sum - "0.3".parse::<DoubleDouble>().unwrap()).abs() < DoubleDouble::from(1e-30));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DoubleDouble {
+    /// The leading part of the number, which is the closest `f64` to the full value.
+    pub hi: f64,
+    /// The rounding error of `hi`, which is always tiny in comparison.
+    pub lo: f64,
+}
+
+impl DoubleDouble {
+    /// Creates a new DoubleDouble from the sum of `hi` and `lo`.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Returns the square root of the number.
+    pub fn sqrt(self) -> Self {
+        if self.hi <= 0.0 {
+            return DoubleDouble::from(Real::sqrt(self.hi));
+        }
+
+        // One step of Newton's method doubles the precision of the f64 estimate
+        let guess = DoubleDouble::from(Real::sqrt(self.hi));
+        guess + (self - guess * guess) / (guess * 2.0)
+    }
+
+    /// Returns the largest whole number less than or equal to this one.
+    pub fn floor(self) -> Self {
+        let hi = self.hi.floor();
+
+        if hi == self.hi {
+            DoubleDouble::new(hi, self.lo.floor())
+        } else {
+            DoubleDouble::from(hi)
+        }
+    }
+
+    /// Returns the smallest whole number greater than or equal to this one.
+    pub fn ceil(self) -> Self {
+        let hi = self.hi.ceil();
+
+        if hi == self.hi {
+            DoubleDouble::new(hi, self.lo.ceil())
+        } else {
+            DoubleDouble::from(hi)
+        }
+    }
+
+    /// Returns the whole number part of this number, rounding towards zero.
+    pub fn trunc(self) -> Self {
+        if self.hi < 0.0 {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    /// Returns the absolute value of this number.
+    pub fn abs(self) -> Self {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns the quotient of Euclidean division, rounded so that
+    /// [`rem_euclid`](Self::rem_euclid) is never negative.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let q = (self / rhs).trunc();
+
+        if self % rhs < DoubleDouble::from(0.0) {
+            if rhs > DoubleDouble::from(0.0) {
+                q - DoubleDouble::from(1.0)
+            } else {
+                q + DoubleDouble::from(1.0)
+            }
+        } else {
+            q
+        }
+    }
+
+    /// Returns the non-negative remainder of Euclidean division.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+
+        if r < DoubleDouble::from(0.0) {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+
+    /// Returns the larger of two numbers.
+    pub fn max(self, other: Self) -> Self {
+        if self >= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the smaller of two numbers.
+    pub fn min(self, other: Self) -> Self {
+        if self <= other {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+// Error-Free Transformations //
+
+/// Returns `a + b` and the rounding error of that sum.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Returns `a * b` and the rounding error of that product.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+// Operators //
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+        let (t, f) = two_sum(self.lo, rhs.lo);
+        let (s, e) = two_sum(s, e + t);
+        DoubleDouble::new(s, e + f)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (p, e) = two_prod(self.hi, rhs.hi);
+        DoubleDouble::new(p, e + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Mul<f64> for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        self * DoubleDouble::from(rhs)
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        // Long division, finding one f64 "digit" of the quotient at a time
+        let q1 = self.hi / rhs.hi;
+
+        // Dividing by zero or infinity, or overflowing, has no remainder to refine. Going on
+        // would multiply zero by infinity and give NaN.
+        if !q1.is_finite() || rhs.hi.is_infinite() {
+            return DoubleDouble::from(q1);
+        }
+
+        let r = self - rhs * q1;
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * q2;
+        let q3 = r.hi / rhs.hi;
+
+        DoubleDouble::new(q1, q2) + DoubleDouble::from(q3)
+    }
+}
+
+impl Rem for DoubleDouble {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        self - (self / rhs).trunc() * rhs
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+macro_rules! impl_assign {
+    ($trait_name:ident, $trait_fn:ident, $op:tt) => {
+        impl $trait_name for DoubleDouble {
+            fn $trait_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_assign!(AddAssign, add_assign, +);
+impl_assign!(SubAssign, sub_assign, -);
+impl_assign!(MulAssign, mul_assign, *);
+impl_assign!(DivAssign, div_assign, /);
+impl_assign!(RemAssign, rem_assign, %);
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi) {
+            Some(Ordering::Equal) => self.lo.partial_cmp(&other.lo),
+            ordering => ordering,
+        }
+    }
+}
+
+// Conversion //
+
+impl From<f64> for DoubleDouble {
+    fn from(v: f64) -> Self {
+        DoubleDouble { hi: v, lo: 0.0 }
+    }
+}
+
+impl From<f32> for DoubleDouble {
+    fn from(v: f32) -> Self {
+        DoubleDouble::from(v as f64)
+    }
+}
+
+impl From<i32> for DoubleDouble {
+    fn from(v: i32) -> Self {
+        DoubleDouble::from(v as f64)
+    }
+}
+
+impl From<DoubleDouble> for f64 {
+    fn from(v: DoubleDouble) -> Self {
+        v.hi + v.lo
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    /// Displays the closest `f64` to the number. Use the `hi` and `lo` fields directly to see
+    /// the full precision.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&f64::from(*self), f)
+    }
+}
+
+impl fmt::LowerExp for DoubleDouble {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&f64::from(*self), f)
+    }
+}
+
+impl fmt::UpperExp for DoubleDouble {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperExp::fmt(&f64::from(*self), f)
+    }
+}
+
+/// An error returned when parsing a [`DoubleDouble`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDoubleDoubleError;
+
+impl fmt::Display for ParseDoubleDoubleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid double-double literal")
+    }
+}
+
+impl std::error::Error for ParseDoubleDoubleError {}
+
+/// The most significant digits [`DoubleDouble::from_str`] reads, which is a few more than the
+/// type can hold. Later digits only move the exponent.
+const MAX_DIGITS: i64 = 36;
+
+/// The most powers of ten scaled by at once while parsing, so the scale stays finite.
+const MAX_SCALE: i64 = 256;
+
+/// The power of two, `2^64`, that parsed values are kept away from the ends of the `f64` range
+/// by while scaling.
+const EDGE_MARGIN: f64 = 18446744073709551616.0;
+
+/// Returns `10^n` for `n` up to [`MAX_SCALE`], using exponentiation by squaring.
+fn pow10(n: i64) -> DoubleDouble {
+    let mut result = DoubleDouble::from(1.0);
+    let mut base = DoubleDouble::from(10.0);
+    let mut n = n;
+
+    while n > 0 {
+        if n & 1 == 1 {
+            result *= base;
+        }
+
+        base = base * base;
+        n >>= 1;
+    }
+
+    result
+}
+
+impl FromStr for DoubleDouble {
+    type Err = ParseDoubleDoubleError;
+
+    /// Parses a decimal number such as `-12.5e-3`, keeping every digit that fits in the
+    /// double-double's precision.
+    ///
+    /// Numbers too large for an `f64` become infinite, and numbers too small become zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(i) => (
+                &s[..i],
+                s[i + 1..]
+                    .parse::<i32>()
+                    .map_err(|_| ParseDoubleDoubleError)?,
+            ),
+            None => (s, 0),
+        };
+
+        let (negative, mantissa) = match mantissa.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+        };
+
+        let mut value = DoubleDouble::from(0.0);
+        let mut exponent = exponent as i64;
+        let mut digits = 0;
+        let mut significant = 0;
+        let mut seen_point = false;
+
+        for c in mantissa.chars() {
+            match c {
+                '0'..='9' => {
+                    digits += 1;
+
+                    if significant < MAX_DIGITS {
+                        value = value * 10.0 + DoubleDouble::from((c as u8 - b'0') as f64);
+
+                        if seen_point {
+                            exponent -= 1;
+                        }
+                    } else if !seen_point {
+                        exponent += 1;
+                    }
+
+                    // Leading zeros don't use up any precision
+                    if value.hi != 0.0 {
+                        significant += 1;
+                    }
+                }
+                '.' if !seen_point => seen_point = true,
+                _ => return Err(ParseDoubleDoubleError),
+            }
+        }
+
+        if digits == 0 {
+            return Err(ParseDoubleDoubleError);
+        }
+
+        // The value is now a whole number below 10^MAX_DIGITS, so anything past these limits is
+        // out of range anyway. Clamping keeps the scaling loop short.
+        let mut exponent = exponent.clamp(-400 - MAX_DIGITS, 400);
+
+        // Products near the ends of the f64 range lose the low part, so the scaling happens
+        // 2^64 further from the edge, and is moved back exactly afterwards
+        let (before, after) = if exponent > 0 {
+            (1.0 / EDGE_MARGIN, EDGE_MARGIN)
+        } else {
+            (EDGE_MARGIN, 1.0 / EDGE_MARGIN)
+        };
+
+        value = value * before;
+
+        while exponent != 0 && value.hi != 0.0 && value.hi.is_finite() {
+            let step = exponent.clamp(-MAX_SCALE, MAX_SCALE);
+
+            if step < 0 {
+                value /= pow10(-step);
+            } else {
+                value *= pow10(step);
+            }
+
+            exponent -= step;
+        }
+
+        value = value * after;
+
+        // Overflow and underflow leave NaN in the parts, so saturate instead
+        if !value.hi.is_finite() {
+            value = DoubleDouble::from(f64::INFINITY);
+        } else if value.lo.is_nan() || value.hi.abs() < f64::MIN_POSITIVE {
+            value = DoubleDouble::from(value.hi);
+        }
+
+        Ok(if negative { -value } else { value })
+    }
+}
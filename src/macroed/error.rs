@@ -0,0 +1,54 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error returned when parsing a vector from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVec2Error {
+    /// The string did not contain exactly two components. Holds the number that was found.
+    WrongCount(usize),
+    /// A component could not be parsed as the vector's scalar type. Holds the component's text.
+    InvalidComponent(String),
+    /// The text before the parentheses was not the name of the vector being parsed. Holds that
+    /// text.
+    WrongName(String),
+}
+
+impl fmt::Display for ParseVec2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseVec2Error::WrongCount(n) => {
+                write!(f, "Expected 2 components, but instead found '{}'", n)
+            }
+            ParseVec2Error::InvalidComponent(s) => write!(f, "Invalid component '{}'", s),
+            ParseVec2Error::WrongName(s) => write!(f, "Unexpected vector name '{}'", s),
+        }
+    }
+}
+
+impl Error for ParseVec2Error {}
+
+/// An error returned when converting a vector with a zero component into a vector of
+/// [`NonZero`](std::num::NonZero) numbers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ZeroComponentError;
+
+impl fmt::Display for ZeroComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Expected both components to be non-zero")
+    }
+}
+
+impl Error for ZeroComponentError {}
+
+/// An error returned when a vector's components don't fit in the scalar type of the vector it is
+/// being converted into, like [`TryFromIntError`](std::num::TryFromIntError).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TryFromVec2Error;
+
+impl fmt::Display for TryFromVec2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vector component out of range for the target type")
+    }
+}
+
+impl Error for TryFromVec2Error {}
@@ -0,0 +1,160 @@
+use std::collections::hash_set;
+use std::collections::HashSet;
+
+use super::{Vec2isize, Vec2usize};
+
+// Grid //
+
+/// A fixed-size 2D grid of cells, stored row by row.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Grid2, Vec2isize, Vec2usize};
+/// let mut grid = Grid2::new(Vec2usize::new(4, 3), '.');
+///
+/// *grid.get_mut(Vec2isize::new(1, 2)).unwrap() = '#';
+///
+/// assert_eq!(grid.get(Vec2isize::new(1, 2)), Some(&'#'));
+/// assert_eq!(grid.get(Vec2isize::new(-1, 0)), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid2<T> {
+    size: Vec2usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid2<T> {
+    /// Creates a new grid where every cell is `fill`.
+    pub fn new(size: Vec2usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid2 {
+            size,
+            cells: vec![fill; size.x * size.y],
+        }
+    }
+
+    /// Creates a grid from a list of cells, stored row by row.
+    ///
+    /// Returns [`None`] if the number of cells doesn't match the size.
+    pub fn from_vec(size: Vec2usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() == size.x * size.y {
+            Some(Grid2 { size, cells })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the width and height of the grid.
+    pub fn size(&self) -> Vec2usize {
+        self.size
+    }
+
+    /// Returns true if `pos` is inside of the grid.
+    pub fn contains(&self, pos: Vec2isize) -> bool {
+        self.index(pos).is_some()
+    }
+
+    /// Returns a reference to the cell at `pos`, or [`None`] if it is outside of the grid.
+    pub fn get(&self, pos: Vec2isize) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    /// Returns a mutable reference to the cell at `pos`, or [`None`] if it is outside of the
+    /// grid.
+    pub fn get_mut(&mut self, pos: Vec2isize) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns the cells, stored row by row.
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    fn index(&self, pos: Vec2isize) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.size.x)?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.size.y)?;
+
+        Some(y * self.size.x + x)
+    }
+}
+
+// Region //
+
+/// A set of grid cells, such as the area visible from a point.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Region2, Vec2isize};
+/// let region: Region2 = [Vec2isize::new(0, 0), Vec2isize::new(1, 0)].into_iter().collect();
+///
+/// assert!(region.contains(Vec2isize::new(1, 0)));
+/// assert_eq!(region.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Region2 {
+    cells: HashSet<Vec2isize>,
+}
+
+impl Region2 {
+    /// Creates a new, empty region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a cell to the region, returning false if it was already there.
+    pub fn insert(&mut self, cell: Vec2isize) -> bool {
+        self.cells.insert(cell)
+    }
+
+    /// Removes a cell from the region, returning false if it wasn't there.
+    pub fn remove(&mut self, cell: Vec2isize) -> bool {
+        self.cells.remove(&cell)
+    }
+
+    /// Returns true if the region includes `cell`.
+    pub fn contains(&self, cell: Vec2isize) -> bool {
+        self.cells.contains(&cell)
+    }
+
+    /// Returns the number of cells in the region.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if the region has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns an iterator over the cells in the region, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = Vec2isize> + '_ {
+        self.cells.iter().copied()
+    }
+}
+
+impl FromIterator<Vec2isize> for Region2 {
+    fn from_iter<I: IntoIterator<Item = Vec2isize>>(iter: I) -> Self {
+        Region2 {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Vec2isize> for Region2 {
+    fn extend<I: IntoIterator<Item = Vec2isize>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+impl IntoIterator for Region2 {
+    type Item = Vec2isize;
+    type IntoIter = hash_set::IntoIter<Vec2isize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
@@ -0,0 +1,39 @@
+use std::ops::Neg;
+
+use half::f16;
+
+use super::{Vec2f16, Vec2f32};
+
+impl Vec2f16 {
+    /// Converts a [`Vec2f32`], rounding each component to the nearest [`f16`].
+    ///
+    /// Components too large for an `f16` become infinite.
+    pub fn from_vec2f32(v: Vec2f32) -> Self {
+        Vec2f16::new(f16::from_f32(v.x), f16::from_f32(v.y))
+    }
+
+    /// Converts to a [`Vec2f32`], which is lossless.
+    pub fn to_vec2f32(self) -> Vec2f32 {
+        Vec2f32::new(self.x.to_f32(), self.y.to_f32())
+    }
+}
+
+impl From<Vec2f32> for Vec2f16 {
+    fn from(v: Vec2f32) -> Self {
+        Vec2f16::from_vec2f32(v)
+    }
+}
+
+impl From<Vec2f16> for Vec2f32 {
+    fn from(v: Vec2f16) -> Self {
+        v.to_vec2f32()
+    }
+}
+
+impl Neg for Vec2f16 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vec2f16::new(-self.x, -self.y)
+    }
+}
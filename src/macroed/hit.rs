@@ -0,0 +1,57 @@
+use super::polygon::loop_contains;
+use super::Vec2f32;
+
+/// Returns true if `point` is inside the axis-aligned rectangle from `min` to `max` or on its
+/// edge.
+///
+/// This is the same test as [`Rect::contains`](super::Rect::contains), for when the corners
+/// are stored separately.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{point_in_rect, Vec2f32};
+/// let min = Vec2f32::new(0.0, 0.0);
+/// let max = Vec2f32::new(10.0, 5.0);
+///
+/// assert!(point_in_rect(Vec2f32::new(3.0, 5.0), min, max));
+/// assert!(!point_in_rect(Vec2f32::new(3.0, 6.0), min, max));
+/// ```
+pub fn point_in_rect(point: Vec2f32, min: Vec2f32, max: Vec2f32) -> bool {
+    min.x <= point.x && point.x <= max.x && min.y <= point.y && point.y <= max.y
+}
+
+/// Returns true if `point` is inside the circle around `center` or on its edge.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{point_in_circle, Vec2f32};
+/// let center = Vec2f32::new(1.0, 1.0);
+///
+/// assert!(point_in_circle(Vec2f32::new(1.0, 3.0), center, 2.0));
+/// assert!(!point_in_circle(Vec2f32::new(3.0, 3.0), center, 2.0));
+/// ```
+pub fn point_in_circle(point: Vec2f32, center: Vec2f32, radius: f32) -> bool {
+    (point - center).mag2() <= radius * radius
+}
+
+/// Returns true if `point` is inside the triangle `a`, `b`, `c` or on one of its edges.
+///
+/// The vertices may go around in either direction.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{point_in_triangle, Vec2f32};
+/// let a = Vec2f32::new(0.0, 0.0);
+/// let b = Vec2f32::new(4.0, 0.0);
+/// let c = Vec2f32::new(0.0, 4.0);
+///
+/// assert!(point_in_triangle(Vec2f32::new(1.0, 1.0), a, b, c));
+/// assert!(point_in_triangle(Vec2f32::new(1.0, 1.0), a, c, b));
+/// assert!(!point_in_triangle(Vec2f32::new(3.0, 3.0), a, b, c));
+/// ```
+pub fn point_in_triangle(point: Vec2f32, a: Vec2f32, b: Vec2f32, c: Vec2f32) -> bool {
+    loop_contains(&[a, b, c], point)
+}
@@ -0,0 +1,200 @@
+use std::ops::{Add, Sub};
+
+/// Adapters for iterators over points, such as the vertices of a polyline.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Vec2IterExt, Vec2i32};
+/// let path = [Vec2i32::new(0, 0), Vec2i32::new(2, 0), Vec2i32::new(2, 3)];
+///
+/// let deltas: Vec<_> = path.iter().copied().deltas().collect();
+/// assert_eq!(deltas, [Vec2i32::new(2, 0), Vec2i32::new(0, 3)]);
+///
+/// let rebuilt: Vec<_> = deltas.into_iter().cumulative_sum().collect();
+/// assert_eq!(rebuilt, path[1..]);
+/// ```
+pub trait Vec2IterExt: Iterator + Sized
+where
+    Self::Item: Copy,
+{
+    /// Returns an iterator over each pair of consecutive points.
+    ///
+    /// `[a, b, c]` gives `(a, b)` and `(b, c)`.
+    fn segments(self) -> Segments<Self> {
+        Segments {
+            iter: self,
+            prev: None,
+        }
+    }
+
+    /// Returns an iterator over each pair of consecutive points, plus a final pair joining the
+    /// last point back to the first.
+    ///
+    /// `[a, b, c]` gives `(a, b)`, `(b, c)`, and `(c, a)`. Fewer than two points give no pairs.
+    fn closed_segments(self) -> ClosedSegments<Self> {
+        ClosedSegments {
+            iter: self,
+            first: None,
+            prev: None,
+            done: false,
+        }
+    }
+
+    /// Returns an iterator over the difference between each point and the one before it.
+    fn deltas(self) -> Deltas<Self>
+    where
+        Self::Item: Sub<Output = Self::Item>,
+    {
+        Deltas {
+            iter: self,
+            prev: None,
+        }
+    }
+
+    /// Returns an iterator over the running total of the items.
+    ///
+    /// This is the opposite of [`Vec2IterExt::deltas`], turning a list of movements into a list
+    /// of positions.
+    fn cumulative_sum(self) -> CumulativeSum<Self>
+    where
+        Self::Item: Add<Output = Self::Item>,
+    {
+        CumulativeSum {
+            iter: self,
+            sum: None,
+        }
+    }
+}
+
+impl<I: Iterator> Vec2IterExt for I where I::Item: Copy {}
+
+/// An iterator over consecutive pairs of points. See [`Vec2IterExt::segments`].
+#[derive(Clone, Debug)]
+pub struct Segments<I: Iterator> {
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Segments<I>
+where
+    I::Item: Copy,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+
+        self.prev = Some(next);
+
+        Some((prev, next))
+    }
+}
+
+/// An iterator over consecutive pairs of points that wraps back to the start. See
+/// [`Vec2IterExt::closed_segments`].
+#[derive(Clone, Debug)]
+pub struct ClosedSegments<I: Iterator> {
+    iter: I,
+    first: Option<I::Item>,
+    prev: Option<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator> Iterator for ClosedSegments<I>
+where
+    I::Item: Copy,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => {
+                let first = self.iter.next()?;
+                self.first = Some(first);
+                first
+            }
+        };
+
+        match self.iter.next() {
+            Some(next) => {
+                self.prev = Some(next);
+                Some((prev, next))
+            }
+            None => {
+                self.done = true;
+
+                // Only close the loop if there was at least one other segment
+                match (self.first, self.prev) {
+                    (Some(first), Some(last)) => Some((last, first)),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over the differences between consecutive points. See [`Vec2IterExt::deltas`].
+#[derive(Clone, Debug)]
+pub struct Deltas<I: Iterator> {
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Deltas<I>
+where
+    I::Item: Copy + Sub<Output = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+
+        self.prev = Some(next);
+
+        Some(next - prev)
+    }
+}
+
+/// An iterator over the running total of its items. See [`Vec2IterExt::cumulative_sum`].
+#[derive(Clone, Debug)]
+pub struct CumulativeSum<I: Iterator> {
+    iter: I,
+    sum: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for CumulativeSum<I>
+where
+    I::Item: Copy + Add<Output = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next()?;
+        let sum = match self.sum {
+            Some(sum) => sum + next,
+            None => next,
+        };
+
+        self.sum = Some(sum);
+
+        Some(sum)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
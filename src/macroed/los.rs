@@ -0,0 +1,117 @@
+//! Line of sight queries over tile grids.
+//!
+//! # Example
+//!
+//! ```
+//! # use manyvecs::macroed::{los, Grid2, Vec2isize, Vec2usize};
+//! // A 5x5 room with a pillar right of the center
+//! let mut walls = Grid2::new(Vec2usize::new(5, 5), false);
+//! *walls.get_mut(Vec2isize::new(3, 2)).unwrap() = true;
+//!
+//! let visible = los::visible_from(Vec2isize::new(2, 2), &walls, 5);
+//!
+//! // The pillar itself is visible, but the tile behind it is not
+//! assert!(visible.contains(Vec2isize::new(3, 2)));
+//! assert!(!visible.contains(Vec2isize::new(4, 2)));
+//! assert!(visible.contains(Vec2isize::new(0, 0)));
+//! ```
+
+use super::{Grid2, Region2, Vec2isize};
+
+/// Multipliers that turn an octant-relative offset into a grid offset, one row per octant.
+const OCTANTS: [[isize; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+/// Returns every cell visible from `origin` within `radius` cells, using recursive shadowcasting.
+///
+/// Cells in `walls` that are `true` block sight, but are themselves visible. Cells outside of the
+/// grid are treated as walls and are never included.
+pub fn visible_from(origin: Vec2isize, walls: &Grid2<bool>, radius: usize) -> Region2 {
+    let mut region = Region2::new();
+
+    if !walls.contains(origin) {
+        return region;
+    }
+
+    region.insert(origin);
+
+    let caster = Caster {
+        origin,
+        walls,
+        radius: radius as isize,
+    };
+
+    for transform in OCTANTS {
+        caster.cast(&mut region, 1, 1.0, 0.0, transform);
+    }
+
+    region
+}
+
+struct Caster<'a> {
+    origin: Vec2isize,
+    walls: &'a Grid2<bool>,
+    radius: isize,
+}
+
+impl Caster<'_> {
+    fn blocks(&self, cell: Vec2isize) -> bool {
+        self.walls.get(cell).copied().unwrap_or(true)
+    }
+
+    /// Scans one octant row by row, recursing whenever a wall splits the visible slope range.
+    fn cast(&self, region: &mut Region2, row: isize, mut start: f32, end: f32, t: [isize; 4]) {
+        if start < end {
+            return;
+        }
+
+        let mut next_start = start;
+
+        for j in row..=self.radius {
+            let dy = -j;
+            let mut blocked = false;
+
+            for dx in -j..=0 {
+                let cell =
+                    self.origin + Vec2isize::new(dx * t[0] + dy * t[1], dx * t[2] + dy * t[3]);
+                let left = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+                let right = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+
+                if start < right {
+                    continue;
+                } else if end > left {
+                    break;
+                }
+
+                if dx * dx + dy * dy <= self.radius * self.radius && self.walls.contains(cell) {
+                    region.insert(cell);
+                }
+
+                if blocked {
+                    if self.blocks(cell) {
+                        next_start = right;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if self.blocks(cell) && j < self.radius {
+                    blocked = true;
+                    self.cast(region, j + 1, start, left, t);
+                    next_start = right;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! The transcendental functions used by floating point vectors.
+//!
+//! Hardware and standard library implementations of these can differ in their last bit between
+//! platforms. With the `deterministic` feature they are routed through `libm`'s software
+//! implementations instead, which give bit-identical results everywhere.
+
+/// A scalar with a square root, needed by the `"floating"` feature of
+/// [`add_vec2_feature!`](crate::add_vec2_feature).
+///
+/// This is implemented for [`f32`] and [`f64`], and can be implemented for other scalar types
+/// to give their vectors magnitudes.
+pub trait Real: Copy {
+    /// The additive identity, `0`.
+    const ZERO: Self;
+
+    /// The multiplicative identity, `1`.
+    const ONE: Self;
+
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+
+    /// Returns `sqrt(self^2 + other^2)` without overflowing or underflowing in between.
+    fn hypot(self, other: Self) -> Self;
+
+    /// Returns `self * a + b`, rounding only once where the type supports it.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+/// A scalar with an absolute value, needed by the `"signed"` feature of
+/// [`add_vec2_feature!`](crate::add_vec2_feature).
+///
+/// Signed integers measure magnitudes in the unsigned type of the same size, which holds the
+/// magnitude of `MIN` and the distance between any two values. Other scalars, including unsigned
+/// integers, use their own type.
+pub trait Magnitude: Copy {
+    /// The type of absolute values and distances.
+    type Output: Copy + PartialOrd + std::ops::Add<Output = Self::Output>;
+
+    /// Returns the absolute value.
+    fn magnitude(self) -> Self::Output;
+
+    /// Returns the absolute difference between two numbers.
+    fn magnitude_diff(self, other: Self) -> Self::Output;
+}
+
+macro_rules! impl_magnitude {
+    (@unsigned $($type_:ty),*) => {
+        $(
+            impl Magnitude for $type_ {
+                type Output = Self;
+
+                #[inline]
+                fn magnitude(self) -> Self {
+                    self
+                }
+
+                #[inline]
+                fn magnitude_diff(self, other: Self) -> Self {
+                    self.abs_diff(other)
+                }
+            }
+        )*
+    };
+    ($($type_:ty => $unsigned:ty),*) => {
+        $(
+            impl Magnitude for $type_ {
+                type Output = $unsigned;
+
+                #[inline]
+                fn magnitude(self) -> $unsigned {
+                    self.unsigned_abs()
+                }
+
+                #[inline]
+                fn magnitude_diff(self, other: Self) -> $unsigned {
+                    self.abs_diff(other)
+                }
+            }
+        )*
+    };
+    ($($type_:ty),*) => {
+        $(
+            impl Magnitude for $type_ {
+                type Output = Self;
+
+                #[inline]
+                fn magnitude(self) -> Self {
+                    self.abs()
+                }
+
+                #[inline]
+                fn magnitude_diff(self, other: Self) -> Self {
+                    (self - other).abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_magnitude!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+impl_magnitude!(@unsigned u8, u16, u32, u64, u128, usize);
+impl_magnitude!(f32, f64);
+#[cfg(feature = "extended")]
+impl_magnitude!(super::DoubleDouble);
+#[cfg(feature = "fixed")]
+impl_magnitude!(fixed::types::I16F16);
+
+/// A scalar with trigonometric and power functions.
+// Some of these are only used by optional features
+#[allow(dead_code)]
+pub(crate) trait Transcendental: Real {
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn tan(self) -> Self;
+
+    fn sin_cos(self) -> (Self, Self);
+
+    fn atan2(self, other: Self) -> Self;
+
+    fn powf(self, n: Self) -> Self;
+
+    fn cbrt(self) -> Self;
+
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    fn log2(self) -> Self;
+
+    fn log10(self) -> Self;
+}
+
+macro_rules! impl_transcendental {
+    (
+        $type_:ident,
+        $sqrt:ident,
+        $hypot:ident,
+        $fma:ident,
+        $sin:ident,
+        $cos:ident,
+        $tan:ident,
+        $sincos:ident,
+        $atan2:ident,
+        $pow:ident,
+        $cbrt:ident,
+        $exp:ident,
+        $ln:ident,
+        $log2:ident,
+        $log10:ident
+    ) => {
+        impl Real for $type_ {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$sqrt(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::sqrt(self);
+            }
+
+            #[inline]
+            fn hypot(self, other: Self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$hypot(self, other);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::hypot(self, other);
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$fma(self, a, b);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::mul_add(self, a, b);
+            }
+        }
+
+        impl Transcendental for $type_ {
+            #[inline]
+            fn sin(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$sin(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::sin(self);
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$cos(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::cos(self);
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$tan(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::tan(self);
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                #[cfg(feature = "deterministic")]
+                return libm::$sincos(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::sin_cos(self);
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$atan2(self, other);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::atan2(self, other);
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$pow(self, n);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::powf(self, n);
+            }
+
+            #[inline]
+            fn cbrt(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$cbrt(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::cbrt(self);
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$exp(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::exp(self);
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$ln(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::ln(self);
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$log2(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::log2(self);
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                #[cfg(feature = "deterministic")]
+                return libm::$log10(self);
+                #[cfg(not(feature = "deterministic"))]
+                return $type_::log10(self);
+            }
+        }
+    };
+}
+
+impl_transcendental!(
+    f32, sqrtf, hypotf, fmaf, sinf, cosf, tanf, sincosf, atan2f, powf, cbrtf, expf, logf, log2f,
+    log10f
+);
+impl_transcendental!(
+    f64, sqrt, hypot, fma, sin, cos, tan, sincos, atan2, pow, cbrt, exp, log, log2, log10
+);
+
+#[cfg(feature = "extended")]
+impl Real for super::DoubleDouble {
+    const ZERO: Self = super::DoubleDouble { hi: 0.0, lo: 0.0 };
+    const ONE: Self = super::DoubleDouble { hi: 1.0, lo: 0.0 };
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        super::DoubleDouble::sqrt(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let (a, b) = (self.abs(), other.abs());
+        let (big, small) = if a >= b { (a, b) } else { (b, a) };
+
+        if big.hi == 0.0 || !big.hi.is_finite() {
+            return big;
+        }
+
+        // Dividing by the larger component keeps the square in range
+        let ratio = small / big;
+        big * (ratio * ratio + super::DoubleDouble::from(1.0)).sqrt()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        // The error of the product is already carried in the low part
+        self * a + b
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl Real for fixed::types::I16F16 {
+    const ZERO: Self = fixed::types::I16F16::ZERO;
+    const ONE: Self = fixed::types::I16F16::ONE;
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        fixed::types::I16F16::sqrt(self)
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        fixed::types::I16F16::hypot(self, other)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        fixed::types::I16F16::mul_add(self, a, b)
+    }
+}
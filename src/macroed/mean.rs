@@ -0,0 +1,245 @@
+use super::*;
+
+/// A vector type that can be averaged by [`centroid`] and [`weighted_centroid`].
+///
+/// Integer components are summed exactly in 256 bits, so averaging any number of large integer
+/// vectors doesn't overflow. Weighted integer sums use `i128` or `u128` and are checked instead.
+/// Float components are summed in `f64`. This is implemented for every vector in this module, and
+/// cannot be implemented outside of it.
+pub trait Centroid: Copy + sealed::Sealed {
+    /// The type of the weights passed to [`weighted_centroid`].
+    type Weight: Copy;
+
+    #[doc(hidden)]
+    fn centroid_of(points: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn weighted_centroid_of(points: &[Self], weights: &[Self::Weight]) -> Option<Self>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Returns the average of a list of points, or [`None`] if there are no points.
+///
+/// Integer results are rounded towards zero.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{centroid, Vec2i8};
+/// let points = [Vec2i8::new(100, -100), Vec2i8::new(120, -120), Vec2i8::new(110, 0)];
+///
+/// // The sums don't fit in an i8, but the average does
+/// assert_eq!(centroid(&points), Some(Vec2i8::new(110, -73)));
+/// assert_eq!(centroid::<Vec2i8>(&[]), None);
+/// ```
+pub fn centroid<V: Centroid>(points: &[V]) -> Option<V> {
+    V::centroid_of(points)
+}
+
+/// Returns the weighted average of a list of points, or [`None`] if there are no points or the
+/// weights add up to zero.
+///
+/// Integer results are rounded towards zero. They are also [`None`] if the weighted sums overflow
+/// `i128` or `u128`, or if the average doesn't fit in the component type, which can happen with
+/// negative weights.
+///
+/// # Panics
+///
+/// Panics if `points` and `weights` have different lengths.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{weighted_centroid, Vec2f32};
+/// let points = [Vec2f32::new(0.0, 0.0), Vec2f32::new(4.0, 8.0)];
+///
+/// assert_eq!(weighted_centroid(&points, &[3.0, 1.0]), Some(Vec2f32::new(1.0, 2.0)));
+/// ```
+pub fn weighted_centroid<V: Centroid>(points: &[V], weights: &[V::Weight]) -> Option<V> {
+    assert_eq!(
+        points.len(),
+        weights.len(),
+        "points and weights must have the same length"
+    );
+
+    V::weighted_centroid_of(points, weights)
+}
+
+/// The low 64 bits of a 128-bit integer.
+const LOW: u128 = u64::MAX as u128;
+
+/// An exact sum of up to `2^64` 128-bit integers, stored as `hi * 2^64 + lo`.
+///
+/// Each value is split into its high and low 64 bits, so neither part can overflow.
+#[derive(Copy, Clone, Default)]
+struct WideSum {
+    hi: i128,
+    lo: u128,
+}
+
+impl WideSum {
+    fn add_signed(&mut self, v: i128) {
+        self.hi += v >> 64;
+        self.lo += v as u128 & LOW;
+    }
+
+    fn add_unsigned(&mut self, v: u128) {
+        self.hi += (v >> 64) as i128;
+        self.lo += v & LOW;
+    }
+
+    /// Divides the sum by `n`, rounding towards zero.
+    ///
+    /// The result is returned as the bits of a 128-bit integer, which is correct as long as the
+    /// quotient fits in the type it is cast to.
+    fn div_trunc(self, n: u64) -> u128 {
+        let hi = self.hi + (self.lo >> 64) as i128;
+        let lo = self.lo & LOW;
+
+        // Long division with 64-bit digits, where the first remainder is less than `n`
+        let n = n as i128;
+        let (q1, r1) = (hi.div_euclid(n), hi.rem_euclid(n));
+        let t = (r1 as u128) << 64 | lo;
+        let (q2, r2) = (t / n as u128, t % n as u128);
+
+        let floor = ((q1 as u128) << 64).wrapping_add(q2);
+
+        if hi < 0 && r2 != 0 {
+            floor.wrapping_add(1)
+        } else {
+            floor
+        }
+    }
+}
+
+/// A macro for implementing [`Centroid`] on [`Vec2`] structs.
+macro_rules! impl_centroid {
+    ($name:ident, $type_:ty, $acc:ty, $one:expr, $widen:expr, $narrow:expr) => {
+        impl sealed::Sealed for $name {}
+
+        impl Centroid for $name {
+            type Weight = $type_;
+
+            fn centroid_of(points: &[Self]) -> Option<Self> {
+                if points.is_empty() {
+                    return None;
+                }
+
+                let widen: fn($type_) -> $acc = $widen;
+                let narrow: fn($acc) -> $type_ = $narrow;
+                let zero = widen(Default::default());
+                let (mut x, mut y, mut n) = (zero, zero, zero);
+
+                for p in points {
+                    x += widen(p.x);
+                    y += widen(p.y);
+                    n += $one;
+                }
+
+                Some(Self::new(narrow(x / n), narrow(y / n)))
+            }
+
+            fn weighted_centroid_of(points: &[Self], weights: &[$type_]) -> Option<Self> {
+                let widen: fn($type_) -> $acc = $widen;
+                let narrow: fn($acc) -> $type_ = $narrow;
+                let zero = widen(Default::default());
+                let (mut x, mut y, mut total) = (zero, zero, zero);
+
+                for (p, &w) in points.iter().zip(weights) {
+                    let w = widen(w);
+
+                    x += widen(p.x) * w;
+                    y += widen(p.y) * w;
+                    total += w;
+                }
+
+                if total == zero {
+                    return None;
+                }
+
+                Some(Self::new(narrow(x / total), narrow(y / total)))
+            }
+        }
+    };
+    ($name:ident, $type_:ty, $acc:ty) => {
+        impl_centroid!($name, $type_, $acc, 1 as $acc, |v| v as $acc, |v| v
+            as $type_);
+    };
+    // Integers are summed exactly, and take `add_signed` or `add_unsigned` to widen
+    ($name:ident, $type_:ty, $acc:ty, $add:ident) => {
+        impl sealed::Sealed for $name {}
+
+        impl Centroid for $name {
+            type Weight = $type_;
+
+            fn centroid_of(points: &[Self]) -> Option<Self> {
+                if points.is_empty() {
+                    return None;
+                }
+
+                let (mut x, mut y) = (WideSum::default(), WideSum::default());
+
+                for p in points {
+                    x.$add(p.x as $acc);
+                    y.$add(p.y as $acc);
+                }
+
+                let n = points.len() as u64;
+                Some(Self::new(
+                    x.div_trunc(n) as $acc as $type_,
+                    y.div_trunc(n) as $acc as $type_,
+                ))
+            }
+
+            fn weighted_centroid_of(points: &[Self], weights: &[$type_]) -> Option<Self> {
+                let (mut x, mut y, mut total): ($acc, $acc, $acc) = (0, 0, 0);
+
+                for (p, &w) in points.iter().zip(weights) {
+                    let w = w as $acc;
+
+                    x = x.checked_add((p.x as $acc).checked_mul(w)?)?;
+                    y = y.checked_add((p.y as $acc).checked_mul(w)?)?;
+                    total = total.checked_add(w)?;
+                }
+
+                if total == 0 {
+                    return None;
+                }
+
+                Some(Self::new(
+                    <$type_>::try_from(x.checked_div(total)?).ok()?,
+                    <$type_>::try_from(y.checked_div(total)?).ok()?,
+                ))
+            }
+        }
+    };
+}
+
+impl_centroid!(Vec2f32, f32, f64);
+impl_centroid!(Vec2f64, f64, f64);
+#[cfg(feature = "extended")]
+impl_centroid!(
+    Vec2dd,
+    DoubleDouble,
+    DoubleDouble,
+    DoubleDouble::from(1.0),
+    |v| v,
+    |v| v
+);
+
+impl_centroid!(Vec2u8, u8, u128, add_unsigned);
+impl_centroid!(Vec2u16, u16, u128, add_unsigned);
+impl_centroid!(Vec2u32, u32, u128, add_unsigned);
+impl_centroid!(Vec2u64, u64, u128, add_unsigned);
+impl_centroid!(Vec2u128, u128, u128, add_unsigned);
+impl_centroid!(Vec2usize, usize, u128, add_unsigned);
+
+impl_centroid!(Vec2i8, i8, i128, add_signed);
+impl_centroid!(Vec2i16, i16, i128, add_signed);
+impl_centroid!(Vec2i32, i32, i128, add_signed);
+impl_centroid!(Vec2i64, i64, i128, add_signed);
+impl_centroid!(Vec2i128, i128, i128, add_signed);
+impl_centroid!(Vec2isize, isize, i128, add_signed);
@@ -1,9 +1,96 @@
+mod angle;
+mod area;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod axis;
+pub mod batch;
+mod bits;
+mod capsule;
+mod cardinal;
+mod cast;
+mod circle;
+#[cfg(feature = "color")]
+mod color;
+mod consts;
+mod convert;
+mod direction;
+#[cfg(feature = "extended")]
+mod double;
+mod error;
+mod grid;
+#[cfg(feature = "half")]
+mod half_float;
+mod hit;
+mod iter;
+pub mod los;
+mod math;
+mod mean;
+mod morton;
+mod motion;
+mod nonzero;
+mod polygon;
+#[cfg(feature = "rand")]
+mod random;
+mod ray;
+mod rect;
+mod rotation;
+#[cfg(feature = "rand")]
+pub mod sampling;
+mod segment;
+mod shape;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[doc(hidden)]
+pub mod simd;
+mod size;
+mod space;
+mod spatial;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod transcendental;
+mod triangle;
 mod vec2;
+mod world;
 
 #[cfg(test)]
 mod tests;
 
+pub use self::angle::*;
+pub use self::area::*;
+#[cfg(target_has_atomic = "64")]
+pub use self::atomic::*;
+pub use self::axis::*;
+pub use self::capsule::*;
+pub use self::cardinal::*;
+pub use self::cast::*;
+pub use self::circle::*;
+#[cfg(feature = "color")]
+pub use self::color::*;
+pub use self::direction::*;
+#[cfg(feature = "extended")]
+pub use self::double::*;
+pub use self::error::*;
+pub use self::grid::*;
+pub use self::hit::*;
+pub use self::iter::*;
+pub use self::math::{Magnitude, Real};
+pub use self::mean::*;
+pub use self::motion::*;
+pub use self::nonzero::*;
+pub use self::polygon::*;
+pub use self::ray::*;
+pub use self::rect::*;
+pub use self::rotation::*;
+pub use self::segment::*;
+pub use self::shape::*;
+pub use self::size::*;
+pub use self::space::*;
+pub use self::spatial::*;
+pub use self::triangle::*;
 pub use self::vec2::*;
+pub use self::world::*;
+
+#[cfg(feature = "derive")]
+pub use manyvecs_derive::Vec2Like;
 
 /// The default Vec2 implementation.
 pub type Vec2 = Vec2f32;
@@ -19,3 +106,6 @@ pub type Vec2u = Vec2usize;
 
 /// The default signed integer Vec2 implementation.
 pub type Vec2i = Vec2isize;
+
+/// The default Size2 implementation.
+pub type Size2 = Size2f32;
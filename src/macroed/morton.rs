@@ -0,0 +1,57 @@
+use super::{Vec2u16, Vec2u32};
+
+/// Spreads the lower 32 bits of `v` out so there is a zero bit between each of them.
+fn spread(v: u64) -> u64 {
+    let mut v = v & 0x0000_0000_FFFF_FFFF;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// The inverse of [`spread`], packing every other bit of `v` together.
+fn compact(v: u64) -> u64 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+    (v | (v >> 16)) & 0x0000_0000_FFFF_FFFF
+}
+
+/// A macro for adding Morton encoding to unsigned [`Vec2`](super::Vec2) structs.
+macro_rules! impl_morton {
+    ($name:ident, $type_:ty, $code:ty) => {
+        impl $name {
+            /// Interleaves the bits of `x` and `y` into a single Morton code (also called a
+            /// Z-order curve), with `x` in the even bits and `y` in the odd bits.
+            ///
+            /// Sorting points by their Morton code keeps points that are close together in 2D
+            /// close together in memory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+            #[doc = concat!("let v = ", stringify!($name), "::new(0b11, 0b01);")]
+            ///
+            /// assert_eq!(v.to_morton(), 0b0111);
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_morton(0b0111), v);")]
+            /// ```
+            pub fn to_morton(&self) -> $code {
+                (spread(self.x as u64) | (spread(self.y as u64) << 1)) as $code
+            }
+
+            /// Splits a Morton code back into a vector. This is the inverse of
+            #[doc = concat!("[`", stringify!($name), "::to_morton`].")]
+            pub fn from_morton(code: $code) -> Self {
+                let code = code as u64;
+                Self::new(compact(code) as $type_, compact(code >> 1) as $type_)
+            }
+        }
+    };
+}
+
+impl_morton!(Vec2u16, u16, u32);
+impl_morton!(Vec2u32, u32, u64);
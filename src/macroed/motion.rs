@@ -0,0 +1,336 @@
+use std::ops::*;
+
+use super::{Vec2f32, Vec2i32};
+
+// Point and Delta //
+
+/// An absolute position.
+///
+/// Points can be offset by a [`Delta2`], and subtracting two points gives the [`Delta2`] between
+/// them, but two points cannot be added together.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Delta2, Point2};
+/// let cursor = Point2::new(10.0, 20.0);
+/// let moved = cursor + Delta2::new(2.0, -5.0);
+///
+/// assert_eq!(moved, Point2::new(12.0, 15.0));
+/// assert_eq!(moved - cursor, Delta2::new(2.0, -5.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Point2(pub Vec2f32);
+
+/// A relative movement, such as a mouse delta or scroll amount.
+///
+/// Unlike [`Point2`], deltas can be added together and scaled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Delta2(pub Vec2f32);
+
+impl Point2 {
+    /// Creates a new Point2.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Point2(Vec2f32::new(x, y))
+    }
+}
+
+impl Delta2 {
+    /// Creates a new Delta2.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Delta2(Vec2f32::new(x, y))
+    }
+}
+
+impl Add<Delta2> for Point2 {
+    type Output = Self;
+
+    fn add(self, rhs: Delta2) -> Self {
+        Point2(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign<Delta2> for Point2 {
+    fn add_assign(&mut self, rhs: Delta2) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub<Delta2> for Point2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Delta2) -> Self {
+        Point2(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign<Delta2> for Point2 {
+    fn sub_assign(&mut self, rhs: Delta2) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Sub<Self> for Point2 {
+    type Output = Delta2;
+
+    fn sub(self, rhs: Self) -> Delta2 {
+        Delta2(self.0 - rhs.0)
+    }
+}
+
+impl Add<Self> for Delta2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Delta2(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign<Self> for Delta2 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub<Self> for Delta2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Delta2(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign<Self> for Delta2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<f32> for Delta2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Delta2(self.0 * rhs)
+    }
+}
+
+impl Div<f32> for Delta2 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Delta2(self.0 / rhs)
+    }
+}
+
+impl Neg for Delta2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Delta2(-self.0)
+    }
+}
+
+// Conversion //
+
+impl From<Vec2f32> for Point2 {
+    fn from(v: Vec2f32) -> Self {
+        Point2(v)
+    }
+}
+
+impl From<Point2> for Vec2f32 {
+    fn from(v: Point2) -> Self {
+        v.0
+    }
+}
+
+impl From<Vec2f32> for Delta2 {
+    fn from(v: Vec2f32) -> Self {
+        Delta2(v)
+    }
+}
+
+impl From<Delta2> for Vec2f32 {
+    fn from(v: Delta2) -> Self {
+        v.0
+    }
+}
+
+// Motion //
+
+/// A movement event, which either jumps to a position or moves relative to the current one.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Delta2, Motion2, Point2};
+/// let position = Point2::new(5.0, 5.0);
+///
+/// assert_eq!(Motion2::Absolute(Point2::new(1.0, 2.0)).apply(position), Point2::new(1.0, 2.0));
+/// assert_eq!(Motion2::Relative(Delta2::new(1.0, 2.0)).apply(position), Point2::new(6.0, 7.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Motion2 {
+    /// Move to the given position, such as a touch or tablet event.
+    Absolute(Point2),
+    /// Move by the given amount, such as a mouse or scroll event.
+    Relative(Delta2),
+}
+
+impl Motion2 {
+    /// Returns the position after applying this motion to `position`.
+    pub fn apply(self, position: Point2) -> Point2 {
+        match self {
+            Motion2::Absolute(p) => p,
+            Motion2::Relative(d) => position + d,
+        }
+    }
+}
+
+// Subpixel Movement //
+
+/// Carries fractional movement between frames, so smooth velocities can drive whole-pixel
+/// positions.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{SubpixelAccumulator, Vec2f32, Vec2i32};
+/// let mut acc = SubpixelAccumulator::new();
+/// let velocity = Vec2f32::new(30.0, -15.0);
+///
+/// // Half a pixel isn't enough to move yet...
+/// assert_eq!(acc.step(velocity, 1.0 / 60.0), Vec2i32::new(0, 0));
+/// // ...but two halves are.
+/// assert_eq!(acc.step(velocity, 1.0 / 60.0), Vec2i32::new(1, 0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SubpixelAccumulator {
+    /// The movement that has not yet added up to a whole pixel.
+    pub remainder: Vec2f32,
+}
+
+impl SubpixelAccumulator {
+    /// Creates a new SubpixelAccumulator with no leftover movement.
+    pub const fn new() -> Self {
+        SubpixelAccumulator {
+            remainder: Vec2f32::new(0.0, 0.0),
+        }
+    }
+
+    /// Adds `velocity * dt` to the accumulated movement and returns the whole pixels to move this
+    /// frame.
+    ///
+    /// Whole pixels are truncated towards zero, so the remainder always has the same sign as the
+    /// movement that produced it.
+    pub fn step(&mut self, velocity: Vec2f32, dt: f32) -> Vec2i32 {
+        let total = self.remainder + velocity * dt;
+        let whole = Vec2f32::new(total.x.trunc(), total.y.trunc());
+
+        self.remainder = total - whole;
+
+        Vec2i32::new(whole.x as i32, whole.y as i32)
+    }
+}
+
+impl Default for SubpixelAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Fixed Timestep //
+
+/// Runs a simulation at a fixed rate while rendering smoothly in between steps.
+///
+/// Each frame, call [`FixedStep::advance`] with the frame time and run that many simulation steps,
+/// passing each new position to [`FixedStep::push`]. Then draw at
+/// `render_pos(fixed.alpha())`, which blends between the last two simulated positions.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{FixedStep, Vec2f32};
+/// let mut fixed = FixedStep::new(0.1, Vec2f32::new(0.0, 0.0));
+/// let velocity = Vec2f32::new(10.0, 0.0);
+///
+/// for _ in 0..fixed.advance(0.25) {
+///     let next = fixed.current + velocity * fixed.step;
+///     fixed.push(next);
+/// }
+///
+/// // Two steps ran, and we're halfway to the third.
+/// assert_eq!(fixed.current, Vec2f32::new(2.0, 0.0));
+/// assert_eq!(fixed.render_pos(0.5), Vec2f32::new(1.5, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FixedStep {
+    /// The length of a single simulation step.
+    pub step: f32,
+    /// The time that has passed but not yet been simulated.
+    pub accumulator: f32,
+    /// The position before the latest simulation step.
+    pub previous: Vec2f32,
+    /// The position after the latest simulation step.
+    pub current: Vec2f32,
+}
+
+impl FixedStep {
+    /// The most steps a single call to [`FixedStep::advance`] returns. Time beyond that is
+    /// dropped, so that a long pause doesn't make the simulation spend ages catching up.
+    pub const MAX_STEPS: u32 = 256;
+
+    /// Creates a new FixedStep starting at rest at `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not greater than zero.
+    pub const fn new(step: f32, position: Vec2f32) -> Self {
+        assert!(step > 0.0, "the step must be greater than zero");
+
+        FixedStep {
+            step,
+            accumulator: 0.0,
+            previous: position,
+            current: position,
+        }
+    }
+
+    /// Adds the time since the last frame and returns how many simulation steps should run, up
+    /// to [`FixedStep::MAX_STEPS`].
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        self.accumulator += frame_time;
+
+        let steps = (self.accumulator / self.step).floor();
+
+        if steps >= Self::MAX_STEPS as f32 {
+            // An infinite accumulator has no meaningful remainder, so it starts over
+            let leftover = self.accumulator.rem_euclid(self.step);
+            self.accumulator = if leftover.is_finite() { leftover } else { 0.0 };
+            return Self::MAX_STEPS;
+        }
+
+        // Negative and NaN counts become no steps at all
+        let steps = steps as u32;
+        self.accumulator = (self.accumulator - steps as f32 * self.step).max(0.0);
+
+        steps
+    }
+
+    /// Records the position produced by a simulation step.
+    pub fn push(&mut self, position: Vec2f32) {
+        self.previous = self.current;
+        self.current = position;
+    }
+
+    /// Returns how far the leftover time is into the next step, between `0` and `1`.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator / self.step
+    }
+
+    /// Returns the position to draw at, blending from the previous position at `0` to the current
+    /// one at `1`.
+    pub fn render_pos(&self, alpha: f32) -> Vec2f32 {
+        self.previous + (self.current - self.previous) * alpha
+    }
+}
@@ -0,0 +1,141 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use super::*;
+
+/// A macro for creating vectors whose components are never zero.
+macro_rules! create_nonzero_vec2 {
+    ($(#[$meta:meta])* $name:ident, $nonzero:ty, $type_:ty, $vec:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name {
+            pub x: $nonzero,
+            pub y: $nonzero,
+        }
+
+        impl $name {
+            /// Creates a new vector from non-zero components.
+            pub const fn new(x: $nonzero, y: $nonzero) -> Self {
+                $name { x, y }
+            }
+
+            /// Creates a new vector, or returns [`None`] if either component is zero.
+            pub const fn new_checked(x: $type_, y: $type_) -> Option<Self> {
+                match (<$nonzero>::new(x), <$nonzero>::new(y)) {
+                    (Some(x), Some(y)) => Some($name { x, y }),
+                    _ => None,
+                }
+            }
+
+            /// Returns the vector with plain numbers as components.
+            pub const fn get(self) -> $vec {
+                $vec::new(self.x.get(), self.y.get())
+            }
+        }
+
+        impl TryFrom<$vec> for $name {
+            type Error = ZeroComponentError;
+
+            fn try_from(v: $vec) -> Result<Self, Self::Error> {
+                Self::new_checked(v.x, v.y).ok_or(ZeroComponentError)
+            }
+        }
+
+        impl From<$name> for $vec {
+            fn from(v: $name) -> Self {
+                v.get()
+            }
+        }
+    };
+}
+
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroU8`]s.
+    Vec2NonZeroU8,
+    NonZeroU8,
+    u8,
+    Vec2u8
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroU16`]s.
+    Vec2NonZeroU16,
+    NonZeroU16,
+    u16,
+    Vec2u16
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroU32`]s, such as a scale factor or grid stride that must never be
+    /// zero.
+    ///
+    /// Since neither component can be zero, `Option<Vec2NonZeroU32>` is the same size as the
+    /// vector itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::{Vec2NonZeroU32, Vec2u32};
+    /// let stride = Vec2NonZeroU32::try_from(Vec2u32::new(4, 2)).unwrap();
+    ///
+    /// assert_eq!(stride.get(), Vec2u32::new(4, 2));
+    /// assert!(Vec2NonZeroU32::try_from(Vec2u32::new(4, 0)).is_err());
+    /// assert_eq!(
+    ///     std::mem::size_of::<Option<Vec2NonZeroU32>>(),
+    ///     std::mem::size_of::<Vec2NonZeroU32>(),
+    /// );
+    /// ```
+    Vec2NonZeroU32,
+    NonZeroU32,
+    u32,
+    Vec2u32
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroU64`]s.
+    Vec2NonZeroU64,
+    NonZeroU64,
+    u64,
+    Vec2u64
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroUsize`]s.
+    Vec2NonZeroUsize,
+    NonZeroUsize,
+    usize,
+    Vec2usize
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroI8`]s.
+    Vec2NonZeroI8,
+    NonZeroI8,
+    i8,
+    Vec2i8
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroI16`]s.
+    Vec2NonZeroI16,
+    NonZeroI16,
+    i16,
+    Vec2i16
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroI32`]s.
+    Vec2NonZeroI32,
+    NonZeroI32,
+    i32,
+    Vec2i32
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroI64`]s.
+    Vec2NonZeroI64,
+    NonZeroI64,
+    i64,
+    Vec2i64
+);
+create_nonzero_vec2!(
+    /// A Vec2 of [`NonZeroIsize`]s.
+    Vec2NonZeroIsize,
+    NonZeroIsize,
+    isize,
+    Vec2isize
+);
@@ -0,0 +1,178 @@
+use super::segment::cross;
+use super::{Segment, Shape2, ShapeRef, Vec2f32};
+
+/// The direction a polygon's vertices go around it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Winding {
+    /// The vertices go around clockwise, assuming `+y` is up.
+    Clockwise,
+    /// The vertices go around counterclockwise, assuming `+y` is up.
+    Counterclockwise,
+}
+
+/// A closed shape made of straight edges between a list of vertices.
+///
+/// The last vertex connects back to the first. The polygon may be concave, and its vertices may
+/// go around in either direction.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Polygon, Shape2, Vec2f32, Winding};
+/// let l_shape: Polygon = [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]
+///     .into_iter()
+///     .map(Vec2f32::from)
+///     .collect();
+///
+/// assert_eq!(l_shape.area(), 3.0);
+/// assert_eq!(l_shape.winding(), Some(Winding::Counterclockwise));
+/// assert!(l_shape.contains_point(Vec2f32::new(0.5, 1.5)));
+/// assert!(!l_shape.contains_point(Vec2f32::new(1.5, 1.5)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Vec2f32>,
+}
+
+impl Polygon {
+    /// Creates a new Polygon from its vertices.
+    pub fn new(vertices: Vec<Vec2f32>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// Returns the vertices of the polygon.
+    pub fn vertices(&self) -> &[Vec2f32] {
+        &self.vertices
+    }
+
+    /// Consumes the polygon and returns its vertices.
+    pub fn into_vertices(self) -> Vec<Vec2f32> {
+        self.vertices
+    }
+
+    /// Returns an iterator over the edges of the polygon, including the one joining the last
+    /// vertex back to the first.
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        loop_edges(&self.vertices)
+    }
+
+    /// Returns the area of the polygon, which is positive if the vertices go around
+    /// counterclockwise and negative if they go around clockwise.
+    pub fn signed_area(&self) -> f32 {
+        self.edges().map(|e| cross(e.a, e.b)).sum::<f32>() / 2.0
+    }
+
+    /// Returns the direction the vertices go around the polygon, or [`None`] if it has no area.
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+
+        if area > 0.0 {
+            Some(Winding::Counterclockwise)
+        } else if area < 0.0 {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Reverses the order of the vertices, flipping the winding.
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+
+    /// Returns true if `point` is inside the polygon or on one of its edges.
+    ///
+    /// This uses the nonzero winding rule, so areas covered by self-intersecting parts of the
+    /// polygon are inside.
+    pub fn contains_point(&self, point: Vec2f32) -> bool {
+        loop_contains(&self.vertices, point)
+    }
+}
+
+impl Shape2 for Polygon {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Polygon(self)
+    }
+
+    fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.edges().map(|e| e.length()).sum()
+    }
+
+    /// Returns the center of mass of the polygon.
+    ///
+    /// Polygons with no area return the average of their vertices instead, and polygons with no
+    /// vertices return NaN.
+    fn centroid(&self) -> Vec2f32 {
+        let area = self.signed_area();
+
+        if area == 0.0 {
+            let sum = self
+                .vertices
+                .iter()
+                .fold(Vec2f32::new(0.0, 0.0), |acc, &v| acc + v);
+
+            return sum / self.vertices.len() as f32;
+        }
+
+        let sum = self.edges().fold(Vec2f32::new(0.0, 0.0), |acc, e| {
+            acc + (e.a + e.b) * cross(e.a, e.b)
+        });
+
+        sum / (6.0 * area)
+    }
+}
+
+impl From<Vec<Vec2f32>> for Polygon {
+    fn from(vertices: Vec<Vec2f32>) -> Self {
+        Polygon::new(vertices)
+    }
+}
+
+impl FromIterator<Vec2f32> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Vec2f32>>(iter: I) -> Self {
+        Polygon::new(iter.into_iter().collect())
+    }
+}
+
+// Closed Loops //
+
+/// Returns the edges of a closed loop of vertices.
+pub(crate) fn loop_edges(vertices: &[Vec2f32]) -> impl Iterator<Item = Segment> + '_ {
+    let next = vertices.iter().cycle().skip(1);
+
+    vertices.iter().zip(next).map(|(&a, &b)| Segment::new(a, b))
+}
+
+/// Returns true if `point` is inside a closed loop of vertices or on its edge, using the nonzero
+/// winding rule.
+pub(crate) fn loop_contains(vertices: &[Vec2f32], point: Vec2f32) -> bool {
+    let mut winding = 0;
+
+    for edge in loop_edges(vertices) {
+        let side = cross(edge.b - edge.a, point - edge.a);
+
+        // Points exactly on an edge count as inside
+        if side == 0.0
+            && edge.a.x.min(edge.b.x) <= point.x
+            && point.x <= edge.a.x.max(edge.b.x)
+            && edge.a.y.min(edge.b.y) <= point.y
+            && point.y <= edge.a.y.max(edge.b.y)
+        {
+            return true;
+        }
+
+        if edge.a.y <= point.y {
+            if edge.b.y > point.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if edge.b.y <= point.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+
+    winding != 0
+}
@@ -0,0 +1,124 @@
+use rand::Rng;
+
+use super::math::{Real, Transcendental};
+use super::*;
+
+/// A macro for adding random sampling to [`Vec2`] structs.
+macro_rules! impl_random {
+    ($name:ident, $type_:ident) => {
+        impl $name {
+            /// Returns a vector where each component is sampled uniformly from `min..max`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `min` is not less than `max` on either axis.
+            pub fn random_range<V: Into<Self>, R: Rng + ?Sized>(
+                min: V,
+                max: V,
+                rng: &mut R,
+            ) -> Self {
+                let min: Self = min.into();
+                let max: Self = max.into();
+
+                Self::new(rng.gen_range(min.x..max.x), rng.gen_range(min.y..max.y))
+            }
+        }
+    };
+    ($name:ident, $type_:ident, "floating") => {
+        impl_random!($name, $type_);
+
+        impl $name {
+            /// Returns a point sampled uniformly from the inside of a circle.
+            pub fn random_in_circle<V: Into<Self>, R: Rng + ?Sized>(
+                center: V,
+                radius: $type_,
+                rng: &mut R,
+            ) -> Self {
+                // Taking the square root keeps the points evenly spread by area
+                let r = radius * Real::sqrt(rng.gen::<$type_>());
+                let (sin, cos) =
+                    Transcendental::sin_cos(rng.gen_range(0.0..std::$type_::consts::TAU));
+
+                center.into() + Self::new(cos * r, sin * r)
+            }
+
+            /// Returns a point sampled uniformly from the edge of a circle around the origin.
+            pub fn random_on_circle<R: Rng + ?Sized>(radius: $type_, rng: &mut R) -> Self {
+                let (sin, cos) =
+                    Transcendental::sin_cos(rng.gen_range(0.0..std::$type_::consts::TAU));
+
+                Self::new(cos * radius, sin * radius)
+            }
+
+            /// Returns a point sampled uniformly from the ring between two circles around the
+            /// origin.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `r_min` is greater than `r_max`.
+            pub fn random_in_annulus<R: Rng + ?Sized>(
+                r_min: $type_,
+                r_max: $type_,
+                rng: &mut R,
+            ) -> Self {
+                assert!(r_min <= r_max, "r_min must not be greater than r_max");
+
+                // Sampling the squared radius keeps the points evenly spread by area
+                let (min2, max2) = (r_min * r_min, r_max * r_max);
+                let r = Real::sqrt(min2 + rng.gen::<$type_>() * (max2 - min2));
+                let (sin, cos) =
+                    Transcendental::sin_cos(rng.gen_range(0.0..std::$type_::consts::TAU));
+
+                Self::new(cos * r, sin * r)
+            }
+
+            /// Returns a vector where each component is sampled from a normal (Gaussian)
+            /// distribution with the given mean and standard deviation.
+            ///
+            /// The components are independent, so with the same standard deviation on both axes
+            /// the points are spread evenly in every direction around `mean`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+            /// # use rand::{rngs::StdRng, SeedableRng};
+            /// let mut rng = StdRng::seed_from_u64(0);
+            #[doc = concat!("let jitter = ", stringify!($name), "::random_normal((10.0, 10.0), (0.5, 0.5), &mut rng);")]
+            ///
+            /// assert!(jitter.x.is_finite() && jitter.y.is_finite());
+            /// ```
+            pub fn random_normal<M: Into<Self>, S: Into<Self>, R: Rng + ?Sized>(
+                mean: M,
+                std_dev: S,
+                rng: &mut R,
+            ) -> Self {
+                // The Box-Muller transform turns two uniform samples into two independent
+                // normal ones. `1 - gen()` is never zero, so the logarithm is finite.
+                let u = 1.0 - rng.gen::<$type_>();
+                let r = Real::sqrt(-2.0 * Transcendental::ln(u));
+                let (sin, cos) =
+                    Transcendental::sin_cos(rng.gen_range(0.0..std::$type_::consts::TAU));
+
+                mean.into() + std_dev.into() * Self::new(cos * r, sin * r)
+            }
+        }
+    };
+}
+
+impl_random!(Vec2f32, f32, "floating");
+impl_random!(Vec2f64, f64, "floating");
+
+impl_random!(Vec2u8, u8);
+impl_random!(Vec2u16, u16);
+impl_random!(Vec2u32, u32);
+impl_random!(Vec2u64, u64);
+impl_random!(Vec2u128, u128);
+impl_random!(Vec2usize, usize);
+
+impl_random!(Vec2i8, i8);
+impl_random!(Vec2i16, i16);
+impl_random!(Vec2i32, i32);
+impl_random!(Vec2i64, i64);
+impl_random!(Vec2i128, i128);
+impl_random!(Vec2isize, isize);
@@ -0,0 +1,123 @@
+use super::math::Real;
+use super::segment::cross;
+use super::{Circle, Rect, Segment, Vec2f32};
+
+/// A half-line starting at `origin` and going on forever in `direction`.
+///
+/// The intersection methods return the `t` of the first hit, which gives the point hit when
+/// passed to [`Ray2::point_at`]. If `direction` has a magnitude of 1, `t` is also the distance to
+/// the hit.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Circle, Ray2, Vec2f32};
+/// let ray = Ray2::new(Vec2f32::new(0.0, 0.0), Vec2f32::new(1.0, 0.0));
+/// let target = Circle::new(Vec2f32::new(5.0, 0.0), 1.0);
+///
+/// let t = ray.intersect_circle(&target).unwrap();
+///
+/// assert_eq!(t, 4.0);
+/// assert_eq!(ray.point_at(t), Vec2f32::new(4.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray2 {
+    /// The point the ray starts from.
+    pub origin: Vec2f32,
+    /// The direction the ray travels in.
+    pub direction: Vec2f32,
+}
+
+impl Ray2 {
+    /// Creates a new Ray2.
+    pub const fn new(origin: Vec2f32, direction: Vec2f32) -> Self {
+        Ray2 { origin, direction }
+    }
+
+    /// Returns the point `t` steps of `direction` along the ray.
+    pub fn point_at(&self, t: f32) -> Vec2f32 {
+        self.origin + self.direction * t
+    }
+
+    /// Returns where the ray first hits a rectangle, or [`None`] if it misses.
+    ///
+    /// Rays starting inside the rectangle hit it at `t = 0`.
+    pub fn intersect_rect(&self, rect: &Rect) -> Option<f32> {
+        let mut enter = 0.0f32;
+        let mut exit = f32::INFINITY;
+
+        for (origin, direction, min, max) in [
+            (self.origin.x, self.direction.x, rect.min.x, rect.max.x),
+            (self.origin.y, self.direction.y, rect.min.y, rect.max.y),
+        ] {
+            if direction == 0.0 {
+                // Parallel to this pair of edges, so it has to already be between them
+                if origin < min || origin > max {
+                    return None;
+                }
+            } else {
+                let t0 = (min - origin) / direction;
+                let t1 = (max - origin) / direction;
+
+                enter = enter.max(t0.min(t1));
+                exit = exit.min(t0.max(t1));
+            }
+        }
+
+        (enter <= exit).then_some(enter)
+    }
+
+    /// Returns where the ray first hits a circle, or [`None`] if it misses.
+    ///
+    /// Rays starting inside the circle hit it at `t = 0`.
+    pub fn intersect_circle(&self, circle: &Circle) -> Option<f32> {
+        let offset = self.origin - circle.center;
+        let c = offset.mag2() - circle.radius * circle.radius;
+
+        if c <= 0.0 {
+            return Some(0.0);
+        }
+
+        // Solve |offset + direction * t| = radius for t
+        let a = self.direction.mag2();
+        let b = offset.dot(self.direction);
+        let discriminant = b * b - a * c;
+
+        if a == 0.0 || discriminant < 0.0 {
+            return None;
+        }
+
+        let t = (-b - Real::sqrt(discriminant)) / a;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Returns where the ray first hits a segment, or [`None`] if it misses.
+    pub fn intersect_segment(&self, segment: &Segment) -> Option<f32> {
+        let r = self.direction;
+        let s = segment.delta();
+        let offset = segment.a - self.origin;
+        let denom = cross(r, s);
+
+        if denom == 0.0 {
+            if cross(offset, r) != 0.0 || r.mag2() == 0.0 {
+                return None;
+            }
+
+            // On the same line, so the first hit is the nearest end in front of the origin
+            let length2 = r.mag2();
+            let t0 = offset.dot(r) / length2;
+            let t1 = (segment.b - self.origin).dot(r) / length2;
+
+            return if t0.max(t1) < 0.0 {
+                None
+            } else {
+                Some(t0.min(t1).max(0.0))
+            };
+        }
+
+        let t = cross(offset, s) / denom;
+        let u = cross(offset, r) / denom;
+
+        (t >= 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+    }
+}
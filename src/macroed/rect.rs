@@ -0,0 +1,229 @@
+use super::{point_in_rect, Shape2, ShapeRef, Vec2f32};
+
+/// An axis-aligned rectangle, also known as an axis-aligned bounding box.
+///
+/// Rectangles include their edges, so a point on the boundary is contained in the rectangle and
+/// two rectangles sharing an edge intersect.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Rect, Vec2f32};
+/// let a = Rect::new(Vec2f32::new(0.0, 0.0), Vec2f32::new(4.0, 2.0));
+/// let b = Rect::new(Vec2f32::new(3.0, 1.0), Vec2f32::new(5.0, 5.0));
+///
+/// assert!(a.contains(Vec2f32::new(1.0, 1.0)));
+/// assert!(a.intersects(&b));
+/// assert_eq!(
+///     a.intersection(&b),
+///     Some(Rect::new(Vec2f32::new(3.0, 1.0), Vec2f32::new(4.0, 2.0)))
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rect {
+    /// The corner with the smallest `x` and `y`.
+    pub min: Vec2f32,
+    /// The corner with the largest `x` and `y`.
+    pub max: Vec2f32,
+}
+
+impl Rect {
+    /// Creates a new Rect from its corners.
+    ///
+    /// `min` should be less than or equal to `max` on both axes. Use [`Rect::from_corners`] if
+    /// the order of the corners isn't known.
+    pub const fn new(min: Vec2f32, max: Vec2f32) -> Self {
+        Rect { min, max }
+    }
+
+    /// Creates a new Rect from any two opposite corners.
+    pub fn from_corners(a: Vec2f32, b: Vec2f32) -> Self {
+        Rect::new(a.min(b), a.max(b))
+    }
+
+    /// Creates the smallest Rect containing two points.
+    ///
+    /// This is the same as [`Rect::from_corners`], for when `a` and `b` are points such as the
+    /// start and end of a mouse drag rather than corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::{Rect, Vec2f32};
+    /// let selection = Rect::from_points(Vec2f32::new(5.0, 1.0), Vec2f32::new(2.0, 3.0));
+    ///
+    /// assert_eq!(selection, Rect::new(Vec2f32::new(2.0, 1.0), Vec2f32::new(5.0, 3.0)));
+    /// ```
+    pub fn from_points(a: Vec2f32, b: Vec2f32) -> Self {
+        Rect::from_corners(a, b)
+    }
+
+    /// Creates a new Rect centered on `center`, reaching `half_extents` out from it on each axis.
+    ///
+    /// This is how physics engines usually describe boxes. `half_extents` should not be
+    /// negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::{Rect, Vec2f32};
+    /// let r = Rect::from_center_half_extents(Vec2f32::new(5.0, 5.0), Vec2f32::new(2.0, 1.0));
+    ///
+    /// assert_eq!(r, Rect::new(Vec2f32::new(3.0, 4.0), Vec2f32::new(7.0, 6.0)));
+    /// ```
+    pub fn from_center_half_extents(center: Vec2f32, half_extents: Vec2f32) -> Self {
+        Rect::new(center - half_extents, center + half_extents)
+    }
+
+    /// Creates a new Rect from its `min` corner and its size, which is how most UI and windowing
+    /// libraries describe rectangles.
+    ///
+    /// `size` should not be negative.
+    pub fn from_pos_size(pos: Vec2f32, size: Vec2f32) -> Self {
+        Rect::new(pos, pos + size)
+    }
+
+    /// Returns the width and height of the rectangle.
+    pub fn size(&self) -> Vec2f32 {
+        self.max - self.min
+    }
+
+    /// Returns the width of the rectangle.
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    /// Returns the height of the rectangle.
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    /// Returns the point in the middle of the rectangle.
+    pub fn center(&self) -> Vec2f32 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Returns true if `point` is inside the rectangle or on its edge.
+    pub fn contains(&self, point: Vec2f32) -> bool {
+        point_in_rect(point, self.min, self.max)
+    }
+
+    /// Returns true if the rectangles overlap or touch.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Returns true if the rectangles share some area, unlike [`Rect::intersects`] which is also
+    /// true when they only touch.
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.min.x < other.max.x
+            && other.min.x < self.max.x
+            && self.min.y < other.max.y
+            && other.min.y < self.max.y
+    }
+
+    /// Returns true if `other` is entirely inside this rectangle, including its edges.
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Returns the rectangle grown by `margin` on every side, or shrunk if `margin` is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::{Rect, Vec2f32};
+    /// let dirty = Rect::new(Vec2f32::new(10.0, 10.0), Vec2f32::new(20.0, 15.0));
+    ///
+    /// assert_eq!(
+    ///     dirty.expand(2.0),
+    ///     Rect::new(Vec2f32::new(8.0, 8.0), Vec2f32::new(22.0, 17.0))
+    /// );
+    /// ```
+    pub fn expand(&self, margin: f32) -> Rect {
+        let margin = Vec2f32::new(margin, margin);
+        Rect::new(self.min - margin, self.max + margin)
+    }
+
+    /// Returns the smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// Returns the area covered by both rectangles, or [`None`] if they don't intersect.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if self.intersects(other) {
+            Some(Rect::new(self.min.max(other.min), self.max.min(other.max)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the corners of the rectangle, counterclockwise starting from `min`.
+    pub fn corners(&self) -> [Vec2f32; 4] {
+        [
+            self.min,
+            Vec2f32::new(self.max.x, self.min.y),
+            self.max,
+            Vec2f32::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// Returns the point in the rectangle closest to `point`, which is `point` itself if it is
+    /// already inside.
+    pub fn clamp(&self, point: Vec2f32) -> Vec2f32 {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Wraps `point` into the rectangle as if its opposite edges were joined, like an
+    /// asteroids-style world.
+    ///
+    /// The `max` edges are excluded, since they are the same place as the `min` edges. See
+    /// [`Vec2f32::wrap`].
+    pub fn wrap(&self, point: Vec2f32) -> Vec2f32 {
+        point.wrap(self.min, self.max)
+    }
+}
+
+/// Returns the smallest rectangle containing every point, or [`None`] if there are no points.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{bounding_box, Rect, Vec2f32};
+/// let points = [Vec2f32::new(1.0, 5.0), Vec2f32::new(-2.0, 3.0), Vec2f32::new(4.0, 4.0)];
+///
+/// assert_eq!(
+///     bounding_box(&points),
+///     Some(Rect::new(Vec2f32::new(-2.0, 3.0), Vec2f32::new(4.0, 5.0)))
+/// );
+/// assert_eq!(bounding_box(&[]), None);
+/// ```
+pub fn bounding_box(points: &[Vec2f32]) -> Option<Rect> {
+    let (&first, rest) = points.split_first()?;
+
+    Some(rest.iter().fold(Rect::new(first, first), |r, &p| {
+        Rect::new(r.min.min(p), r.max.max(p))
+    }))
+}
+
+impl Shape2 for Rect {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Rect(*self)
+    }
+
+    fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+
+    fn perimeter(&self) -> f32 {
+        2.0 * (self.width() + self.height())
+    }
+
+    fn centroid(&self) -> Vec2f32 {
+        self.center()
+    }
+}
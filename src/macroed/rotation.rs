@@ -0,0 +1,127 @@
+use std::ops::{Mul, MulAssign};
+
+use super::{Angle, UnitVec2, Vec2f32};
+
+/// A rotation around the origin that stores the cosine and sine of its angle.
+///
+/// Creating a rotation calls `sin` and `cos` once. Rotating a vector afterwards only takes a few
+/// multiplications, so reuse one rotation when turning many points by the same angle.
+///
+/// Rotations are counterclockwise for positive angles, and can be combined by multiplying them.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Angle, Rotation2, Vec2f32};
+/// let quarter = Rotation2::from_angle(Angle::QUARTER_TURN);
+/// let points = [Vec2f32::new(1.0, 0.0), Vec2f32::new(0.0, 2.0)];
+///
+/// let rotated: Vec<Vec2f32> = points.iter().map(|&p| quarter * p).collect();
+///
+/// assert!((rotated[0] - Vec2f32::new(0.0, 1.0)).mag() < 1e-6);
+/// assert!((rotated[1] - Vec2f32::new(-2.0, 0.0)).mag() < 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rotation2 {
+    cos: f32,
+    sin: f32,
+}
+
+impl Rotation2 {
+    /// The rotation that leaves vectors unchanged.
+    pub const IDENTITY: Self = Rotation2 { cos: 1.0, sin: 0.0 };
+
+    /// Creates a rotation by `angle`.
+    pub fn from_angle(angle: Angle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Rotation2 { cos, sin }
+    }
+
+    /// Creates the rotation that turns +x to point in `direction`.
+    pub fn from_direction(direction: UnitVec2) -> Self {
+        let v = direction.get();
+        Rotation2 { cos: v.x, sin: v.y }
+    }
+
+    /// Returns the cosine of the angle.
+    pub const fn cos(self) -> f32 {
+        self.cos
+    }
+
+    /// Returns the sine of the angle.
+    pub const fn sin(self) -> f32 {
+        self.sin
+    }
+
+    /// Returns the angle of the rotation, in the range [-π, π].
+    pub fn angle(self) -> Angle {
+        Vec2f32::new(self.cos, self.sin).angle()
+    }
+
+    /// Returns the rotation that undoes this one.
+    pub const fn inverse(self) -> Self {
+        Rotation2 {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Rotates `v` around the origin.
+    pub fn rotate(self, v: Vec2f32) -> Vec2f32 {
+        Vec2f32::new(
+            self.cos * v.x - self.sin * v.y,
+            self.sin * v.x + self.cos * v.y,
+        )
+    }
+
+    /// Returns the rotation with its cosine and sine rescaled to undo rounding drift.
+    ///
+    /// Useful after composing many rotations, which slowly makes them scale vectors as well as
+    /// turn them.
+    pub fn renormalized(self) -> Self {
+        let len = Vec2f32::new(self.cos, self.sin).mag();
+
+        Rotation2 {
+            cos: self.cos / len,
+            sin: self.sin / len,
+        }
+    }
+}
+
+impl Default for Rotation2 {
+    fn default() -> Self {
+        Rotation2::IDENTITY
+    }
+}
+
+impl From<Angle> for Rotation2 {
+    fn from(angle: Angle) -> Self {
+        Rotation2::from_angle(angle)
+    }
+}
+
+impl Mul<Vec2f32> for Rotation2 {
+    type Output = Vec2f32;
+
+    fn mul(self, rhs: Vec2f32) -> Vec2f32 {
+        self.rotate(rhs)
+    }
+}
+
+/// Combines two rotations, so `(a * b) * v` is the same as `a * (b * v)`.
+impl Mul for Rotation2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Rotation2 {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+impl MulAssign for Rotation2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
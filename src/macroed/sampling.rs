@@ -0,0 +1,123 @@
+//! Random point sets with an even spread, also known as blue noise.
+//!
+//! Uniformly random points tend to clump together and leave gaps. The samplers here keep points
+//! apart, which looks more natural for placing trees, stars, or particles.
+//!
+//! # Example
+//!
+//! ```
+//! # use manyvecs::macroed::{sampling, Rect, Vec2f32};
+//! # use rand::{rngs::StdRng, SeedableRng};
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let field = Rect::new(Vec2f32::new(0.0, 0.0), Vec2f32::new(100.0, 50.0));
+//!
+//! let trees = sampling::poisson_disk(&field, 8.0, &mut rng);
+//!
+//! for (i, a) in trees.iter().enumerate() {
+//!     assert!(field.contains(*a));
+//!
+//!     for b in &trees[i + 1..] {
+//!         assert!((*a - *b).mag() >= 8.0);
+//!     }
+//! }
+//! ```
+
+use std::f32::consts::{SQRT_2, TAU};
+
+use rand::Rng;
+
+use super::math::{Real, Transcendental};
+use super::{Rect, Vec2f32};
+
+/// The number of candidates tried around a point before giving up on it.
+const CANDIDATES: usize = 30;
+
+/// Returns points inside `rect` that are all at least `min_distance` apart, using Bridson's
+/// algorithm.
+///
+/// Points keep being added until there is no room left for another, so the whole rectangle is
+/// covered evenly. Returns no points if the rectangle is inverted.
+///
+/// # Panics
+///
+/// Panics if `min_distance` is not positive and finite.
+pub fn poisson_disk<R: Rng + ?Sized>(rect: &Rect, min_distance: f32, rng: &mut R) -> Vec<Vec2f32> {
+    assert!(
+        min_distance > 0.0 && min_distance.is_finite(),
+        "min_distance must be positive and finite"
+    );
+
+    if rect.width() < 0.0 || rect.height() < 0.0 {
+        return Vec::new();
+    }
+
+    // Each cell is small enough to hold at most one point
+    let cell_size = min_distance / SQRT_2;
+    let columns = ((rect.width() / cell_size).ceil() as usize).max(1);
+    let rows = ((rect.height() / cell_size).ceil() as usize).max(1);
+
+    let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+    let cell_of = |p: Vec2f32| {
+        let cell = ((p - rect.min) / cell_size).floor();
+        (
+            (cell.x as usize).min(columns - 1),
+            (cell.y as usize).min(rows - 1),
+        )
+    };
+
+    let mut points = Vec::new();
+    let mut active = Vec::new();
+
+    let add = |p: Vec2f32,
+               grid: &mut Vec<Option<usize>>,
+               points: &mut Vec<Vec2f32>,
+               active: &mut Vec<usize>| {
+        let (column, row) = cell_of(p);
+        grid[row * columns + column] = Some(points.len());
+        active.push(points.len());
+        points.push(p);
+    };
+
+    let first = Vec2f32::new(
+        rect.min.x + rng.gen::<f32>() * rect.width(),
+        rect.min.y + rng.gen::<f32>() * rect.height(),
+    );
+    add(first, &mut grid, &mut points, &mut active);
+
+    while !active.is_empty() {
+        let i = rng.gen_range(0..active.len());
+        let center = points[active[i]];
+
+        let found = (0..CANDIDATES).find_map(|_| {
+            // Candidates come from the ring between one and two times the distance
+            let r = min_distance * Real::sqrt(rng.gen_range(1.0..4.0f32));
+            let (sin, cos) = Transcendental::sin_cos(rng.gen_range(0.0..TAU));
+            let candidate = center + Vec2f32::new(cos * r, sin * r);
+
+            if !rect.contains(candidate) {
+                return None;
+            }
+
+            // Only the 5x5 cells around the candidate can hold points that are too close
+            let (column, row) = cell_of(candidate);
+            let too_close = (row.saturating_sub(2)..(row + 3).min(rows)).any(|r| {
+                (column.saturating_sub(2)..(column + 3).min(columns)).any(|c| {
+                    grid[r * columns + c].is_some_and(|j| {
+                        (points[j] - candidate).mag2() < min_distance * min_distance
+                    })
+                })
+            });
+
+            (!too_close).then_some(candidate)
+        });
+
+        match found {
+            Some(p) => add(p, &mut grid, &mut points, &mut active),
+            None => {
+                active.swap_remove(i);
+            }
+        }
+    }
+
+    points
+}
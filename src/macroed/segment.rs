@@ -0,0 +1,175 @@
+use super::{Shape2, ShapeRef, Vec2f32};
+
+/// A straight line between two points.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Segment, Vec2f32};
+/// let a = Segment::new(Vec2f32::new(0.0, 0.0), Vec2f32::new(4.0, 4.0));
+/// let b = Segment::new(Vec2f32::new(0.0, 4.0), Vec2f32::new(4.0, 0.0));
+///
+/// assert_eq!(a.length(), 32f32.sqrt());
+/// assert_eq!(a.intersect(&b), Some(Vec2f32::new(2.0, 2.0)));
+/// assert_eq!(a.closest_point(Vec2f32::new(0.0, 2.0)), Vec2f32::new(1.0, 1.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment {
+    /// The start of the segment.
+    pub a: Vec2f32,
+    /// The end of the segment.
+    pub b: Vec2f32,
+}
+
+impl Segment {
+    /// Creates a new Segment.
+    pub const fn new(a: Vec2f32, b: Vec2f32) -> Self {
+        Segment { a, b }
+    }
+
+    /// Returns the vector from the start of the segment to the end.
+    pub fn delta(&self) -> Vec2f32 {
+        self.b - self.a
+    }
+
+    /// Returns the length of the segment.
+    pub fn length(&self) -> f32 {
+        self.delta().mag()
+    }
+
+    /// Returns the point halfway along the segment.
+    pub fn midpoint(&self) -> Vec2f32 {
+        (self.a + self.b) / 2.0
+    }
+
+    /// Returns the point on the segment closest to `point`.
+    pub fn closest_point(&self, point: Vec2f32) -> Vec2f32 {
+        let delta = self.delta();
+        let length2 = delta.mag2();
+
+        if length2 == 0.0 {
+            return self.a;
+        }
+
+        let t = ((point - self.a).dot(delta) / length2).clamp(0.0, 1.0);
+        self.a + delta * t
+    }
+
+    /// Returns the point where two segments cross, or [`None`] if they don't touch.
+    ///
+    /// If the segments overlap along the same line, the overlapping point closest to `self.a` is
+    /// returned.
+    pub fn intersect(&self, other: &Segment) -> Option<Vec2f32> {
+        let r = self.delta();
+        let s = other.delta();
+
+        // Zero-length segments are points, which only intersect if they lie on the other segment
+        if r.mag2() == 0.0 {
+            return (other.closest_point(self.a) == self.a).then_some(self.a);
+        }
+
+        if s.mag2() == 0.0 {
+            return (self.closest_point(other.a) == other.a).then_some(other.a);
+        }
+
+        let offset = other.a - self.a;
+        let denom = cross(r, s);
+
+        if denom == 0.0 {
+            if cross(offset, r) != 0.0 {
+                // Parallel, but on different lines
+                return None;
+            }
+
+            // On the same line, so find where the other segment lies along this one
+            let length2 = r.mag2();
+            let t0 = offset.dot(r) / length2;
+            let t1 = (other.b - self.a).dot(r) / length2;
+            let start = t0.min(t1).max(0.0);
+            let end = t0.max(t1).min(1.0);
+
+            return (start <= end).then(|| self.a + r * start);
+        }
+
+        let t = cross(offset, s) / denom;
+        let u = cross(offset, r) / denom;
+
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            Some(self.a + r * t)
+        } else {
+            None
+        }
+    }
+}
+
+impl Shape2 for Segment {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Segment(*self)
+    }
+
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.length()
+    }
+
+    fn centroid(&self) -> Vec2f32 {
+        self.midpoint()
+    }
+}
+
+/// Returns the point where the infinite lines through `p1` along `d1` and through `p2` along
+/// `d2` cross, or [`None`] if they are parallel.
+///
+/// Unlike [`Segment::intersect`], the lines continue forever in both directions. Use
+/// [`line_intersection_params`] to find how far along each line the point is.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{line_intersection, Vec2f32};
+/// let p1 = Vec2f32::new(0.0, 1.0);
+/// let p2 = Vec2f32::new(5.0, 0.0);
+///
+/// assert_eq!(
+///     line_intersection(p1, Vec2f32::new(1.0, 0.0), p2, Vec2f32::new(0.0, 2.0)),
+///     Some(Vec2f32::new(5.0, 1.0))
+/// );
+/// assert_eq!(
+///     line_intersection(p1, Vec2f32::new(1.0, 0.0), p2, Vec2f32::new(-3.0, 0.0)),
+///     None
+/// );
+/// ```
+pub fn line_intersection(p1: Vec2f32, d1: Vec2f32, p2: Vec2f32, d2: Vec2f32) -> Option<Vec2f32> {
+    line_intersection_params(p1, d1, p2, d2).map(|(t, _)| p1 + d1 * t)
+}
+
+/// Returns `(t, u)` such that `p1 + d1 * t` and `p2 + d2 * u` are the point where the lines
+/// cross, or [`None`] if they are parallel.
+///
+/// The parameters are measured in multiples of the direction vectors, so `t` between zero and
+/// one means the point is on the segment from `p1` to `p1 + d1`. Lines with a zero direction
+/// count as parallel to everything.
+pub fn line_intersection_params(
+    p1: Vec2f32,
+    d1: Vec2f32,
+    p2: Vec2f32,
+    d2: Vec2f32,
+) -> Option<(f32, f32)> {
+    let denom = cross(d1, d2);
+
+    if denom == 0.0 {
+        return None;
+    }
+
+    let offset = p2 - p1;
+    Some((cross(offset, d2) / denom, cross(offset, d1) / denom))
+}
+
+/// Returns the z component of the cross product of two vectors, which is positive when `b` is
+/// counterclockwise from `a`.
+pub(crate) fn cross(a: Vec2f32, b: Vec2f32) -> f32 {
+    a.x * b.y - a.y * b.x
+}
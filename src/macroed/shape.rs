@@ -0,0 +1,356 @@
+use super::polygon::{loop_contains, loop_edges};
+use super::{Capsule, Circle, Polygon, Rect, Segment, Triangle, UnitVec2, Vec2f32};
+
+/// A borrowed view of a shape, used to dispatch queries between two shapes
+/// without knowing their concrete types.
+#[derive(Copy, Clone, Debug)]
+pub enum ShapeRef<'a> {
+    /// A single point.
+    Point(Vec2f32),
+    /// An axis-aligned rectangle.
+    Rect(Rect),
+    /// A circle.
+    Circle(Circle),
+    /// A segment with a radius around it.
+    Capsule(Capsule),
+    /// A line segment.
+    Segment(Segment),
+    /// A polygon.
+    Polygon(&'a Polygon),
+    /// A triangle.
+    Triangle(Triangle),
+}
+
+impl<'a> ShapeRef<'a> {
+    /// Splits a shape into a core shape and a radius around it, so that rounded shapes can share
+    /// the distance code of their cores.
+    fn split_radius(self) -> (ShapeRef<'a>, f32) {
+        match self {
+            ShapeRef::Circle(c) => (ShapeRef::Point(c.center), c.radius),
+            ShapeRef::Capsule(c) => (ShapeRef::Segment(c.segment), c.radius),
+            shape => (shape, 0.0),
+        }
+    }
+
+    /// Returns the vertices of shapes that are closed loops of straight edges.
+    fn as_loop(&self) -> Option<LoopVertices<'a>> {
+        match *self {
+            ShapeRef::Rect(r) => Some(LoopVertices::Four(r.corners())),
+            ShapeRef::Triangle(t) => Some(LoopVertices::Three(t.vertices())),
+            ShapeRef::Polygon(p) => Some(LoopVertices::Slice(p.vertices())),
+            _ => None,
+        }
+    }
+}
+
+/// The vertices of a closed loop, either borrowed or stored inline.
+enum LoopVertices<'a> {
+    Three([Vec2f32; 3]),
+    Four([Vec2f32; 4]),
+    Slice(&'a [Vec2f32]),
+}
+
+impl LoopVertices<'_> {
+    fn as_slice(&self) -> &[Vec2f32] {
+        match self {
+            LoopVertices::Three(v) => v,
+            LoopVertices::Four(v) => v,
+            LoopVertices::Slice(v) => v,
+        }
+    }
+}
+
+/// A 2D shape that can take part in generic shape queries, such as
+/// [`distance_between`].
+pub trait Shape2 {
+    /// Returns a view of this shape that the dispatch table can match on.
+    fn as_shape(&self) -> ShapeRef<'_>;
+
+    /// Returns the area enclosed by this shape.
+    fn area(&self) -> f32;
+
+    /// Returns the length of this shape's outline.
+    fn perimeter(&self) -> f32;
+
+    /// Returns the center of mass of this shape, assuming uniform density.
+    fn centroid(&self) -> Vec2f32;
+}
+
+impl Shape2 for Vec2f32 {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Point(*self)
+    }
+
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f32 {
+        0.0
+    }
+
+    fn centroid(&self) -> Vec2f32 {
+        *self
+    }
+}
+
+/// Returns the signed distance between two shapes, followed by the closest
+/// point on `a` and the closest point on `b`.
+///
+/// Overlapping shapes have a negative distance, measuring how far apart they
+/// would need to move to only touch. This depth is only measured between
+/// points, circles, and rectangles, and for points and circles inside a
+/// polygon. Other overlapping shapes, including segments which have no inside,
+/// report a distance of 0 and a point they share.
+///
+/// # Panics
+///
+/// Panics if either shape is a polygon with no vertices.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{distance_between, Vec2f32};
+/// let a = Vec2f32::new(0.0, 0.0);
+/// let b = Vec2f32::new(3.0, 4.0);
+///
+/// assert_eq!(distance_between(&a, &b), (5.0, a, b));
+/// ```
+pub fn distance_between(a: &dyn Shape2, b: &dyn Shape2) -> (f32, Vec2f32, Vec2f32) {
+    let (core_a, radius_a) = a.as_shape().split_radius();
+    let (core_b, radius_b) = b.as_shape().split_radius();
+    let (distance, on_a, on_b) = core_distance(core_a, core_b);
+
+    if radius_a == 0.0 && radius_b == 0.0 {
+        return (distance, on_a, on_b);
+    }
+
+    // The direction `b` would have to move to get away from `a`. If the closest points are the
+    // same, fall back to the direction between the shapes' centers.
+    let normal = UnitVec2::new((on_b - on_a) * distance.signum())
+        .or_else(|| UnitVec2::new(b.centroid() - a.centroid()))
+        .map_or(Vec2f32::new(1.0, 0.0), UnitVec2::get);
+
+    (
+        distance - radius_a - radius_b,
+        on_a + normal * radius_a,
+        on_b - normal * radius_b,
+    )
+}
+
+/// Returns the signed distance between two shapes without radii, and the closest points on each.
+fn core_distance(a: ShapeRef<'_>, b: ShapeRef<'_>) -> (f32, Vec2f32, Vec2f32) {
+    match (a, b) {
+        (ShapeRef::Point(a), ShapeRef::Point(b)) => ((b - a).mag(), a, b),
+        (ShapeRef::Point(a), ShapeRef::Rect(b)) => {
+            let (distance, on_b) = point_rect(a, &b);
+            (distance, a, on_b)
+        }
+        (ShapeRef::Rect(a), ShapeRef::Point(b)) => {
+            let (distance, on_a) = point_rect(b, &a);
+            (distance, on_a, b)
+        }
+        (ShapeRef::Rect(a), ShapeRef::Rect(b)) => rect_rect(&a, &b),
+        (ShapeRef::Point(a), ShapeRef::Segment(b)) => {
+            let on_b = b.closest_point(a);
+            ((on_b - a).mag(), a, on_b)
+        }
+        (ShapeRef::Segment(a), ShapeRef::Point(b)) => {
+            let on_a = a.closest_point(b);
+            ((b - on_a).mag(), on_a, b)
+        }
+        (ShapeRef::Segment(a), ShapeRef::Segment(b)) => segment_segment(&a, &b),
+        (ShapeRef::Segment(a), ShapeRef::Rect(b)) => segment_rect(&a, &b),
+        (ShapeRef::Rect(a), ShapeRef::Segment(b)) => {
+            let (distance, on_b, on_a) = segment_rect(&b, &a);
+            (distance, on_a, on_b)
+        }
+        (ShapeRef::Circle(_), _) | (_, ShapeRef::Circle(_)) => {
+            unreachable!("circles are split into a point and a radius")
+        }
+        (ShapeRef::Capsule(_), _) | (_, ShapeRef::Capsule(_)) => {
+            unreachable!("capsules are split into a segment and a radius")
+        }
+        // Polygons and triangles, and rectangles paired with them
+        (a, b) => match (a, b, a.as_loop(), b.as_loop()) {
+            (ShapeRef::Point(a), _, _, Some(b)) => {
+                let (distance, on_b) = point_loop(a, b.as_slice());
+                (distance, a, on_b)
+            }
+            (_, ShapeRef::Point(b), Some(a), _) => {
+                let (distance, on_a) = point_loop(b, a.as_slice());
+                (distance, on_a, b)
+            }
+            (ShapeRef::Segment(a), _, _, Some(b)) => segment_loop(&a, b.as_slice()),
+            (_, ShapeRef::Segment(b), Some(a), _) => {
+                let (distance, on_b, on_a) = segment_loop(&b, a.as_slice());
+                (distance, on_a, on_b)
+            }
+            (_, _, Some(a), Some(b)) => loop_loop(a.as_slice(), b.as_slice()),
+            _ => unreachable!("every other pair is matched above"),
+        },
+    }
+}
+
+// Pairs //
+
+/// Returns the signed distance from a point to a rectangle, and the closest point on the
+/// rectangle's edge.
+fn point_rect(p: Vec2f32, r: &Rect) -> (f32, Vec2f32) {
+    if !r.contains(p) {
+        let closest = r.clamp(p);
+        return ((p - closest).mag(), closest);
+    }
+
+    // Inside, so the closest point is on the nearest edge
+    let edges = [
+        (p.x - r.min.x, Vec2f32::new(r.min.x, p.y)),
+        (r.max.x - p.x, Vec2f32::new(r.max.x, p.y)),
+        (p.y - r.min.y, Vec2f32::new(p.x, r.min.y)),
+        (r.max.y - p.y, Vec2f32::new(p.x, r.max.y)),
+    ];
+
+    let (depth, closest) = edges
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap();
+
+    (-depth, closest)
+}
+
+/// Returns the signed distance between two rectangles, and the closest points on each.
+///
+/// When they overlap, the distance is minus the smallest distance one would have to move to
+/// separate them.
+fn rect_rect(a: &Rect, b: &Rect) -> (f32, Vec2f32, Vec2f32) {
+    // Returns the gap between two intervals (negative when overlapping), and a coordinate from
+    // each interval facing the other
+    let axis = |a_min: f32, a_max: f32, b_min: f32, b_max: f32| {
+        let gap = (b_min - a_max).max(a_min - b_max);
+
+        if gap > 0.0 {
+            if a_max < b_min {
+                (gap, a_max, b_min)
+            } else {
+                (gap, a_min, b_max)
+            }
+        } else {
+            let middle = (a_min.max(b_min) + a_max.min(b_max)) / 2.0;
+            (gap, middle, middle)
+        }
+    };
+
+    let (gap_x, ax, bx) = axis(a.min.x, a.max.x, b.min.x, b.max.x);
+    let (gap_y, ay, by) = axis(a.min.y, a.max.y, b.min.y, b.max.y);
+
+    if gap_x > 0.0 || gap_y > 0.0 {
+        let gap = Vec2f32::new(gap_x.max(0.0), gap_y.max(0.0));
+        return (gap.mag(), Vec2f32::new(ax, ay), Vec2f32::new(bx, by));
+    }
+
+    // Overlapping, so push apart along the axis with the least overlap
+    let (ca, cb) = (a.center(), b.center());
+
+    if gap_x >= gap_y {
+        let (ax, bx) = if ca.x <= cb.x {
+            (a.max.x, b.min.x)
+        } else {
+            (a.min.x, b.max.x)
+        };
+
+        (gap_x, Vec2f32::new(ax, ay), Vec2f32::new(bx, by))
+    } else {
+        let (ay, by) = if ca.y <= cb.y {
+            (a.max.y, b.min.y)
+        } else {
+            (a.min.y, b.max.y)
+        };
+
+        (gap_y, Vec2f32::new(ax, ay), Vec2f32::new(bx, by))
+    }
+}
+
+/// Returns the distance between two segments, and the closest points on each.
+fn segment_segment(a: &Segment, b: &Segment) -> (f32, Vec2f32, Vec2f32) {
+    if let Some(p) = a.intersect(b) {
+        return (0.0, p, p);
+    }
+
+    // Segments that don't cross are closest at one of their ends
+    let candidates = [
+        (a.a, b.closest_point(a.a)),
+        (a.b, b.closest_point(a.b)),
+        (a.closest_point(b.a), b.a),
+        (a.closest_point(b.b), b.b),
+    ];
+
+    closest_pair(candidates)
+}
+
+/// Returns the distance between a segment and a rectangle, and the closest points on each.
+fn segment_rect(s: &Segment, r: &Rect) -> (f32, Vec2f32, Vec2f32) {
+    segment_loop(s, &r.corners())
+}
+
+/// Returns the signed distance from a point to a closed loop of vertices, and the closest point
+/// on the loop's edge.
+fn point_loop(p: Vec2f32, vertices: &[Vec2f32]) -> (f32, Vec2f32) {
+    let (distance, _, on_loop) =
+        closest_pair_iter(loop_edges(vertices).map(|edge| (p, edge.closest_point(p))));
+
+    if loop_contains(vertices, p) {
+        (-distance, on_loop)
+    } else {
+        (distance, on_loop)
+    }
+}
+
+/// Returns the distance between a segment and a closed loop of vertices, and the closest points
+/// on each.
+fn segment_loop(s: &Segment, vertices: &[Vec2f32]) -> (f32, Vec2f32, Vec2f32) {
+    let touching = [s.a, s.b]
+        .into_iter()
+        .find(|&p| loop_contains(vertices, p))
+        .or_else(|| loop_edges(vertices).find_map(|edge| s.intersect(&edge)));
+
+    if let Some(p) = touching {
+        return (0.0, p, p);
+    }
+
+    closest_triple_iter(loop_edges(vertices).map(|edge| segment_segment(s, &edge)))
+}
+
+/// Returns the distance between two closed loops of vertices, and the closest points on each.
+fn loop_loop(a: &[Vec2f32], b: &[Vec2f32]) -> (f32, Vec2f32, Vec2f32) {
+    let touching = a
+        .iter()
+        .copied()
+        .find(|&p| loop_contains(b, p))
+        .or_else(|| b.iter().copied().find(|&p| loop_contains(a, p)));
+
+    if let Some(p) = touching {
+        return (0.0, p, p);
+    }
+
+    // Neither loop has a vertex inside the other, so they either cross at an edge or are apart
+    closest_triple_iter(loop_edges(a).map(|edge| segment_loop(&edge, b)))
+}
+
+/// Returns the pair of points that are closest together, along with their distance.
+fn closest_pair<const N: usize>(pairs: [(Vec2f32, Vec2f32); N]) -> (f32, Vec2f32, Vec2f32) {
+    closest_pair_iter(pairs.into_iter())
+}
+
+/// The same as [`closest_pair`], for any number of pairs.
+fn closest_pair_iter(pairs: impl Iterator<Item = (Vec2f32, Vec2f32)>) -> (f32, Vec2f32, Vec2f32) {
+    closest_triple_iter(pairs.map(|(a, b)| ((b - a).mag(), a, b)))
+}
+
+/// Returns the result with the smallest distance.
+fn closest_triple_iter(
+    results: impl Iterator<Item = (f32, Vec2f32, Vec2f32)>,
+) -> (f32, Vec2f32, Vec2f32) {
+    results
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .expect("polygons must have at least one vertex")
+}
@@ -0,0 +1,131 @@
+//! SSE2 implementations of the hot operations on [`f32`] and [`f64`] vectors.
+//!
+//! SSE2 is part of the x86_64 baseline, so these are always safe to call on that target. They are
+//! selected by `simd_or!` inside of the vector macros, which looks at the scalar type rather than
+//! the vector's name, so every vector of `f32`s or `f64`s uses them. None of this is public API.
+//!
+//! The dispatch relies on inherent methods taking priority over trait methods: [`Lanes<f32>`] and
+//! [`Lanes<f64>`] have inherent versions of each operation that return [`Some`], while every other
+//! scalar type only gets the [`Fallback`] versions, which return [`None`].
+
+use std::arch::x86_64::*;
+use std::marker::PhantomData;
+
+/// Selects the SSE2 operations for a scalar type, if there are any.
+pub struct Lanes<T>(PhantomData<T>);
+
+impl<T> Lanes<T> {
+    pub const NEW: Self = Lanes(PhantomData);
+}
+
+/// The operations for scalar types without SSE2 versions, which always return [`None`].
+pub trait Fallback {
+    fn add<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn sub<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn mul<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn div<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn rem<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn bitand<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn bitor<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn bitxor<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn shl<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn shr<A>(&self, _a: A, _b: A) -> Option<A> {
+        None
+    }
+
+    fn mag2<A, S>(&self, _a: A) -> Option<S> {
+        None
+    }
+}
+
+impl<T> Fallback for Lanes<T> {}
+
+/// Operations on two `f32`s, using the lower two lanes of an `__m128`.
+impl Lanes<f32> {
+    #[inline(always)]
+    fn load(v: [f32; 2]) -> __m128 {
+        // SAFETY: SSE2 is always available on x86_64.
+        unsafe { _mm_set_ps(0.0, 0.0, v[1], v[0]) }
+    }
+
+    #[inline(always)]
+    fn store(r: __m128) -> [f32; 2] {
+        let mut out = [0.0; 4];
+        // SAFETY: `out` is large enough for all four lanes, and `storeu` allows any alignment.
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), r) };
+        [out[0], out[1]]
+    }
+}
+
+/// Operations on two `f64`s, which fill an `__m128d` exactly.
+impl Lanes<f64> {
+    #[inline(always)]
+    fn load(v: [f64; 2]) -> __m128d {
+        // SAFETY: SSE2 is always available on x86_64.
+        unsafe { _mm_set_pd(v[1], v[0]) }
+    }
+
+    #[inline(always)]
+    fn store(r: __m128d) -> [f64; 2] {
+        let mut out = [0.0; 2];
+        // SAFETY: `out` holds both lanes, and `storeu` allows any alignment.
+        unsafe { _mm_storeu_pd(out.as_mut_ptr(), r) };
+        out
+    }
+}
+
+macro_rules! impl_lanes {
+    ($type_:ident, $($fn_name:ident => $intrinsic:ident),*) => {
+        impl Lanes<$type_> {
+            $(
+                #[inline(always)]
+                pub fn $fn_name(&self, a: [$type_; 2], b: [$type_; 2]) -> Option<[$type_; 2]> {
+                    // SAFETY: SSE2 is always available on x86_64.
+                    Some(Self::store(unsafe { $intrinsic(Self::load(a), Self::load(b)) }))
+                }
+            )*
+
+            /// There is no SSE remainder instruction, so this is done per component.
+            #[inline(always)]
+            pub fn rem(&self, a: [$type_; 2], b: [$type_; 2]) -> Option<[$type_; 2]> {
+                Some([a[0] % b[0], a[1] % b[1]])
+            }
+
+            #[inline(always)]
+            pub fn mag2(&self, a: [$type_; 2]) -> Option<$type_> {
+                let v = self.mul(a, a)?;
+                Some(v[0] + v[1])
+            }
+        }
+    };
+}
+
+impl_lanes!(f32, add => _mm_add_ps, sub => _mm_sub_ps, mul => _mm_mul_ps, div => _mm_div_ps);
+impl_lanes!(f64, add => _mm_add_pd, sub => _mm_sub_pd, mul => _mm_mul_pd, div => _mm_div_pd);
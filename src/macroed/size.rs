@@ -0,0 +1,239 @@
+use super::{Vec2f32, Vec2f64, Vec2u32, Vec2usize};
+
+/// A macro for creating [`Size2`](super::Size2) structs.
+macro_rules! create_size2 {
+    // Match a name, its component type, the matching Vec2, a type wide enough to hold the area,
+    // the type used for ratios, and the Vec2 containing it.
+    (
+        $(#[$meta:meta])* $name:ident,
+        $type_:ty,
+        $vec:ident,
+        $area:ty,
+        $ratio:ty,
+        $ratio_vec:ident
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq)]
+        pub struct $name {
+            width: $type_,
+            height: $type_,
+        }
+
+        impl $name {
+            /// A size with no width or height.
+            pub const ZERO: Self = $name {
+                width: 0 as $type_,
+                height: 0 as $type_,
+            };
+
+            /// Creates a new Size2.
+            ///
+            /// Negative and NaN dimensions are replaced with zero, so a size is never negative.
+            pub fn new(width: $type_, height: $type_) -> Self {
+                $name {
+                    width: Self::non_negative(width),
+                    height: Self::non_negative(height),
+                }
+            }
+
+            /// Creates a Size2 using `x` as the width and `y` as the height.
+            ///
+            /// Negative and NaN components are replaced with zero.
+            pub fn from_vec2(v: $vec) -> Self {
+                Self::new(v.x, v.y)
+            }
+
+            /// Returns a Vec2 with the width as `x` and the height as `y`.
+            pub const fn to_vec2(self) -> $vec {
+                $vec::new(self.width, self.height)
+            }
+
+            /// Returns the width.
+            pub const fn width(self) -> $type_ {
+                self.width
+            }
+
+            /// Returns the height.
+            pub const fn height(self) -> $type_ {
+                self.height
+            }
+
+            /// Returns the width times the height.
+            ///
+            /// For integer sizes, this is returned as a wider type so that it can't overflow.
+            pub fn area(self) -> $area {
+                self.width as $area * self.height as $area
+            }
+
+            /// Returns the width divided by the height.
+            ///
+            /// This is infinite if only the height is zero, and NaN if both are.
+            pub fn aspect_ratio(self) -> $ratio {
+                self.width as $ratio / self.height as $ratio
+            }
+
+            /// Returns true if the width or the height is zero.
+            pub fn is_empty(self) -> bool {
+                self.width == 0 as $type_ || self.height == 0 as $type_
+            }
+
+            /// Returns true if this size is no wider and no taller than `other`.
+            pub fn fits_within(self, other: Self) -> bool {
+                self.width <= other.width && self.height <= other.height
+            }
+
+            /// Returns this size scaled up or down to be as large as possible while fitting
+            /// inside `other`, keeping its aspect ratio.
+            ///
+            /// The result matches `other` on one axis and leaves bars on the other, like a
+            /// letterboxed video. Empty sizes stay empty.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::{", stringify!($name), ", ", stringify!($ratio_vec), "};")]
+            #[doc = concat!("let game = ", stringify!($name), "::new(320 as _, 240 as _);")]
+            #[doc = concat!("let window = ", stringify!($name), "::new(1920 as _, 1080 as _);")]
+            ///
+            #[doc = concat!("assert_eq!(game.fit_within(window), ", stringify!($ratio_vec), "::new(1440.0, 1080.0));")]
+            #[doc = concat!("assert_eq!(game.letterbox_offset(window), ", stringify!($ratio_vec), "::new(240.0, 0.0));")]
+            /// ```
+            pub fn fit_within(self, other: Self) -> $ratio_vec {
+                self.scaled_by(|x: $ratio, y: $ratio| x.min(y), other)
+            }
+
+            /// Returns this size scaled up or down to be as small as possible while covering
+            /// all of `other`, keeping its aspect ratio.
+            ///
+            /// The result matches `other` on one axis and overflows it on the other, which is
+            /// cropped when drawing. Empty sizes stay empty.
+            pub fn fill(self, other: Self) -> $ratio_vec {
+                self.scaled_by(|x: $ratio, y: $ratio| x.max(y), other)
+            }
+
+            /// Returns where to place the [`fit_within`](Self::fit_within) size so that it is
+            /// centered in `other`.
+            ///
+            /// This is the width of the bars on the left and right, and the height of the bars
+            /// on the top and bottom.
+            pub fn letterbox_offset(self, other: Self) -> $ratio_vec {
+                let fitted = self.fit_within(other);
+
+                $ratio_vec::new(
+                    (other.width as $ratio - fitted.x) / 2.0,
+                    (other.height as $ratio - fitted.y) / 2.0,
+                )
+            }
+
+            fn scaled_by(self, pick: impl Fn($ratio, $ratio) -> $ratio, other: Self) -> $ratio_vec {
+                if self.is_empty() {
+                    return $ratio_vec::new(0.0, 0.0);
+                }
+
+                let (width, height) = (self.width as $ratio, self.height as $ratio);
+                let scale = pick(
+                    other.width as $ratio / width,
+                    other.height as $ratio / height,
+                );
+
+                $ratio_vec::new(width * scale, height * scale)
+            }
+
+            fn non_negative(n: $type_) -> $type_ {
+                // Written so NaN also becomes zero
+                if n > 0 as $type_ {
+                    n
+                } else {
+                    0 as $type_
+                }
+            }
+        }
+
+        impl From<$name> for $vec {
+            fn from(size: $name) -> Self {
+                size.to_vec2()
+            }
+        }
+
+        impl From<$vec> for $name {
+            fn from(v: $vec) -> Self {
+                Self::from_vec2(v)
+            }
+        }
+    };
+}
+
+create_size2!(
+    /// A width and height stored as [`f32`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Size2f32;
+    /// let screen = Size2f32::new(1920.0, 1080.0);
+    /// let sprite = Size2f32::new(64.0, 64.0);
+    ///
+    /// assert_eq!(screen.aspect_ratio(), 16.0 / 9.0);
+    /// assert!(sprite.fits_within(screen));
+    /// ```
+    Size2f32,
+    f32,
+    Vec2f32,
+    f32,
+    f32,
+    Vec2f32
+);
+
+create_size2!(
+    /// A width and height stored as [`f64`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Size2f64;
+    /// assert_eq!(Size2f64::new(3.0, -2.0).height(), 0.0);
+    /// ```
+    Size2f64,
+    f64,
+    Vec2f64,
+    f64,
+    f64,
+    Vec2f64
+);
+
+create_size2!(
+    /// A width and height stored as [`u32`]s, such as the size of a window or texture.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Size2u32;
+    /// let texture = Size2u32::new(256, 128);
+    ///
+    /// assert_eq!(texture.area(), 32768);
+    /// assert_eq!(texture.aspect_ratio(), 2.0);
+    /// ```
+    Size2u32,
+    u32,
+    Vec2u32,
+    u64,
+    f64,
+    Vec2f64
+);
+
+create_size2!(
+    /// A width and height stored as [`usize`]s, such as the size of a grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Size2usize;
+    /// assert!(Size2usize::new(0, 10).is_empty());
+    /// ```
+    Size2usize,
+    usize,
+    Vec2usize,
+    u128,
+    f64,
+    Vec2f64
+);
@@ -0,0 +1,207 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use super::Vec2f32;
+
+// Spaces //
+
+/// Marks a vector as being in world space.
+#[derive(Copy, Clone, Debug)]
+pub enum WorldSpace {}
+
+/// Marks a vector as being in screen space, measured in pixels.
+#[derive(Copy, Clone, Debug)]
+pub enum ScreenSpace {}
+
+/// Marks a vector as being in texture space, where `0` to `1` covers the whole texture.
+#[derive(Copy, Clone, Debug)]
+pub enum UvSpace {}
+
+// Typed Vector //
+
+/// A vector tagged with the coordinate space it belongs to.
+///
+/// Vectors in the same space can be added and subtracted, but mixing spaces is a compile error.
+/// Use [`TypedVec2::cast_space`] or a [`SpaceTransform`] to move between spaces on purpose.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{ScreenSpace, TypedVec2, Vec2f32, WorldSpace};
+/// let player = TypedVec2::<_, WorldSpace>::new(Vec2f32::new(10.0, 5.0));
+/// let step = TypedVec2::<_, WorldSpace>::new(Vec2f32::new(1.0, 0.0));
+///
+/// assert_eq!((player + step).vec, Vec2f32::new(11.0, 5.0));
+/// ```
+///
+/// ```compile_fail
+/// # use manyvecs::macroed::{ScreenSpace, TypedVec2, Vec2f32, WorldSpace};
+/// let player = TypedVec2::<_, WorldSpace>::new(Vec2f32::new(10.0, 5.0));
+/// let cursor = TypedVec2::<_, ScreenSpace>::new(Vec2f32::new(1.0, 0.0));
+///
+/// let _ = player + cursor;
+/// ```
+pub struct TypedVec2<T, S> {
+    /// The untagged vector.
+    pub vec: T,
+    space: PhantomData<fn() -> S>,
+}
+
+impl<T, S> TypedVec2<T, S> {
+    /// Creates a new TypedVec2.
+    pub const fn new(vec: T) -> Self {
+        TypedVec2 {
+            vec,
+            space: PhantomData,
+        }
+    }
+
+    /// Returns the same vector, tagged as being in a different space.
+    pub fn cast_space<S2>(self) -> TypedVec2<T, S2> {
+        TypedVec2::new(self.vec)
+    }
+}
+
+// These are implemented by hand so the space doesn't also need to implement the trait
+
+impl<T: Copy, S> Copy for TypedVec2<T, S> {}
+
+impl<T: Clone, S> Clone for TypedVec2<T, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.vec.clone())
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for TypedVec2<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TypedVec2").field(&self.vec).finish()
+    }
+}
+
+impl<T: PartialEq, S> PartialEq for TypedVec2<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vec == other.vec
+    }
+}
+
+impl<T: Add<Output = T>, S> Add<Self> for TypedVec2<T, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.vec + rhs.vec)
+    }
+}
+
+impl<T: AddAssign, S> AddAssign<Self> for TypedVec2<T, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.vec += rhs.vec;
+    }
+}
+
+impl<T: Sub<Output = T>, S> Sub<Self> for TypedVec2<T, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.vec - rhs.vec)
+    }
+}
+
+impl<T: SubAssign, S> SubAssign<Self> for TypedVec2<T, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.vec -= rhs.vec;
+    }
+}
+
+impl<T: Mul<K, Output = T>, K, S> Mul<K> for TypedVec2<T, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: K) -> Self {
+        Self::new(self.vec * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>, S> Neg for TypedVec2<T, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.vec)
+    }
+}
+
+// Transform //
+
+/// Converts vectors from one space to another by scaling and then offsetting them.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{ScreenSpace, SpaceTransform, TypedVec2, Vec2f32, WorldSpace};
+/// // 32 pixels per world unit, with the y axis flipped and the origin at (400, 300)
+/// let to_screen = SpaceTransform::<WorldSpace, ScreenSpace>::new(
+///     Vec2f32::new(32.0, -32.0),
+///     Vec2f32::new(400.0, 300.0),
+/// );
+///
+/// let world = TypedVec2::new(Vec2f32::new(1.0, 1.0));
+/// let screen = to_screen.apply(world);
+///
+/// assert_eq!(screen.vec, Vec2f32::new(432.0, 268.0));
+/// assert_eq!(to_screen.inverse().apply(screen), world);
+/// ```
+pub struct SpaceTransform<From, To> {
+    /// The amount each component is multiplied by.
+    pub scale: Vec2f32,
+    /// The amount added after scaling.
+    pub offset: Vec2f32,
+    spaces: PhantomData<fn(From) -> To>,
+}
+
+impl<From, To> SpaceTransform<From, To> {
+    /// Creates a new SpaceTransform.
+    pub const fn new(scale: Vec2f32, offset: Vec2f32) -> Self {
+        SpaceTransform {
+            scale,
+            offset,
+            spaces: PhantomData,
+        }
+    }
+
+    /// Moves a vector from the `From` space into the `To` space.
+    pub fn apply(&self, v: TypedVec2<Vec2f32, From>) -> TypedVec2<Vec2f32, To> {
+        TypedVec2::new(v.vec * self.scale + self.offset)
+    }
+
+    /// Returns the transform that moves vectors back from the `To` space into the `From` space.
+    pub fn inverse(&self) -> SpaceTransform<To, From> {
+        SpaceTransform::new(
+            Vec2f32::new(1.0, 1.0) / self.scale,
+            -self.offset / self.scale,
+        )
+    }
+
+    /// Returns a single transform that applies this one, followed by `next`.
+    pub fn then<Next>(&self, next: &SpaceTransform<To, Next>) -> SpaceTransform<From, Next> {
+        SpaceTransform::new(
+            self.scale * next.scale,
+            self.offset * next.scale + next.offset,
+        )
+    }
+}
+
+impl<From, To> Copy for SpaceTransform<From, To> {}
+
+impl<From, To> Clone for SpaceTransform<From, To> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<From, To> fmt::Debug for SpaceTransform<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpaceTransform")
+            .field("scale", &self.scale)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
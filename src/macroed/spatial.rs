@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use super::{point_in_circle, Rect, Vec2f32, Vec2i32};
+
+/// A broad-phase lookup structure that buckets items into square cells by position.
+///
+/// Items are stored in a [`HashMap`] keyed by the integer coordinates of their cell, so only
+/// occupied cells take up memory. Queries only look at the cells that overlap the queried area,
+/// which makes finding nearby items much cheaper than checking every item.
+///
+/// A cell size around the typical query radius works best.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{SpatialHash, Vec2f32};
+/// let mut hash = SpatialHash::new(10.0);
+///
+/// hash.insert(Vec2f32::new(1.0, 1.0), "near");
+/// hash.insert(Vec2f32::new(4.0, -2.0), "also near");
+/// hash.insert(Vec2f32::new(50.0, 50.0), "far");
+///
+/// let mut found: Vec<_> = hash
+///     .query_radius(Vec2f32::new(0.0, 0.0), 5.0)
+///     .map(|(_, item)| *item)
+///     .collect();
+/// found.sort();
+///
+/// assert_eq!(found, ["also near", "near"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialHash<T> {
+    cell_size: f32,
+    cells: HashMap<Vec2i32, Vec<(Vec2f32, T)>>,
+    len: usize,
+}
+
+impl<T> SpatialHash<T> {
+    /// Creates an empty spatial hash with square cells of width `cell_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive and finite.
+    pub fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "cell size must be positive and finite, got {cell_size}"
+        );
+
+        SpatialHash {
+            cell_size,
+            cells: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the width of each cell.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Returns the coordinates of the cell containing `position`.
+    ///
+    /// Positions too far away to have their own cell share the outermost cells, and NaN
+    /// components are placed in cell zero.
+    pub fn cell_of(&self, position: Vec2f32) -> Vec2i32 {
+        let cell = (position / self.cell_size).floor();
+        Vec2i32::new(Self::clamp_cell(cell.x), Self::clamp_cell(cell.y))
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.len = 0;
+    }
+
+    /// Adds `item` at `position`.
+    pub fn insert(&mut self, position: Vec2f32, item: T) {
+        let cell = self.cell_of(position);
+        self.cells.entry(cell).or_default().push((position, item));
+        self.len += 1;
+    }
+
+    /// Keeps only the items for which `keep` returns true.
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(Vec2f32, &T) -> bool,
+    {
+        self.cells.retain(|_, items| {
+            items.retain(|(position, item)| keep(*position, item));
+            !items.is_empty()
+        });
+
+        self.len = self.cells.values().map(Vec::len).sum();
+    }
+
+    /// Returns an iterator over every item and its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2f32, &T)> + '_ {
+        self.cells
+            .values()
+            .flatten()
+            .map(|(position, item)| (*position, item))
+    }
+
+    /// Returns an iterator over the items inside `rect` or on its edge, in no particular order.
+    pub fn query_rect(&self, rect: &Rect) -> impl Iterator<Item = (Vec2f32, &T)> + '_ {
+        let rect = *rect;
+
+        self.cells_overlapping(&rect)
+            .filter(move |(position, _)| rect.contains(*position))
+    }
+
+    /// Returns an iterator over the items within `radius` of `center`, in no particular order.
+    pub fn query_radius(
+        &self,
+        center: Vec2f32,
+        radius: f32,
+    ) -> impl Iterator<Item = (Vec2f32, &T)> + '_ {
+        let reach = Vec2f32::new(radius, radius);
+
+        self.cells_overlapping(&Rect::new(center - reach, center + reach))
+            .filter(move |(position, _)| point_in_circle(*position, center, radius))
+    }
+
+    /// Converts a whole number of cells to an `i32`, clamping it into range.
+    fn clamp_cell(cell: f32) -> i32 {
+        if cell.is_nan() {
+            0
+        } else if cell <= i32::MIN as f32 {
+            i32::MIN
+        } else if cell >= i32::MAX as f32 {
+            i32::MAX
+        } else {
+            cell as i32
+        }
+    }
+
+    /// Returns every item in the cells that overlap `rect`.
+    fn cells_overlapping(&self, rect: &Rect) -> impl Iterator<Item = (Vec2f32, &T)> + '_ {
+        let (lo, hi) = (self.cell_of(rect.min), self.cell_of(rect.max));
+        let in_range = move |cell: &Vec2i32| {
+            lo.x <= cell.x && cell.x <= hi.x && lo.y <= cell.y && cell.y <= hi.y
+        };
+
+        // Looking up every cell in a huge range is slower than scanning the occupied cells
+        let span =
+            (i128::from(hi.x) - i128::from(lo.x) + 1) * (i128::from(hi.y) - i128::from(lo.y) + 1);
+        let scan_all = span > self.cells.len() as i128;
+
+        let scanned = scan_all.then(|| {
+            self.cells
+                .iter()
+                .filter(move |(cell, _)| in_range(cell))
+                .flat_map(|(_, items)| items)
+        });
+
+        let looked_up = (!scan_all).then(|| {
+            (lo.y..=hi.y)
+                .flat_map(move |y| (lo.x..=hi.x).map(move |x| Vec2i32::new(x, y)))
+                .filter_map(|cell| self.cells.get(&cell))
+                .flatten()
+        });
+
+        scanned
+            .into_iter()
+            .flatten()
+            .chain(looked_up.into_iter().flatten())
+            .map(|(position, item)| (*position, item))
+    }
+}
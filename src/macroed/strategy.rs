@@ -0,0 +1,90 @@
+//! [`proptest`] strategies for generating vectors.
+//!
+//! Every vector type also implements [`Arbitrary`], so `any::<Vec2f32>()` works as well.
+//!
+//! # Example
+//!
+//! ```
+//! # use manyvecs::macroed::{strategy, Vec2i32};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(v in strategy::vec2_in::<_, Vec2i32>(-100..100))| {
+//!     prop_assert!(v.mag2() <= 20_000);
+//! });
+//! ```
+
+use std::fmt::Debug;
+use std::ops::Range;
+
+use proptest::num;
+use proptest::prelude::*;
+
+use super::*;
+
+/// Returns a strategy for vectors where both components are drawn from `range`.
+pub fn vec2_in<T, V>(range: Range<T>) -> impl Strategy<Value = V>
+where
+    Range<T>: Strategy<Value = T> + Clone,
+    V: From<(T, T)> + Debug,
+{
+    (range.clone(), range).prop_map(V::from)
+}
+
+/// Returns a strategy for vectors where both components are finite, so never infinite or NaN.
+pub fn finite_vec2f32() -> impl Strategy<Value = Vec2f32> {
+    let finite = num::f32::POSITIVE
+        | num::f32::NEGATIVE
+        | num::f32::NORMAL
+        | num::f32::SUBNORMAL
+        | num::f32::ZERO;
+
+    (finite, finite).prop_map(Vec2f32::from)
+}
+
+/// Returns a strategy for vectors where both components are finite, so never infinite or NaN.
+pub fn finite_vec2f64() -> impl Strategy<Value = Vec2f64> {
+    let finite = num::f64::POSITIVE
+        | num::f64::NEGATIVE
+        | num::f64::NORMAL
+        | num::f64::SUBNORMAL
+        | num::f64::ZERO;
+
+    (finite, finite).prop_map(Vec2f64::from)
+}
+
+/// A macro for implementing [`Arbitrary`] on [`Vec2`] structs.
+macro_rules! impl_arbitrary {
+    ($name:ident, $type_:ty) => {
+        impl Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = proptest::strategy::Map<
+                (
+                    <$type_ as Arbitrary>::Strategy,
+                    <$type_ as Arbitrary>::Strategy,
+                ),
+                fn(($type_, $type_)) -> Self,
+            >;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                (any::<$type_>(), any::<$type_>()).prop_map(Self::from)
+            }
+        }
+    };
+}
+
+impl_arbitrary!(Vec2f32, f32);
+impl_arbitrary!(Vec2f64, f64);
+
+impl_arbitrary!(Vec2u8, u8);
+impl_arbitrary!(Vec2u16, u16);
+impl_arbitrary!(Vec2u32, u32);
+impl_arbitrary!(Vec2u64, u64);
+impl_arbitrary!(Vec2u128, u128);
+impl_arbitrary!(Vec2usize, usize);
+
+impl_arbitrary!(Vec2i8, i8);
+impl_arbitrary!(Vec2i16, i16);
+impl_arbitrary!(Vec2i32, i32);
+impl_arbitrary!(Vec2i64, i64);
+impl_arbitrary!(Vec2i128, i128);
+impl_arbitrary!(Vec2isize, isize);
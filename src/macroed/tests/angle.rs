@@ -0,0 +1,95 @@
+use std::f32::consts::PI;
+
+use crate::macroed::*;
+
+fn close(a: Angle, b: Angle) -> bool {
+    (a.radians() - b.radians()).abs() < 1e-5
+}
+
+#[test]
+fn units() {
+    let a = Angle::from_degrees(180.0);
+
+    assert_eq!(a, Angle::HALF_TURN);
+    assert_eq!(a.radians(), PI);
+    assert_eq!(Angle::from_radians(PI / 2.0).degrees(), 90.0);
+    assert_eq!(Angle::default(), Angle::ZERO);
+}
+
+#[test]
+fn normalized() {
+    assert_eq!(Angle::HALF_TURN.normalized(), Angle::HALF_TURN);
+    assert_eq!((-Angle::HALF_TURN).normalized(), Angle::HALF_TURN);
+    assert_eq!(Angle::FULL_TURN.normalized(), Angle::ZERO);
+    assert!(close(
+        (Angle::FULL_TURN * 2.0 + Angle::QUARTER_TURN).normalized(),
+        Angle::QUARTER_TURN
+    ));
+    assert!(close(
+        Angle::from_degrees(-450.0).normalized(),
+        -Angle::QUARTER_TURN
+    ));
+}
+
+#[test]
+fn arithmetic() {
+    let mut a = Angle::QUARTER_TURN;
+
+    a += Angle::QUARTER_TURN;
+    assert_eq!(a, Angle::HALF_TURN);
+
+    a -= Angle::FULL_TURN;
+    assert_eq!(a, -Angle::HALF_TURN);
+
+    a *= 2.0;
+    a /= -4.0;
+    assert_eq!(a, Angle::QUARTER_TURN);
+    assert_eq!(a / 2.0 + a * 2.0 - a, Angle::from_radians(PI * 0.75));
+}
+
+#[test]
+fn vectors() {
+    assert_eq!(Vec2::from_angle(Angle::ZERO), Vec2::new(1.0, 0.0));
+    assert_eq!(Vec2::new(0.0, 2.0).angle(), Angle::QUARTER_TURN);
+    assert_eq!(Vec2::new(-1.0, 0.0).angle(), Angle::HALF_TURN);
+
+    let v = Vec2::from_angle(Angle::from_degrees(60.0));
+    assert!((v.angle().degrees() - 60.0).abs() < 1e-5);
+    assert!((v.mag() - 1.0).abs() < 1e-6);
+    assert_eq!(Angle::QUARTER_TURN.sin(), 1.0);
+    assert_eq!(Angle::HALF_TURN.cos(), -1.0);
+}
+
+#[test]
+fn shortest_to() {
+    let turn = |from: f32, to: f32| Angle::from_degrees(from).shortest_to(Angle::from_degrees(to));
+
+    assert!(close(turn(359.0, 1.0), Angle::from_degrees(2.0)));
+    assert!(close(turn(1.0, 359.0), Angle::from_degrees(-2.0)));
+    assert!(close(turn(-170.0, 170.0), Angle::from_degrees(-20.0)));
+    assert!(close(turn(0.0, 180.0), Angle::from_degrees(180.0)));
+    assert!(close(turn(90.0, 90.0), Angle::from_degrees(0.0)));
+}
+
+#[test]
+fn angle_to() {
+    let right = Vec2::new(1.0, 0.0);
+
+    assert!(close(
+        right.angle_to(Vec2::new(0.0, 3.0)),
+        Angle::from_degrees(90.0)
+    ));
+    assert!(close(
+        right.angle_to(Vec2::new(0.0, -3.0)),
+        Angle::from_degrees(-90.0)
+    ));
+    assert!(close(
+        right.angle_to(Vec2::new(-1.0, 0.0)),
+        Angle::from_degrees(180.0)
+    ));
+    assert!(close(
+        Vec2::new(-1.0, 0.1).angle_to(Vec2::new(-1.0, -0.1)),
+        Angle::from_radians(0.2f32.atan2(0.99))
+    ));
+    assert_eq!(right.angle_to(Vec2::new(0.0, 0.0)), Angle::ZERO);
+}
@@ -0,0 +1,156 @@
+use crate::macroed::*;
+
+#[test]
+fn signed_area_matches_triangle() {
+    let (a, b, c) = (
+        Vec2::new(1.0, 1.0),
+        Vec2::new(5.0, 2.0),
+        Vec2::new(2.0, 4.0),
+    );
+
+    assert_eq!(signed_area(a, b, c), Triangle::new(a, b, c).signed_area());
+    assert_eq!(signed_area(a, c, b), -signed_area(a, b, c));
+    assert_eq!(signed_area(a, b, a), 0.0);
+}
+
+#[test]
+fn signed_area_ints() {
+    let a = Vec2u8::new(0, 255);
+    let b = Vec2u8::new(255, 255);
+    let c = Vec2u8::new(0, 0);
+
+    assert_eq!(signed_area(a, b, c), -255.0 * 255.0 / 2.0);
+    assert_eq!(
+        signed_area(
+            Vec2i32::new(i32::MIN, i32::MIN),
+            Vec2i32::new(i32::MAX, i32::MIN),
+            Vec2i32::new(i32::MIN, i32::MAX),
+        ),
+        (u32::MAX as f64).powi(2) / 2.0
+    );
+}
+
+#[test]
+fn signed_area_64_bit() {
+    let side = u64::MAX as f64;
+
+    assert_eq!(
+        signed_area(
+            Vec2i64::new(i64::MIN, i64::MIN),
+            Vec2i64::new(i64::MAX, i64::MIN),
+            Vec2i64::new(i64::MIN, i64::MAX),
+        ),
+        side * side / 2.0
+    );
+    assert_eq!(
+        signed_area(
+            Vec2u64::new(0, 0),
+            Vec2u64::new(0, u64::MAX),
+            Vec2u64::new(u64::MAX, 0),
+        ),
+        -side * side / 2.0
+    );
+
+    // The two products are huge but nearly cancel out
+    assert_eq!(
+        signed_area(
+            Vec2u64::new(0, 0),
+            Vec2u64::new(u64::MAX, u64::MAX - 1),
+            Vec2u64::new(u64::MAX - 1, u64::MAX - 2),
+        ),
+        -0.5
+    );
+}
+
+#[test]
+fn parallelogram_area_ints() {
+    assert_eq!(
+        parallelogram_area(Vec2i64::new(i64::MIN, 0), Vec2i64::new(0, i64::MIN)),
+        2f64.powi(126)
+    );
+    assert_eq!(
+        parallelogram_area(Vec2i::new(2, 4), Vec2i::new(-1, -2)),
+        0.0
+    );
+}
+
+#[test]
+fn orientation_ints() {
+    let a = Vec2i64::new(i64::MIN / 2, i64::MIN / 2);
+    let b = Vec2i64::new(i64::MAX / 2, i64::MAX / 2);
+
+    assert_eq!(
+        orientation(a, b, Vec2i64::new(0, 0)),
+        Orientation::Collinear
+    );
+    assert_eq!(
+        orientation(a, b, Vec2i64::new(0, 1)),
+        Orientation::Counterclockwise
+    );
+    assert_eq!(
+        orientation(a, b, Vec2i64::new(1, 0)),
+        Orientation::Clockwise
+    );
+    assert_eq!(
+        orientation(Vec2u8::new(0, 0), Vec2u8::new(0, 0), Vec2u8::new(3, 7)),
+        Orientation::Collinear
+    );
+}
+
+#[test]
+fn orientation_64_bit() {
+    let (a, b) = (Vec2u64::new(0, 0), Vec2u64::new(u64::MAX, 0));
+
+    assert_eq!(
+        orientation(a, b, Vec2u64::new(0, u64::MAX)),
+        Orientation::Counterclockwise
+    );
+    assert_eq!(
+        orientation(b, a, Vec2u64::new(0, u64::MAX)),
+        Orientation::Clockwise
+    );
+
+    // The products are about 2^128 and differ by one
+    assert_eq!(
+        orientation(
+            a,
+            Vec2u64::new(u64::MAX, u64::MAX - 1),
+            Vec2u64::new(u64::MAX - 1, u64::MAX - 2),
+        ),
+        Orientation::Clockwise
+    );
+    assert_eq!(
+        orientation(
+            Vec2i64::new(i64::MIN, i64::MIN),
+            Vec2i64::new(0, 0),
+            Vec2i64::new(i64::MAX, i64::MAX),
+        ),
+        Orientation::Collinear
+    );
+    assert_eq!(
+        orientation(
+            Vec2i64::new(i64::MIN, i64::MAX),
+            Vec2i64::new(i64::MAX, i64::MIN),
+            Vec2i64::new(i64::MIN, i64::MIN),
+        ),
+        Orientation::Clockwise
+    );
+}
+
+#[test]
+fn orientation_floats() {
+    let a = Vec2d::new(0.0, 0.0);
+
+    assert_eq!(
+        orientation(a, Vec2d::new(0.1, 0.3), Vec2d::new(0.3, 0.9)),
+        Orientation::Collinear
+    );
+    assert_eq!(
+        orientation(a, Vec2d::new(1.0, 0.0), Vec2d::new(1.0, 1e-9)),
+        Orientation::Counterclockwise
+    );
+    assert_eq!(
+        orientation(a, Vec2d::new(1.0, 0.0), Vec2d::new(1.0, -1e-9)),
+        Orientation::Clockwise
+    );
+}
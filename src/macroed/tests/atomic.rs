@@ -0,0 +1,57 @@
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+
+use crate::macroed::*;
+
+#[test]
+fn load_store() {
+    let atomic = AtomicVec2f32::new(Vec2::new(-0.0, f32::INFINITY));
+
+    assert_eq!(
+        atomic.load(Ordering::SeqCst),
+        Vec2::new(-0.0, f32::INFINITY)
+    );
+    assert_eq!(
+        atomic.swap(Vec2::new(1.0, 2.0), Ordering::SeqCst),
+        Vec2::new(-0.0, f32::INFINITY)
+    );
+    assert_eq!(atomic.into_inner(), Vec2::new(1.0, 2.0));
+}
+
+#[test]
+fn fetch_update_none() {
+    let atomic = AtomicVec2f32::from(Vec2::new(1.0, 2.0));
+
+    assert_eq!(
+        atomic.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |_| None),
+        Err(Vec2::new(1.0, 2.0))
+    );
+}
+
+#[test]
+fn concurrent_updates() {
+    let atomic = Arc::new(AtomicVec2f32::new(Vec2::new(0.0, 0.0)));
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let atomic = Arc::clone(&atomic);
+
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    atomic
+                        .fetch_update(Ordering::AcqRel, Ordering::Acquire, |v| {
+                            Some(v + Vec2::new(1.0, 2.0))
+                        })
+                        .unwrap();
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load(Ordering::Acquire), Vec2::new(400.0, 800.0));
+}
@@ -0,0 +1,66 @@
+use crate::macroed::*;
+
+#[test]
+fn other() {
+    assert_eq!(Axis2::X.other(), Axis2::Y);
+    assert_eq!(Axis2::Y.other(), Axis2::X);
+    assert_eq!(Axis2::ALL, [Axis2::X, Axis2::Y]);
+}
+
+#[test]
+fn get_and_set() {
+    let mut v = Vec2i::new(3, -4);
+
+    assert_eq!(v.get(Axis2::X), 3);
+    assert_eq!(v.get(Axis2::Y), -4);
+
+    v.set(Axis2::Y, 8);
+    *v.axis_mut(Axis2::X) += 1;
+    assert_eq!(v, Vec2i::new(4, 8));
+}
+
+#[test]
+fn sweep_axes() {
+    // Overlap test of two boxes, written once for both axes
+    let overlaps = |a_min: Vec2, a_max: Vec2, b_min: Vec2, b_max: Vec2| {
+        Axis2::ALL
+            .into_iter()
+            .all(|axis| a_min.get(axis) <= b_max.get(axis) && b_min.get(axis) <= a_max.get(axis))
+    };
+
+    let (a_min, a_max) = (Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+
+    assert!(overlaps(
+        a_min,
+        a_max,
+        Vec2::new(1.0, 1.0),
+        Vec2::new(3.0, 3.0)
+    ));
+    assert!(!overlaps(
+        a_min,
+        a_max,
+        Vec2::new(1.0, 3.0),
+        Vec2::new(3.0, 4.0)
+    ));
+}
+
+#[test]
+fn major_axis() {
+    assert_eq!(Vec2::new(-3.0, 2.0).major_axis(), Axis2::X);
+    assert_eq!(Vec2::new(0.5, -0.75).major_axis(), Axis2::Y);
+    assert_eq!(Vec2i::new(-2, 2).major_axis(), Axis2::X);
+    assert_eq!(Vec2u::new(1, 9).major_axis(), Axis2::Y);
+    assert_eq!(Vec2i32::new(i32::MIN, i32::MAX).major_axis(), Axis2::X);
+    assert_eq!(Vec2i32::new(0, i32::MIN).major_axis(), Axis2::Y);
+}
+
+#[test]
+fn abs_max_component() {
+    assert_eq!(Vec2::new(-3.0, 2.0).abs_max_component(), 3.0);
+    assert_eq!(Vec2i8::new(4, -7).abs_max_component(), 7);
+    assert_eq!(Vec2u8::new(200, 9).abs_max_component(), 200);
+    assert_eq!(Vec2i32::new(i32::MIN, 0).abs_max_component(), 1 << 31);
+
+    let v = Vec2i::new(5, -6);
+    assert_eq!(v.get(v.major_axis()).unsigned_abs(), v.abs_max_component());
+}
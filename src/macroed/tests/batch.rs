@@ -0,0 +1,111 @@
+use crate::macroed::batch::*;
+use crate::macroed::*;
+
+#[test]
+fn interleave_round_trip() {
+    let xs = [1, 2, 3];
+    let ys = [4, 5, 6];
+    let vs: Vec<Vec2i8> = interleave(&xs, &ys);
+
+    assert_eq!(vs[2], Vec2i8::new(3, 6));
+    assert_eq!(deinterleave(&vs), (xs.to_vec(), ys.to_vec()));
+}
+
+#[test]
+#[should_panic]
+fn interleave_mismatched() {
+    let _: Vec<Vec2> = interleave(&[1.0, 2.0], &[1.0]);
+}
+
+#[test]
+fn flatten_views() {
+    let mut vs = [Vec2u16::new(1, 2), Vec2u16::new(3, 4)];
+
+    assert_eq!(flatten(&vs), [1, 2, 3, 4]);
+
+    flatten_mut(&mut vs)[3] = 10;
+
+    assert_eq!(vs[1], Vec2u16::new(3, 10));
+}
+
+#[test]
+fn unflatten_views() {
+    let mut flat = [1.0, 2.0, 3.0, 4.0];
+
+    assert_eq!(
+        unflatten::<Vec2d>(&flat),
+        Some(&[Vec2d::new(1.0, 2.0), Vec2d::new(3.0, 4.0)][..])
+    );
+
+    unflatten_mut::<Vec2d>(&mut flat).unwrap()[0].y = 0.0;
+
+    assert_eq!(flat, [1.0, 0.0, 3.0, 4.0]);
+    assert_eq!(unflatten::<Vec2d>(&flat[..3]), None);
+}
+
+#[test]
+fn slice_ops() {
+    let mut vs = [Vec2i32::new(1, 2), Vec2i32::new(3, 4)];
+    let other = [Vec2i32::new(2, 2), Vec2i32::new(-1, 3)];
+
+    add_assign_slices(&mut vs, &other);
+    assert_eq!(vs, [Vec2i32::new(3, 4), Vec2i32::new(2, 7)]);
+
+    sub_assign_slices(&mut vs, &other);
+    mul_assign_slices(&mut vs, &other);
+    assert_eq!(vs, [Vec2i32::new(2, 4), Vec2i32::new(-3, 12)]);
+
+    scale_slice(&mut vs, 2);
+    translate_slice(&mut vs, Vec2i32::new(1, -1));
+    assert_eq!(vs, [Vec2i32::new(5, 7), Vec2i32::new(-5, 23)]);
+
+    scale_translate_slice(&mut vs, Vec2i32::new(0, 1), Vec2i32::new(1, 1));
+    assert_eq!(vs, [Vec2i32::new(1, 8), Vec2i32::new(1, 24)]);
+}
+
+#[test]
+#[should_panic]
+fn slice_ops_mismatched() {
+    add_assign_slices(&mut [Vec2::new(1.0, 2.0)], &[]);
+}
+
+#[test]
+fn buffer_basics() {
+    let mut buffer: Vec2Buffer<Vec2u8> = (1..4).map(|i| Vec2u8::new(i, i * 2)).collect();
+
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.xs(), [1, 2, 3]);
+    assert_eq!(buffer.ys(), [2, 4, 6]);
+
+    buffer.set(0, Vec2u8::new(9, 9));
+
+    assert_eq!(buffer.get(0), Some(Vec2u8::new(9, 9)));
+    assert_eq!(buffer.get(3), None);
+    assert_eq!(buffer.pop(), Some(Vec2u8::new(3, 6)));
+    assert_eq!(
+        buffer.iter().collect::<Vec<_>>(),
+        [Vec2u8::new(9, 9), Vec2u8::new(2, 4)]
+    );
+}
+
+#[test]
+fn buffer_math() {
+    let mut positions = Vec2Buffer::from_components(vec![0.0, 1.0], vec![0.0, -1.0]);
+    let velocities: Vec2Buffer<Vec2> = [Vec2::new(2.0, 0.0), Vec2::new(0.0, 4.0)]
+        .into_iter()
+        .collect();
+
+    positions.add_scaled_buffer(&velocities, 0.5);
+    assert_eq!(
+        positions.into_components(),
+        (vec![1.0, 1.0], vec![0.0, 1.0])
+    );
+
+    let mut buffer = velocities.clone();
+    buffer.add_assign_buffer(&velocities);
+    buffer.scale(0.5);
+    buffer.translate(Vec2::new(1.0, 1.0));
+
+    assert_eq!(buffer.xs(), [3.0, 1.0]);
+    assert_eq!(buffer.ys(), [1.0, 5.0]);
+}
@@ -0,0 +1,68 @@
+use crate::macroed::*;
+
+#[test]
+fn to_bits() {
+    let v = Vec2::new(1.5, -2.0);
+
+    assert_eq!(
+        v.to_bits(),
+        Vec2u32::new(1.5f32.to_bits(), (-2.0f32).to_bits())
+    );
+    assert_eq!(Vec2::from_bits(v.to_bits()), v);
+    assert_eq!(
+        Vec2d::from_bits(Vec2d::new(0.1, 1e300).to_bits()),
+        Vec2d::new(0.1, 1e300)
+    );
+}
+
+#[test]
+fn bits_are_exact() {
+    // Signed zeros differ, and NaNs keep their payload
+    assert_ne!(
+        Vec2::new(0.0, 0.0).to_bits(),
+        Vec2::new(-0.0, 0.0).to_bits()
+    );
+
+    let nan = Vec2d::new(f64::NAN, 1.0);
+    assert_eq!(nan.to_bits(), nan.to_bits());
+    assert!(Vec2d::from_bits(nan.to_bits()).x.is_nan());
+}
+
+#[test]
+fn ulps_eq() {
+    let one = Vec2::new(1.0, 1.0);
+    let next = f32::from_bits(1.0f32.to_bits() + 2);
+
+    assert!(one.ulps_eq(one, 0));
+    assert!(one.ulps_eq((next, 1.0), 2));
+    assert!(!one.ulps_eq((next, 1.0), 1));
+
+    // Signs and NaNs
+    assert!(Vec2d::new(0.0, 0.0).ulps_eq((-0.0, 0.0), 0));
+    assert!(!Vec2d::new(1e-300, 0.0).ulps_eq((-1e-300, 0.0), u64::MAX));
+    assert!(!Vec2d::new(f64::NAN, 0.0).ulps_eq((f64::NAN, 0.0), u64::MAX));
+}
+
+#[test]
+fn le_bytes() {
+    let v = Vec2u16::new(0x1234, 0x5678);
+
+    assert_eq!(v.to_le_bytes(), [0x34, 0x12, 0x78, 0x56]);
+    assert_eq!(Vec2u16::from_le_bytes([0x34, 0x12, 0x78, 0x56]), v);
+
+    let v = Vec2::new(1.5, -2.0);
+    assert_eq!(v.to_le_bytes()[..4], 1.5f32.to_le_bytes());
+    assert_eq!(Vec2::from_le_bytes(v.to_le_bytes()), v);
+}
+
+#[test]
+fn be_bytes() {
+    let v = Vec2i32::new(-1, 0x01020304);
+
+    assert_eq!(v.to_be_bytes(), [0xff, 0xff, 0xff, 0xff, 1, 2, 3, 4]);
+    assert_eq!(Vec2i32::from_be_bytes(v.to_be_bytes()), v);
+
+    let v = Vec2u::new(7, usize::MAX);
+    assert_eq!(Vec2u::from_be_bytes(v.to_be_bytes()), v);
+    assert_ne!(v.to_be_bytes(), v.to_le_bytes());
+}
@@ -0,0 +1,47 @@
+use std::f32::consts::PI;
+
+use crate::macroed::*;
+
+#[test]
+fn measure() {
+    let c = Capsule::new(Segment::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 4.0)), 2.0);
+
+    assert_eq!(c.area(), 4.0 * PI + 12.0);
+    assert_eq!(c.perimeter(), 4.0 * PI + 6.0);
+    assert_eq!(c.centroid(), Vec2::new(1.0, 2.5));
+
+    // Without a length, a capsule is a circle
+    let point = Capsule::new(Segment::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0)), 2.0);
+    let circle = Circle::new(Vec2::new(1.0, 1.0), 2.0);
+
+    assert_eq!(point.area(), circle.area());
+    assert_eq!(point.perimeter(), circle.perimeter());
+}
+
+#[test]
+fn containment() {
+    let c = Capsule::new(Segment::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0)), 1.0);
+
+    assert!(c.contains_point(Vec2::new(2.0, 1.0)));
+    assert!(c.contains_point(Vec2::new(-0.6, 0.6)));
+    assert!(!c.contains_point(Vec2::new(-0.8, 0.8)));
+    assert!(!c.contains_point(Vec2::new(2.0, -1.5)));
+}
+
+#[test]
+fn distance() {
+    let a = Capsule::new(Segment::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0)), 1.0);
+    let b = Circle::new(Vec2::new(2.0, 5.0), 2.0);
+
+    assert_eq!(
+        distance_between(&a, &b),
+        (2.0, Vec2::new(2.0, 1.0), Vec2::new(2.0, 3.0))
+    );
+
+    let c = Capsule::new(Segment::new(Vec2::new(7.0, -3.0), Vec2::new(7.0, 3.0)), 0.5);
+
+    assert_eq!(
+        distance_between(&a, &c),
+        (1.5, Vec2::new(5.0, 0.0), Vec2::new(6.5, 0.0))
+    );
+}
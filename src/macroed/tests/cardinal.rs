@@ -0,0 +1,57 @@
+use crate::macroed::*;
+
+#[test]
+fn direction4() {
+    for d in Direction4::ALL {
+        assert_eq!(d.to_vec2().to_direction4(), Some(d));
+        assert_eq!(d.to_vec2() + d.opposite().to_vec2(), Vec2i::new(0, 0));
+        assert_eq!(Direction8::from(d).to_vec2(), d.to_vec2());
+    }
+
+    assert_eq!(Direction4::North.to_vec2(), Vec2i::new(0, 1));
+}
+
+#[test]
+fn direction8() {
+    for d in Direction8::ALL {
+        assert_eq!(d.to_vec2().sign_direction8(), Some(d));
+        assert_eq!(d.opposite().opposite(), d);
+        assert_eq!(d.is_diagonal(), d.to_vec2().x != 0 && d.to_vec2().y != 0);
+    }
+
+    assert_eq!(Direction8::NorthWest.opposite(), Direction8::SouthEast);
+}
+
+#[test]
+fn float_to_direction() {
+    let v = Vec2::new(0.9, 0.5);
+
+    assert_eq!(v.to_direction4(), Some(Direction4::East));
+    assert_eq!(v.to_direction8(), Some(Direction8::NorthEast));
+    assert_eq!(
+        Vec2::new(-1.0, -0.1).to_direction8(),
+        Some(Direction8::West)
+    );
+    assert_eq!(
+        Vec2::new(0.1, -1.0).to_direction8(),
+        Some(Direction8::South)
+    );
+
+    assert_eq!(Vec2::new(0.0, 0.0).to_direction4(), None);
+    assert_eq!(Vec2::new(f32::NAN, 1.0).to_direction8(), None);
+}
+
+#[test]
+fn grid_to_direction() {
+    assert_eq!(Vec2i::new(-3, 2).to_direction4(), Some(Direction4::West));
+    assert_eq!(Vec2i::new(1, -7).to_direction4(), Some(Direction4::South));
+    assert_eq!(
+        Vec2i::new(isize::MIN, 5).to_direction4(),
+        Some(Direction4::West)
+    );
+    assert_eq!(
+        Vec2i::new(5, 1).sign_direction8(),
+        Some(Direction8::NorthEast)
+    );
+    assert_eq!(Vec2i::new(0, 0).sign_direction8(), None);
+}
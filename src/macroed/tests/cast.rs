@@ -0,0 +1,56 @@
+use crate::macroed::*;
+
+#[test]
+fn cast() {
+    assert_eq!(Vec2u::new(3, 4).cast::<f32>(), Vec2::new(3.0, 4.0));
+    assert_eq!(Vec2i8::new(-1, 5).cast::<i64>(), Vec2i64::new(-1, 5));
+    assert_eq!(Vec2::new(1.5, 2.25).cast::<f64>(), Vec2d::new(1.5, 2.25));
+}
+
+#[test]
+fn cast_truncates() {
+    // Floats truncate toward zero and saturate
+    assert_eq!(Vec2::new(2.9, -2.9).cast::<i32>(), Vec2i32::new(2, -2));
+    assert_eq!(Vec2::new(-1.0, 300.0).cast::<u8>(), Vec2u8::new(0, 255));
+    assert_eq!(Vec2::new(f32::NAN, 0.0).cast::<i32>(), Vec2i32::new(0, 0));
+
+    // Integers wrap
+    assert_eq!(Vec2i32::new(-1, 256).cast::<u8>(), Vec2u8::new(255, 0));
+}
+
+#[test]
+fn shorthands() {
+    let v = Vec2i32::new(-3, 7);
+
+    assert_eq!(v.as_f32(), Vec2::new(-3.0, 7.0));
+    assert_eq!(v.as_f64(), Vec2d::new(-3.0, 7.0));
+    assert_eq!(v.as_i32(), v);
+    assert_eq!(v.as_u32(), Vec2u32::new(u32::MAX - 2, 7));
+    assert_eq!(v.as_isize(), Vec2i::new(-3, 7));
+    assert_eq!(Vec2::new(1.9, 2.1).as_usize(), Vec2u::new(1, 2));
+}
+
+#[test]
+fn rounding() {
+    let v = Vec2::new(2.5, -1.2);
+
+    assert_eq!(v.round_cast::<i64>(), Vec2i64::new(3, -1));
+    assert_eq!(v.floor_cast::<i8>(), Vec2i8::new(2, -2));
+    assert_eq!(v.ceil_cast::<isize>(), Vec2i::new(3, -1));
+
+    assert_eq!(v.to_i32_round(), Vec2i32::new(3, -1));
+    assert_eq!(v.to_i32_floor(), Vec2i32::new(2, -2));
+    assert_eq!(v.to_i32_ceil(), Vec2i32::new(3, -1));
+}
+
+#[test]
+fn rounding_unsigned() {
+    let v = Vec2d::new(3.7, -0.5);
+
+    assert_eq!(v.to_u32_round(), Vec2u32::new(4, 0));
+    assert_eq!(v.to_u32_floor(), Vec2u32::new(3, 0));
+    assert_eq!(v.to_u32_ceil(), Vec2u32::new(4, 0));
+    assert_eq!(v.to_usize_round(), Vec2u::new(4, 0));
+    assert_eq!(v.to_usize_floor(), Vec2u::new(3, 0));
+    assert_eq!(v.to_usize_ceil(), Vec2u::new(4, 0));
+}
@@ -0,0 +1,66 @@
+use std::f32::consts::PI;
+
+use crate::macroed::*;
+
+#[test]
+fn measure() {
+    let c = Circle::new(Vec2::new(1.0, 2.0), 2.0);
+
+    assert_eq!(c.area(), 4.0 * PI);
+    assert_eq!(c.perimeter(), 4.0 * PI);
+    assert_eq!(c.centroid(), Vec2::new(1.0, 2.0));
+}
+
+#[test]
+fn containment() {
+    let c = Circle::new(Vec2::new(0.0, 0.0), 5.0);
+
+    assert!(c.contains_point(Vec2::new(3.0, 4.0)));
+    assert!(!c.contains_point(Vec2::new(3.0, 4.5)));
+    assert!(c.intersects_circle(&Circle::new(Vec2::new(8.0, 0.0), 3.0)));
+    assert!(!c.intersects_circle(&Circle::new(Vec2::new(8.0, 0.0), 2.5)));
+}
+
+#[test]
+fn intersects_rect() {
+    let c = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+
+    assert!(c.intersects_rect(&Rect::new(Vec2::new(-5.0, -5.0), Vec2::new(5.0, 5.0))));
+    assert!(c.intersects_rect(&Rect::new(Vec2::new(0.5, 0.5), Vec2::new(2.0, 2.0))));
+    assert!(!c.intersects_rect(&Rect::new(Vec2::new(0.8, 0.8), Vec2::new(2.0, 2.0))));
+}
+
+#[test]
+fn closest_points() {
+    let c = Circle::new(Vec2::new(1.0, 1.0), 2.0);
+
+    assert_eq!(c.closest_point(Vec2::new(1.5, 1.0)), Vec2::new(1.5, 1.0));
+    assert_eq!(c.closest_point(Vec2::new(1.0, -9.0)), Vec2::new(1.0, -1.0));
+    assert_eq!(
+        c.closest_edge_point(Vec2::new(1.5, 1.0)),
+        Vec2::new(3.0, 1.0)
+    );
+    assert_eq!(c.closest_edge_point(c.center), Vec2::new(3.0, 1.0));
+}
+
+#[test]
+fn distance() {
+    let a = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+    let b = Circle::new(Vec2::new(0.0, 5.0), 2.0);
+
+    assert_eq!(
+        distance_between(&a, &b),
+        (2.0, Vec2::new(0.0, 1.0), Vec2::new(0.0, 3.0))
+    );
+    assert_eq!(
+        distance_between(&a, &Vec2::new(-3.0, 0.0)),
+        (2.0, Vec2::new(-1.0, 0.0), Vec2::new(-3.0, 0.0))
+    );
+
+    let r = Rect::new(Vec2::new(0.5, -2.0), Vec2::new(4.0, 2.0));
+
+    assert_eq!(
+        distance_between(&a, &r),
+        (-0.5, Vec2::new(1.0, 0.0), Vec2::new(0.5, 0.0))
+    );
+}
@@ -0,0 +1,47 @@
+use crate::macroed::*;
+
+#[test]
+fn hue_round_trip() {
+    for hue in [0.0, 45.0, 180.0, 270.0, 359.0] {
+        let v = Vec2::from_hue(hue, 0.5);
+
+        assert!((v.hue() - hue).abs() < 0.001);
+        assert!((v.mag() - 0.5).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn srgb_round_trip() {
+    for i in 0..=10 {
+        let c = i as f32 / 10.0;
+
+        assert!((linear_to_srgb(srgb_to_linear(c)) - c).abs() < 0.0001);
+    }
+
+    assert_eq!(srgb_to_linear(0.0), 0.0);
+    assert!((srgb_to_linear(1.0) - 1.0).abs() < 0.0001);
+}
+
+#[test]
+fn lerp_color_ends() {
+    let a = [0.2, 0.4, 0.6];
+    let b = [1.0, 0.0, 0.5];
+
+    for (x, y) in lerp_color(a, b, 0.0).iter().zip(a) {
+        assert!((x - y).abs() < 0.0001);
+    }
+
+    for (x, y) in lerp_color(a, b, 1.0).iter().zip(b) {
+        assert!((x - y).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn lerp_color_alpha() {
+    let a = [0.0, 0.0, 0.0, 0.2];
+    let b = [1.0, 1.0, 1.0, 1.0];
+    let mid = lerp_color(a, b, 0.5);
+
+    assert_eq!(mid[3], 0.6);
+    assert_eq!(mid[..3], lerp_color([0.0; 3], [1.0; 3], 0.5));
+}
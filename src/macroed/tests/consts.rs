@@ -0,0 +1,33 @@
+use crate::macroed::*;
+
+#[test]
+fn bounds() {
+    assert_eq!(Vec2u8::MIN, Vec2u8::new(0, 0));
+    assert_eq!(Vec2i32::MAX, Vec2i32::new(i32::MAX, i32::MAX));
+    assert_eq!(Vec2::MIN, Vec2::new(f32::MIN, f32::MIN));
+}
+
+#[test]
+fn bounding_box() {
+    let points = [
+        Vec2::new(1.0, -2.0),
+        Vec2::new(-3.0, 4.0),
+        Vec2::new(0.5, 0.5),
+    ];
+    let (mut min, mut max) = (Vec2::MAX, Vec2::MIN);
+
+    for p in points {
+        min = min.min(p);
+        max = max.max(p);
+    }
+
+    assert_eq!(min, Vec2::new(-3.0, -2.0));
+    assert_eq!(max, Vec2::new(1.0, 4.0));
+}
+
+#[test]
+fn infinity() {
+    assert_eq!(Vec2d::INFINITY, Vec2d::new(f64::INFINITY, f64::INFINITY));
+    assert_eq!(-Vec2::INFINITY, Vec2::NEG_INFINITY);
+    assert_eq!(Vec2::NEG_INFINITY.max(Vec2::MIN), Vec2::MIN);
+}
@@ -0,0 +1,41 @@
+use crate::macroed::*;
+
+#[test]
+fn from_widening() {
+    let v: Vec2u32 = Vec2u8::new(1, 255).into();
+    assert_eq!(v, Vec2u32::new(1, 255));
+
+    assert_eq!(Vec2d::from(Vec2::new(0.5, -2.0)), Vec2d::new(0.5, -2.0));
+    assert_eq!(
+        Vec2d::from(Vec2i32::new(i32::MIN, 7)),
+        Vec2d::new(i32::MIN as f64, 7.0)
+    );
+    assert_eq!(Vec2i::from(Vec2i16::new(-3, 4)), Vec2i::new(-3, 4));
+}
+
+#[test]
+fn try_from_narrowing() {
+    assert_eq!(
+        Vec2i32::try_from(Vec2i64::new(-5, 1 << 20)),
+        Ok(Vec2i32::new(-5, 1 << 20))
+    );
+    assert_eq!(
+        Vec2i32::try_from(Vec2i64::new(0, i64::MAX)),
+        Err(TryFromVec2Error)
+    );
+    assert_eq!(
+        Vec2u8::try_from(Vec2u32::new(256, 0)),
+        Err(TryFromVec2Error)
+    );
+}
+
+#[test]
+fn try_from_sign() {
+    assert_eq!(Vec2u::try_from(Vec2i::new(3, 4)), Ok(Vec2u::new(3, 4)));
+    assert_eq!(Vec2u::try_from(Vec2i::new(3, -4)), Err(TryFromVec2Error));
+    assert_eq!(Vec2i8::try_from(Vec2u8::new(128, 0)), Err(TryFromVec2Error));
+    assert_eq!(
+        TryFromVec2Error.to_string(),
+        "Vector component out of range for the target type"
+    );
+}
@@ -0,0 +1,69 @@
+use crate::macroed::*;
+
+#[derive(Vec2Like, Copy, Clone, Debug, PartialEq)]
+#[vec2(scalar = f32, neg)]
+struct ScreenPos(Vec2f32);
+
+#[derive(Vec2Like, Copy, Clone, Debug, PartialEq)]
+struct Tile {
+    coord: Vec2i32,
+}
+
+#[cfg(feature = "legacy")]
+#[derive(Vec2Like, Copy, Clone, Debug, PartialEq)]
+#[vec2(scalar = T)]
+struct Generic<T: num_traits::Num + Copy>(crate::legacy::Vec2<T>);
+
+#[test]
+fn vector_ops() {
+    let a = ScreenPos(Vec2::new(1.0, 2.0));
+    let b = ScreenPos(Vec2::new(3.0, 4.0));
+
+    assert_eq!(a + b, ScreenPos(Vec2::new(4.0, 6.0)));
+    assert_eq!(b - a, ScreenPos(Vec2::new(2.0, 2.0)));
+    assert_eq!(a * b, ScreenPos(Vec2::new(3.0, 8.0)));
+    assert_eq!(b / a, ScreenPos(Vec2::new(3.0, 2.0)));
+    assert_eq!(b % a, ScreenPos(Vec2::new(0.0, 0.0)));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= a;
+    c /= b;
+    c %= b;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn scalar_ops() {
+    let mut a = ScreenPos(Vec2::new(1.0, -2.0));
+
+    assert_eq!(a * 2.0, ScreenPos(Vec2::new(2.0, -4.0)));
+    assert_eq!(a + 1.0, ScreenPos(Vec2::new(2.0, -1.0)));
+    assert_eq!(-a, ScreenPos(Vec2::new(-1.0, 2.0)));
+
+    a /= 2.0;
+    assert_eq!(a, ScreenPos(Vec2::new(0.5, -1.0)));
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn generic() {
+    use crate::legacy;
+
+    let g = Generic(legacy::Vec2::new(2, 3));
+
+    assert_eq!(g * 2 + g, Generic(legacy::Vec2::new(6, 9)));
+    assert_eq!(legacy::Vec2::from(g), legacy::Vec2::new(2, 3));
+}
+
+#[test]
+fn named_field() {
+    let mut t = Tile {
+        coord: Vec2i32::new(1, 1),
+    };
+
+    t += Tile::from(Vec2i32::new(2, 3));
+
+    assert_eq!(Vec2i32::from(t), Vec2i32::new(3, 4));
+}
@@ -0,0 +1,75 @@
+use crate::macroed::*;
+
+// Unit Vector //
+
+#[test]
+fn unit_vec2() {
+    let v = UnitVec2::new(Vec2::new(3.0, -4.0)).unwrap();
+
+    assert_eq!(Vec2::from(v), Vec2::new(0.6, -0.8));
+    assert_eq!(UnitVec2::new(Vec2::new(f32::NAN, 1.0)), None);
+}
+
+#[test]
+fn unit_vec2_extremes() {
+    assert_eq!(
+        UnitVec2::new(Vec2::new(1e30, 0.0)).map(UnitVec2::get),
+        Some(Vec2::new(1.0, 0.0))
+    );
+    assert_eq!(
+        UnitVec2::new(Vec2::new(0.0, -1e-40)).map(UnitVec2::get),
+        Some(Vec2::new(0.0, -1.0))
+    );
+    assert_eq!(UnitVec2::new(Vec2::new(f32::INFINITY, 1.0)), None);
+    assert_eq!(UnitVec2::new(Vec2::new(0.0, 0.0)), None);
+}
+
+// Analysis //
+
+#[test]
+fn dominant_direction_cancels() {
+    let deltas = [Vec2::new(1.0, 0.0), Vec2::new(-4.0, 0.0)];
+
+    assert_eq!(dominant_direction(&deltas), None);
+    assert_eq!(dominant_direction(&[]), None);
+}
+
+#[test]
+fn dominant_direction_equal_weight() {
+    // The long movement doesn't outweigh the two short ones
+    let deltas = [
+        Vec2::new(0.0, 100.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 0.0),
+    ];
+    let d = dominant_direction(&deltas).unwrap().get();
+
+    assert!(d.x > d.y);
+}
+
+#[test]
+fn orientation_histogram_bins() {
+    let deltas = [
+        Vec2::new(1.0, -0.1),
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(0.0, -2.0),
+        Vec2::new(0.0, 0.0),
+    ];
+    let bins = orientation_histogram::<4>(&deltas);
+
+    assert_eq!(bins[0], Vec2::new(1.0, -0.1).mag());
+    assert_eq!(bins[1], 0.0);
+    assert_eq!(bins[2], 1.0);
+    assert_eq!(bins[3], 2.0);
+
+    assert_eq!(
+        orientation_histogram::<16>(&deltas).iter().sum::<f32>(),
+        bins.iter().sum::<f32>()
+    );
+}
+
+#[test]
+#[should_panic]
+fn orientation_histogram_no_bins() {
+    orientation_histogram::<0>(&[Vec2::new(1.0, 0.0)]);
+}
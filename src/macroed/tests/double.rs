@@ -0,0 +1,115 @@
+use crate::macroed::*;
+
+fn dd(s: &str) -> DoubleDouble {
+    s.parse().unwrap()
+}
+
+#[test]
+fn arithmetic() {
+    let third = DoubleDouble::from(1.0) / DoubleDouble::from(3.0);
+
+    // f64 would give 1.0 - 0.9999999999999999 here
+    assert_eq!(third * DoubleDouble::from(3.0), DoubleDouble::from(1.0));
+    assert_eq!(third + third - third, third);
+    assert!((third - dd("0.333333333333333333333333333333")).abs() < dd("1e-30"));
+}
+
+#[test]
+fn divide_by_zero() {
+    let zero = DoubleDouble::from(0.0);
+
+    assert_eq!(dd("2") / zero, DoubleDouble::from(f64::INFINITY));
+    assert_eq!(-dd("2") / zero, DoubleDouble::from(f64::NEG_INFINITY));
+    assert!((zero / zero).hi.is_nan());
+    assert_eq!(dd("2") / DoubleDouble::from(f64::INFINITY), zero);
+}
+
+#[test]
+fn precision_beyond_f64() {
+    let big = DoubleDouble::from(1e20);
+    let small = DoubleDouble::from(1.0);
+
+    assert_eq!((big + small - big), small);
+}
+
+#[test]
+fn sqrt() {
+    let two = DoubleDouble::from(2.0);
+    let root = two.sqrt();
+
+    assert!((root * root - two).abs() < dd("1e-30"));
+    assert_eq!(DoubleDouble::from(16.0).sqrt(), DoubleDouble::from(4.0));
+}
+
+#[test]
+fn rounding() {
+    let v = DoubleDouble::new(4.0, -1e-20);
+
+    assert_eq!(v.floor(), DoubleDouble::from(3.0));
+    assert_eq!(v.ceil(), DoubleDouble::from(4.0));
+    assert_eq!((-v).trunc(), DoubleDouble::from(-3.0));
+    assert_eq!(dd("7.5") % dd("2"), dd("1.5"));
+}
+
+#[test]
+fn parse() {
+    assert_eq!(dd("-12.5e-1"), DoubleDouble::from(-1.25));
+    assert_eq!(dd("+3"), DoubleDouble::from(3.0));
+    assert!("1.2.3".parse::<DoubleDouble>().is_err());
+    assert!("".parse::<DoubleDouble>().is_err());
+    assert!("1e".parse::<DoubleDouble>().is_err());
+}
+
+#[test]
+fn parse_out_of_range() {
+    let inf = DoubleDouble::from(f64::INFINITY);
+
+    assert_eq!(dd("1e309"), inf);
+    assert_eq!(dd("-1e400"), -inf);
+    assert_eq!(dd("1e2147483647"), inf);
+    assert_eq!(dd("1e-400"), DoubleDouble::from(0.0));
+    assert_eq!(dd("1e-2147483648"), DoubleDouble::from(0.0));
+    assert_eq!(dd("0e999"), DoubleDouble::from(0.0));
+    assert_eq!(f64::from(dd("2.5e-320")), 2.5e-320);
+}
+
+#[test]
+fn parse_limits() {
+    assert_eq!(f64::from(dd("1.7976931348623157e308")), f64::MAX);
+    assert_eq!(f64::from(dd("2.2250738585072014e-308")), f64::MIN_POSITIVE);
+    assert_eq!(dd("1e200") * dd("1e-200"), DoubleDouble::from(1.0));
+
+    // Digits past the precision only move the exponent
+    let long = format!("1{}e-400", "0".repeat(400));
+    assert_eq!(dd(&long), DoubleDouble::from(1.0));
+    assert_eq!(
+        dd("0.000000000000000000000000000000000000000000125e42"),
+        dd("0.125")
+    );
+}
+
+#[test]
+fn vec2dd() {
+    let v = Vec2dd::new(dd("3"), dd("4"));
+
+    assert_eq!(v.mag(), dd("5"));
+    assert_eq!(v.mag_precise(), dd("5"));
+    assert_eq!(
+        Vec2dd::new(dd("-7"), dd("7")).rem_euclid(Vec2dd::new(dd("2"), dd("-2"))),
+        Vec2dd::new(dd("1"), dd("1"))
+    );
+    assert_eq!(
+        Vec2dd::new(dd("-7"), dd("7")).div_euclid(Vec2dd::new(dd("2"), dd("-2"))),
+        Vec2dd::new(dd("-4"), dd("-3"))
+    );
+    assert_eq!(v.norm(), Vec2dd::new(dd("0.6"), dd("0.8")));
+    assert_eq!(
+        Vec2dd::new(dd("9"), dd("16")).sqrt(),
+        Vec2dd::new(dd("3"), dd("4"))
+    );
+    assert_eq!(
+        "(1.5, -2)".parse::<Vec2dd>(),
+        Ok(Vec2dd::new(dd("1.5"), dd("-2")))
+    );
+    assert_eq!(v.to_string(), "Vec2dd(3, 4)");
+}
@@ -0,0 +1,52 @@
+use fixed::types::I16F16;
+
+use crate::macroed::*;
+
+fn fx(n: f64) -> I16F16 {
+    I16F16::from_num(n)
+}
+
+fn v(x: f64, y: f64) -> Vec2Fixed {
+    Vec2Fixed::new(fx(x), fx(y))
+}
+
+#[test]
+fn operators() {
+    let a = v(1.5, -2.0);
+    let b = v(0.25, 4.0);
+
+    assert_eq!(a + b, v(1.75, 2.0));
+    assert_eq!(a - b, v(1.25, -6.0));
+    assert_eq!(a * b, v(0.375, -8.0));
+    assert_eq!(a * fx(2.0), v(3.0, -4.0));
+    assert_eq!(-a, v(-1.5, 2.0));
+}
+
+#[test]
+fn magnitude() {
+    let a = v(3.0, -4.0);
+
+    assert_eq!(a.mag2(), 25);
+    assert_eq!(a.mag(), 5);
+    assert_eq!(v(9.0, 0.25).sqrt(), v(3.0, 0.5));
+    assert_eq!(a.mag_precise(), 5);
+    assert_eq!(a.manhattan_length(), 7);
+    assert_eq!(v(0.0, 2.0).norm(), v(0.0, 1.0));
+}
+
+#[test]
+fn min_max() {
+    let a = v(1.0, 5.0);
+    let b = v(2.5, -1.0);
+
+    assert_eq!(a.max(b), v(2.5, 5.0));
+    assert_eq!(a.min(b), v(1.0, -1.0));
+    assert_eq!(v(9.0, -9.0).clamp(v(0.0, 0.0), v(2.0, 2.0)), v(2.0, 0.0));
+    assert_eq!(v(1.75, -1.25).floor(), v(1.0, -2.0));
+}
+
+#[test]
+fn parse_and_display() {
+    assert_eq!("1.5, -2".parse::<Vec2Fixed>(), Ok(v(1.5, -2.0)));
+    assert_eq!(format!("{}", v(1.5, -2.0)), "Vec2Fixed(1.5, -2)");
+}
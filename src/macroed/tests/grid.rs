@@ -0,0 +1,45 @@
+use crate::macroed::*;
+
+// Grid //
+
+#[test]
+fn grid_get() {
+    let grid = Grid2::from_vec(Vec2u::new(3, 2), vec![0, 1, 2, 3, 4, 5]).unwrap();
+
+    assert_eq!(grid.size(), Vec2u::new(3, 2));
+    assert_eq!(grid.get(Vec2i::new(2, 1)), Some(&5));
+    assert_eq!(grid.get(Vec2i::new(3, 0)), None);
+    assert_eq!(grid.get(Vec2i::new(0, -1)), None);
+    assert!(grid.contains(Vec2i::new(0, 0)));
+}
+
+#[test]
+fn grid_from_vec_wrong_size() {
+    assert_eq!(Grid2::from_vec(Vec2u::new(2, 2), vec![1, 2, 3]), None);
+}
+
+#[test]
+fn grid_get_mut() {
+    let mut grid = Grid2::new(Vec2u::new(2, 2), 0);
+
+    *grid.get_mut(Vec2i::new(1, 0)).unwrap() = 7;
+
+    assert_eq!(grid.as_slice(), [0, 7, 0, 0]);
+}
+
+// Region //
+
+#[test]
+fn region() {
+    let mut region = Region2::new();
+
+    assert!(region.is_empty());
+    assert!(region.insert(Vec2i::new(1, 2)));
+    assert!(!region.insert(Vec2i::new(1, 2)));
+
+    region.extend([Vec2i::new(0, 0)]);
+
+    assert_eq!(region.len(), 2);
+    assert!(region.remove(Vec2i::new(0, 0)));
+    assert_eq!(region.iter().collect::<Vec<_>>(), [Vec2i::new(1, 2)]);
+}
@@ -0,0 +1,37 @@
+use half::f16;
+
+use crate::macroed::*;
+
+#[test]
+fn conversion() {
+    let v = Vec2f16::from(Vec2::new(1.5, -0.25));
+
+    assert_eq!(v, Vec2f16::new(f16::from_f32(1.5), f16::from_f32(-0.25)));
+    assert_eq!(v.to_vec2f32(), Vec2::new(1.5, -0.25));
+
+    // Rounds to the nearest f16, and overflows to infinity
+    let rounded = Vec2f16::from_vec2f32(Vec2::new(0.1, 1e6)).to_vec2f32();
+
+    assert!((rounded.x - 0.1).abs() < 1e-4 && rounded.x != 0.1);
+    assert_eq!(rounded.y, f32::INFINITY);
+}
+
+#[test]
+fn operators() {
+    let a = Vec2f16::from(Vec2::new(1.0, 2.0));
+    let b = Vec2f16::from(Vec2::new(0.5, 4.0));
+
+    assert_eq!(Vec2::from(a + b), Vec2::new(1.5, 6.0));
+    assert_eq!(Vec2::from(a * b), Vec2::new(0.5, 8.0));
+    assert_eq!(Vec2::from(-a), Vec2::new(-1.0, -2.0));
+    assert_eq!(Vec2::from(a.max(b)), Vec2::new(1.0, 4.0));
+    assert_eq!(a.mag2(), f16::from_f32(5.0));
+}
+
+#[test]
+fn parse_and_display() {
+    let v = Vec2f16::from(Vec2::new(1.5, -2.0));
+
+    assert_eq!("1.5, -2".parse::<Vec2f16>(), Ok(v));
+    assert_eq!(format!("{}", v), "Vec2f16(1.5, -2)");
+}
@@ -0,0 +1,40 @@
+use crate::macroed::*;
+
+#[test]
+fn rect() {
+    let min = Vec2::new(-1.0, -1.0);
+    let max = Vec2::new(1.0, 2.0);
+
+    assert!(point_in_rect(Vec2::new(0.0, 0.0), min, max));
+    assert!(point_in_rect(max, min, max));
+    assert!(!point_in_rect(Vec2::new(0.0, 2.5), min, max));
+    assert!(!point_in_rect(Vec2::new(0.0, 0.0), max, min));
+}
+
+#[test]
+fn circle() {
+    let center = Vec2::new(0.0, 0.0);
+
+    assert!(point_in_circle(Vec2::new(0.6, 0.8), center, 1.0));
+    assert!(!point_in_circle(Vec2::new(0.8, 0.8), center, 1.0));
+    assert!(point_in_circle(center, center, 0.0));
+}
+
+#[test]
+fn triangle() {
+    let (a, b, c) = (
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(1.0, 2.0),
+    );
+
+    assert!(point_in_triangle(Vec2::new(1.0, 1.0), a, b, c));
+    assert!(point_in_triangle(Vec2::new(1.0, 0.0), c, b, a));
+    assert!(point_in_triangle(c, a, b, c));
+    assert!(!point_in_triangle(Vec2::new(0.0, 1.0), a, b, c));
+
+    // A flat triangle only contains the points on its line segment
+    let d = Vec2::new(4.0, 0.0);
+    assert!(point_in_triangle(Vec2::new(3.0, 0.0), a, b, d));
+    assert!(!point_in_triangle(Vec2::new(5.0, 0.0), a, b, d));
+}
@@ -0,0 +1,58 @@
+use crate::macroed::*;
+
+fn square() -> [Vec2; 4] {
+    [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ]
+}
+
+#[test]
+fn segments() {
+    let p = square();
+    let segments: Vec<_> = p.iter().copied().segments().collect();
+
+    assert_eq!(segments, [(p[0], p[1]), (p[1], p[2]), (p[2], p[3])]);
+    assert_eq!(p[..1].iter().copied().segments().count(), 0);
+}
+
+#[test]
+fn closed_segments() {
+    let p = square();
+    let segments: Vec<_> = p.iter().copied().closed_segments().collect();
+
+    assert_eq!(
+        segments,
+        [(p[0], p[1]), (p[1], p[2]), (p[2], p[3]), (p[3], p[0])]
+    );
+    assert_eq!(p[..2].iter().copied().closed_segments().count(), 2);
+    assert_eq!(p[..1].iter().copied().closed_segments().count(), 0);
+    assert_eq!(p[..0].iter().copied().closed_segments().count(), 0);
+}
+
+#[test]
+fn deltas() {
+    let deltas: Vec<_> = square().into_iter().deltas().collect();
+
+    assert_eq!(
+        deltas,
+        [
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-1.0, 0.0)
+        ]
+    );
+}
+
+#[test]
+fn cumulative_sum() {
+    let steps = [Vec2i::new(1, 0), Vec2i::new(1, 2), Vec2i::new(-3, 0)];
+    let sums: Vec<_> = steps.into_iter().cumulative_sum().collect();
+
+    assert_eq!(
+        sums,
+        [Vec2i::new(1, 0), Vec2i::new(2, 2), Vec2i::new(-1, 2)]
+    );
+}
@@ -0,0 +1,43 @@
+use crate::macroed::*;
+
+fn open_room(size: usize) -> Grid2<bool> {
+    Grid2::new(Vec2u::new(size, size), false)
+}
+
+#[test]
+fn open_room_sees_radius() {
+    let visible = los::visible_from(Vec2i::new(5, 5), &open_room(11), 3);
+
+    assert!(visible.contains(Vec2i::new(5, 5)));
+    assert!(visible.contains(Vec2i::new(8, 5)));
+    assert!(visible.contains(Vec2i::new(7, 7)));
+    assert!(!visible.contains(Vec2i::new(9, 5)));
+    assert!(!visible.contains(Vec2i::new(8, 8)));
+
+    // Every visible cell is within the radius
+    assert!(visible.iter().all(|c| (c - Vec2i::new(5, 5)).mag2() <= 9));
+}
+
+#[test]
+fn wall_casts_shadow() {
+    let mut walls = open_room(9);
+
+    for y in 2..=6 {
+        *walls.get_mut(Vec2i::new(6, y)).unwrap() = true;
+    }
+
+    let visible = los::visible_from(Vec2i::new(4, 4), &walls, 8);
+
+    assert!(visible.contains(Vec2i::new(6, 4)));
+    assert!(!visible.contains(Vec2i::new(7, 4)));
+    assert!(!visible.contains(Vec2i::new(8, 3)));
+    assert!(visible.contains(Vec2i::new(0, 4)));
+}
+
+#[test]
+fn stays_inside_grid() {
+    let visible = los::visible_from(Vec2i::new(0, 0), &open_room(3), 10);
+
+    assert_eq!(visible.len(), 9);
+    assert!(los::visible_from(Vec2i::new(-1, 0), &open_room(3), 10).is_empty());
+}
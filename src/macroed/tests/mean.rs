@@ -0,0 +1,95 @@
+use crate::macroed::*;
+
+#[test]
+fn centroid_ints() {
+    let points = [
+        Vec2u8::new(255, 0),
+        Vec2u8::new(255, 1),
+        Vec2u8::new(254, 3),
+    ];
+
+    assert_eq!(centroid(&points), Some(Vec2u8::new(254, 1)));
+    assert_eq!(
+        centroid(&[Vec2i64::new(i64::MAX, i64::MIN); 4]),
+        Some(Vec2i64::new(i64::MAX, i64::MIN))
+    );
+    assert_eq!(
+        centroid(&[Vec2i::new(-3, 0), Vec2i::new(0, 0)]),
+        Some(Vec2i::new(-1, 0))
+    );
+}
+
+#[test]
+fn centroid_floats() {
+    let points = [Vec2::new(1.0, 2.0), Vec2::new(3.0, -2.0)];
+
+    assert_eq!(centroid(&points), Some(Vec2::new(2.0, 0.0)));
+    assert_eq!(centroid::<Vec2>(&[]), None);
+}
+
+#[test]
+fn weighted() {
+    let points = [Vec2u16::new(0, 10), Vec2u16::new(10, 20)];
+
+    assert_eq!(
+        weighted_centroid(&points, &[1, 4]),
+        Some(Vec2u16::new(8, 18))
+    );
+    assert_eq!(weighted_centroid(&points, &[0, 0]), None);
+    assert_eq!(
+        weighted_centroid(&[Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0)], &[-1.0, 1.0]),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn weighted_mismatched() {
+    weighted_centroid(&[Vec2::new(1.0, 1.0)], &[]);
+}
+
+#[test]
+fn centroid_128_bit() {
+    assert_eq!(
+        centroid(&[Vec2i128::new(i128::MAX, i128::MIN); 2]),
+        Some(Vec2i128::new(i128::MAX, i128::MIN))
+    );
+    assert_eq!(
+        centroid(&[Vec2u128::new(u128::MAX, 0); 3]),
+        Some(Vec2u128::new(u128::MAX, 0))
+    );
+    assert_eq!(
+        centroid(&[
+            Vec2i128::new(i128::MIN, i128::MAX),
+            Vec2i128::new(i128::MIN, i128::MAX - 1),
+            Vec2i128::new(i128::MAX, 0),
+        ]),
+        Some(Vec2i128::new(i128::MIN / 3 - 1, (i128::MAX / 3) * 2))
+    );
+    assert_eq!(
+        centroid(&[Vec2u128::new(u128::MAX, 1), Vec2u128::new(u128::MAX - 1, 0)]),
+        Some(Vec2u128::new(u128::MAX - 1, 0))
+    );
+}
+
+#[test]
+fn weighted_overflow() {
+    assert_eq!(
+        weighted_centroid(&[Vec2u64::new(u64::MAX, 0); 2], &[u64::MAX; 2]),
+        None
+    );
+    assert_eq!(
+        weighted_centroid(&[Vec2u32::new(u32::MAX, 0); 2], &[u32::MAX; 2]),
+        Some(Vec2u32::new(u32::MAX, 0))
+    );
+
+    // The average is -300, which doesn't fit in an i8
+    assert_eq!(
+        weighted_centroid(&[Vec2i8::new(100, 0), Vec2i8::new(-100, 0)], &[1, -2]),
+        None
+    );
+    assert_eq!(
+        weighted_centroid(&[Vec2i8::new(30, 0), Vec2i8::new(10, 0)], &[-1, 2]),
+        Some(Vec2i8::new(-10, 0))
+    );
+}
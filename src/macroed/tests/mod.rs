@@ -1 +1,53 @@
+mod angle;
+mod area;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod axis;
+mod batch;
+mod bits;
+mod capsule;
+mod cardinal;
+mod cast;
+mod circle;
+#[cfg(feature = "color")]
+mod color;
+mod consts;
+mod convert;
+#[cfg(feature = "derive")]
+mod derive;
+mod direction;
+#[cfg(feature = "extended")]
+mod double;
+#[cfg(feature = "fixed")]
+mod fixed;
+mod grid;
+#[cfg(feature = "half")]
+mod half_float;
+mod hit;
+mod iter;
+mod los;
+mod mean;
+mod morton;
+mod motion;
+mod nonzero;
+mod polygon;
+#[cfg(feature = "rand")]
+mod random;
+mod ray;
+mod rect;
+mod rotation;
+#[cfg(feature = "rand")]
+mod sampling;
+mod segment;
+mod shape;
+#[cfg(feature = "simd")]
+mod simd;
+mod size;
+mod space;
+mod spatial;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod transcendental;
+mod triangle;
 mod vec2;
+mod world;
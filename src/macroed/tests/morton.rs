@@ -0,0 +1,45 @@
+use crate::macroed::*;
+
+#[test]
+fn morton_u16() {
+    assert_eq!(Vec2u16::new(0, 0).to_morton(), 0);
+    assert_eq!(Vec2u16::new(1, 0).to_morton(), 1);
+    assert_eq!(Vec2u16::new(0, 1).to_morton(), 2);
+    assert_eq!(Vec2u16::new(u16::MAX, 0).to_morton(), 0x5555_5555);
+    assert_eq!(Vec2u16::new(u16::MAX, u16::MAX).to_morton(), u32::MAX);
+
+    for v in [Vec2u16::new(1234, 65000), Vec2u16::new(u16::MAX, 7)] {
+        assert_eq!(Vec2u16::from_morton(v.to_morton()), v);
+    }
+}
+
+#[test]
+fn morton_u32() {
+    assert_eq!(Vec2u32::new(0, u32::MAX).to_morton(), 0xAAAA_AAAA_AAAA_AAAA);
+
+    for v in [Vec2u32::new(123_456_789, 4_000_000_000), Vec2u32::new(5, 0)] {
+        assert_eq!(Vec2u32::from_morton(v.to_morton()), v);
+    }
+}
+
+#[test]
+fn morton_order() {
+    let mut points = [
+        Vec2u16::new(1, 1),
+        Vec2u16::new(0, 1),
+        Vec2u16::new(1, 0),
+        Vec2u16::new(0, 0),
+    ];
+
+    points.sort_by_key(Vec2u16::to_morton);
+
+    assert_eq!(
+        points,
+        [
+            Vec2u16::new(0, 0),
+            Vec2u16::new(1, 0),
+            Vec2u16::new(0, 1),
+            Vec2u16::new(1, 1)
+        ]
+    );
+}
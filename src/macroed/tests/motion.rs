@@ -0,0 +1,121 @@
+use crate::macroed::*;
+
+// Point and Delta //
+
+#[test]
+fn point_delta_ops() {
+    let mut p = Point2::new(1.0, 2.0);
+    let d = Delta2::new(3.0, -1.0);
+
+    assert_eq!(p + d, Point2::new(4.0, 1.0));
+    assert_eq!(p - d, Point2::new(-2.0, 3.0));
+    assert_eq!((p + d) - p, d);
+
+    p += d;
+    p -= d * 2.0;
+
+    assert_eq!(p, Point2::new(-2.0, 3.0));
+}
+
+#[test]
+fn delta_ops() {
+    let mut d = Delta2::new(4.0, 6.0);
+
+    assert_eq!(d + d, Delta2::new(8.0, 12.0));
+    assert_eq!(d - d, Delta2::new(0.0, 0.0));
+    assert_eq!(d / 2.0, Delta2::new(2.0, 3.0));
+    assert_eq!(-d, Delta2::new(-4.0, -6.0));
+
+    d += Delta2::new(1.0, 1.0);
+    d -= Delta2::new(0.0, 2.0);
+
+    assert_eq!(d, Delta2::new(5.0, 5.0));
+}
+
+#[test]
+fn conv_vec2() {
+    let v = Vec2::new(1.0, 2.0);
+
+    assert_eq!(Vec2::from(Point2::from(v)), v);
+    assert_eq!(Vec2::from(Delta2::from(v)), v);
+}
+
+// Motion //
+
+#[test]
+fn motion_apply() {
+    let p = Point2::new(2.0, 2.0);
+
+    assert_eq!(
+        Motion2::Absolute(Point2::new(0.0, 1.0)).apply(p),
+        Point2::new(0.0, 1.0)
+    );
+    assert_eq!(
+        Motion2::Relative(Delta2::new(0.0, 1.0)).apply(p),
+        Point2::new(2.0, 3.0)
+    );
+}
+
+// Subpixel Movement //
+
+#[test]
+fn subpixel_step() {
+    let mut acc = SubpixelAccumulator::new();
+    let mut moved = Vec2i32::new(0, 0);
+
+    for _ in 0..8 {
+        moved += acc.step(Vec2::new(1.0, -2.0), 0.25);
+    }
+
+    assert_eq!(moved, Vec2i32::new(2, -4));
+    assert_eq!(acc.remainder, Vec2::new(0.0, 0.0));
+}
+
+#[test]
+fn subpixel_remainder() {
+    let mut acc = SubpixelAccumulator::default();
+
+    assert_eq!(acc.step(Vec2::new(2.5, -0.75), 1.0), Vec2i32::new(2, 0));
+    assert_eq!(acc.remainder, Vec2::new(0.5, -0.75));
+}
+
+// Fixed Timestep //
+
+#[test]
+fn fixed_step_advance() {
+    let mut fixed = FixedStep::new(0.25, Vec2::new(0.0, 0.0));
+
+    assert_eq!(fixed.advance(0.125), 0);
+    assert_eq!(fixed.advance(0.5), 2);
+    assert_eq!(fixed.alpha(), 0.5);
+}
+
+#[test]
+fn fixed_step_catch_up() {
+    let mut fixed = FixedStep::new(1.0 / 60.0, Vec2::new(0.0, 0.0));
+
+    assert_eq!(fixed.advance(1e9), FixedStep::MAX_STEPS);
+    assert!((0.0..=fixed.step).contains(&fixed.accumulator));
+    assert_eq!(fixed.advance(f32::INFINITY), FixedStep::MAX_STEPS);
+    assert_eq!(fixed.accumulator, 0.0);
+}
+
+#[test]
+#[should_panic]
+fn fixed_step_zero() {
+    FixedStep::new(0.0, Vec2::new(0.0, 0.0));
+}
+
+#[test]
+fn fixed_step_render_pos() {
+    let mut fixed = FixedStep::new(1.0, Vec2::new(0.0, 0.0));
+
+    assert_eq!(fixed.render_pos(0.5), Vec2::new(0.0, 0.0));
+
+    fixed.push(Vec2::new(4.0, -8.0));
+
+    assert_eq!(fixed.previous, Vec2::new(0.0, 0.0));
+    assert_eq!(fixed.render_pos(0.0), Vec2::new(0.0, 0.0));
+    assert_eq!(fixed.render_pos(0.25), Vec2::new(1.0, -2.0));
+    assert_eq!(fixed.render_pos(1.0), Vec2::new(4.0, -8.0));
+}
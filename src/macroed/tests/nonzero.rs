@@ -0,0 +1,47 @@
+use std::mem::size_of;
+use std::num::{NonZeroI8, NonZeroU32};
+
+use crate::macroed::*;
+
+#[test]
+fn construction() {
+    let two = NonZeroU32::new(2).unwrap();
+    let v = Vec2NonZeroU32::new(two, two);
+
+    assert_eq!(v.get(), Vec2u32::new(2, 2));
+    assert_eq!(Vec2NonZeroU32::new_checked(2, 2), Some(v));
+    assert_eq!(Vec2NonZeroU32::new_checked(0, 2), None);
+    assert_eq!(Vec2NonZeroU32::new_checked(2, 0), None);
+}
+
+#[test]
+fn conversion() {
+    assert_eq!(
+        Vec2NonZeroI8::try_from(Vec2i8::new(-1, 5)),
+        Ok(Vec2NonZeroI8::new(
+            NonZeroI8::new(-1).unwrap(),
+            NonZeroI8::new(5).unwrap()
+        ))
+    );
+    assert_eq!(
+        Vec2NonZeroI8::try_from(Vec2i8::new(0, 5)),
+        Err(ZeroComponentError)
+    );
+    assert_eq!(
+        Vec2usize::from(Vec2NonZeroUsize::new_checked(3, 4).unwrap()),
+        Vec2usize::new(3, 4)
+    );
+    assert_eq!(
+        ZeroComponentError.to_string(),
+        "Expected both components to be non-zero"
+    );
+}
+
+#[test]
+fn niche() {
+    assert_eq!(
+        size_of::<Option<Vec2NonZeroU32>>(),
+        size_of::<Vec2NonZeroU32>()
+    );
+    assert_eq!(size_of::<Option<Vec2NonZeroI64>>(), 16);
+}
@@ -0,0 +1,100 @@
+use crate::macroed::*;
+
+fn poly(points: &[(f32, f32)]) -> Polygon {
+    points.iter().map(|&p| Vec2::from(p)).collect()
+}
+
+fn square() -> Polygon {
+    poly(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)])
+}
+
+#[test]
+fn measure() {
+    let mut p = square();
+
+    assert_eq!(p.signed_area(), 4.0);
+    assert_eq!(p.area(), 4.0);
+    assert_eq!(p.perimeter(), 8.0);
+    assert_eq!(p.centroid(), Vec2::new(1.0, 1.0));
+    assert_eq!(p.winding(), Some(Winding::Counterclockwise));
+
+    p.reverse();
+
+    assert_eq!(p.signed_area(), -4.0);
+    assert_eq!(p.area(), 4.0);
+    assert_eq!(p.centroid(), Vec2::new(1.0, 1.0));
+    assert_eq!(p.winding(), Some(Winding::Clockwise));
+}
+
+#[test]
+fn centroid_concave() {
+    let l_shape = poly(&[
+        (0.0, 0.0),
+        (3.0, 0.0),
+        (3.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 3.0),
+        (0.0, 3.0),
+    ]);
+
+    // Two 1x3 bars overlapping in a 1x1 square
+    assert_eq!(l_shape.area(), 5.0);
+    assert_eq!(l_shape.centroid(), Vec2::new(1.1, 1.1));
+}
+
+#[test]
+fn degenerate() {
+    let line = poly(&[(0.0, 0.0), (4.0, 0.0)]);
+
+    assert_eq!(line.area(), 0.0);
+    assert_eq!(line.winding(), None);
+    assert_eq!(line.centroid(), Vec2::new(2.0, 0.0));
+    assert!(Polygon::default().centroid().x.is_nan());
+}
+
+#[test]
+fn contains_point() {
+    let p = square();
+
+    assert!(p.contains_point(Vec2::new(1.0, 1.0)));
+    assert!(p.contains_point(Vec2::new(0.0, 1.0)));
+    assert!(p.contains_point(Vec2::new(2.0, 2.0)));
+    assert!(!p.contains_point(Vec2::new(3.0, 1.0)));
+    assert!(!p.contains_point(Vec2::new(1.0, -0.5)));
+
+    // Passing exactly through a vertex shouldn't count twice
+    let diamond = poly(&[(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 1.0)]);
+
+    assert!(!diamond.contains_point(Vec2::new(-1.0, 1.0)));
+    assert!(!diamond.contains_point(Vec2::new(3.0, 1.0)));
+    assert!(diamond.contains_point(Vec2::new(1.5, 1.0)));
+}
+
+#[test]
+fn distance() {
+    let p = square();
+
+    assert_eq!(
+        distance_between(&Vec2::new(1.0, 1.5), &p),
+        (-0.5, Vec2::new(1.0, 1.5), Vec2::new(1.0, 2.0))
+    );
+    assert_eq!(
+        distance_between(&p, &Circle::new(Vec2::new(5.0, 1.0), 1.0)),
+        (2.0, Vec2::new(2.0, 1.0), Vec2::new(4.0, 1.0))
+    );
+    assert_eq!(
+        distance_between(&p, &Segment::new(Vec2::new(3.0, 3.0), Vec2::new(3.0, 5.0))).0,
+        2f32.sqrt()
+    );
+
+    let rect = Rect::new(Vec2::new(-3.0, 0.5), Vec2::new(-1.0, 1.0));
+
+    assert_eq!(
+        distance_between(&rect, &p),
+        (1.0, Vec2::new(-1.0, 0.5), Vec2::new(0.0, 0.5))
+    );
+
+    let overlapping = poly(&[(1.0, 1.0), (5.0, 1.0), (5.0, 5.0)]);
+
+    assert_eq!(distance_between(&p, &overlapping).0, 0.0);
+}
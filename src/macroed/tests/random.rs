@@ -0,0 +1,77 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::macroed::*;
+
+#[test]
+fn random_range() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    for _ in 0..100 {
+        let v = Vec2i::random_range((-5, 10), (5, 20), &mut rng);
+
+        assert!(-5 <= v.x && v.x < 5);
+        assert!(10 <= v.y && v.y < 20);
+    }
+}
+
+#[test]
+fn random_in_circle() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let center = Vec2::new(3.0, -2.0);
+
+    for _ in 0..100 {
+        let v = Vec2::random_in_circle(center, 4.0, &mut rng);
+
+        assert!((v - center).mag() <= 4.0);
+    }
+}
+
+#[test]
+fn random_on_circle() {
+    let mut rng = StdRng::seed_from_u64(5);
+
+    for _ in 0..100 {
+        let v = Vec2d::random_on_circle(2.5, &mut rng);
+
+        assert!((v.mag() - 2.5).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn random_in_annulus() {
+    let mut rng = StdRng::seed_from_u64(6);
+    let samples: Vec<Vec2> = (0..10_000)
+        .map(|_| Vec2::random_in_annulus(1.0, 3.0, &mut rng))
+        .collect();
+
+    assert!(samples.iter().all(|v| (1.0..=3.0).contains(&v.mag())));
+
+    // The outer half of the ring by radius covers 5/8 of its area
+    let outer = samples.iter().filter(|v| v.mag() >= 2.0).count() as f32;
+    assert!((outer / samples.len() as f32 - 0.625).abs() < 0.02);
+}
+
+#[test]
+fn random_normal() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let samples: Vec<Vec2d> = (0..10_000)
+        .map(|_| Vec2d::random_normal((5.0, -1.0), (2.0, 0.5), &mut rng))
+        .collect();
+
+    let mean = samples.iter().fold(Vec2d::new(0.0, 0.0), |a, &b| a + b) / samples.len() as f64;
+    let variance = samples
+        .iter()
+        .fold(Vec2d::new(0.0, 0.0), |a, &b| a + (b - mean) * (b - mean))
+        / samples.len() as f64;
+
+    assert!(mean.approx_eq((5.0, -1.0), 0.1));
+    assert!(variance.sqrt().approx_eq((2.0, 0.5), 0.1));
+}
+
+#[test]
+#[should_panic]
+fn random_range_empty() {
+    let mut rng = StdRng::seed_from_u64(3);
+    Vec2u8::random_range([4; 2], [4; 2], &mut rng);
+}
@@ -0,0 +1,83 @@
+use crate::macroed::*;
+
+#[test]
+fn point_at() {
+    let ray = Ray2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, -1.0));
+
+    assert_eq!(ray.point_at(0.0), ray.origin);
+    assert_eq!(ray.point_at(1.5), Vec2::new(4.0, -0.5));
+}
+
+#[test]
+fn intersect_rect() {
+    let rect = Rect::new(Vec2::new(2.0, -1.0), Vec2::new(4.0, 1.0));
+
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)).intersect_rect(&rect),
+        Some(2.0)
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(-1.0, 0.0)).intersect_rect(&rect),
+        None
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, 2.0), Vec2::new(1.0, 0.0)).intersect_rect(&rect),
+        None
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(3.0, 0.0), Vec2::new(0.0, 1.0)).intersect_rect(&rect),
+        Some(0.0)
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, -3.0), Vec2::new(1.0, 1.0)).intersect_rect(&rect),
+        Some(2.0)
+    );
+}
+
+#[test]
+fn intersect_circle() {
+    let circle = Circle::new(Vec2::new(0.0, 5.0), 2.0);
+
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 2.0)).intersect_circle(&circle),
+        Some(1.5)
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, -1.0)).intersect_circle(&circle),
+        None
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(3.0, 0.0), Vec2::new(0.0, 1.0)).intersect_circle(&circle),
+        None
+    );
+    assert_eq!(
+        Ray2::new(Vec2::new(0.0, 4.0), Vec2::new(1.0, 0.0)).intersect_circle(&circle),
+        Some(0.0)
+    );
+}
+
+#[test]
+fn intersect_segment() {
+    let ray = Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0));
+
+    assert_eq!(
+        ray.intersect_segment(&Segment::new(Vec2::new(3.0, -1.0), Vec2::new(3.0, 1.0))),
+        Some(3.0)
+    );
+    assert_eq!(
+        ray.intersect_segment(&Segment::new(Vec2::new(-3.0, -1.0), Vec2::new(-3.0, 1.0))),
+        None
+    );
+    assert_eq!(
+        ray.intersect_segment(&Segment::new(Vec2::new(3.0, 1.0), Vec2::new(3.0, 2.0))),
+        None
+    );
+    assert_eq!(
+        ray.intersect_segment(&Segment::new(Vec2::new(5.0, 0.0), Vec2::new(2.0, 0.0))),
+        Some(2.0)
+    );
+    assert_eq!(
+        ray.intersect_segment(&Segment::new(Vec2::new(-1.0, 0.0), Vec2::new(2.0, 0.0))),
+        Some(0.0)
+    );
+}
@@ -0,0 +1,125 @@
+use crate::macroed::*;
+
+fn rect(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Rect {
+    Rect::new(Vec2::new(min_x, min_y), Vec2::new(max_x, max_y))
+}
+
+#[test]
+fn measure() {
+    let r = rect(1.0, 2.0, 4.0, 6.0);
+
+    assert_eq!(r.size(), Vec2::new(3.0, 4.0));
+    assert_eq!(r.center(), Vec2::new(2.5, 4.0));
+    assert_eq!(r.area(), 12.0);
+    assert_eq!(r.perimeter(), 14.0);
+    assert_eq!(r.centroid(), r.center());
+}
+
+#[test]
+fn from_corners() {
+    assert_eq!(
+        Rect::from_corners(Vec2::new(4.0, 0.0), Vec2::new(1.0, 3.0)),
+        rect(1.0, 0.0, 4.0, 3.0)
+    );
+    assert_eq!(
+        Rect::from_points(Vec2::new(4.0, 0.0), Vec2::new(1.0, 3.0)),
+        rect(1.0, 0.0, 4.0, 3.0)
+    );
+}
+
+#[test]
+fn from_center_and_pos() {
+    let r = rect(1.0, -2.0, 5.0, 4.0);
+
+    assert_eq!(
+        Rect::from_center_half_extents(r.center(), r.size() / 2.0),
+        r
+    );
+    assert_eq!(Rect::from_pos_size(r.min, r.size()), r);
+}
+
+#[test]
+fn contains() {
+    let r = rect(0.0, 0.0, 2.0, 2.0);
+
+    assert!(r.contains(Vec2::new(1.0, 1.0)));
+    assert!(r.contains(Vec2::new(2.0, 0.0)));
+    assert!(!r.contains(Vec2::new(2.5, 1.0)));
+}
+
+#[test]
+fn set_ops() {
+    let a = rect(0.0, 0.0, 2.0, 2.0);
+    let b = rect(2.0, 1.0, 3.0, 5.0);
+    let c = rect(5.0, 5.0, 6.0, 6.0);
+
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&c));
+    assert_eq!(a.intersection(&b), Some(rect(2.0, 1.0, 2.0, 2.0)));
+    assert_eq!(a.intersection(&c), None);
+    assert_eq!(a.union(&c), rect(0.0, 0.0, 6.0, 6.0));
+}
+
+#[test]
+fn overlap_queries() {
+    let a = rect(0.0, 0.0, 4.0, 4.0);
+
+    assert!(a.overlaps(&rect(3.0, 3.0, 5.0, 5.0)));
+    assert!(!a.overlaps(&rect(4.0, 0.0, 5.0, 4.0)));
+    assert!(a.contains_rect(&rect(0.0, 1.0, 4.0, 2.0)));
+    assert!(!a.contains_rect(&rect(-1.0, 1.0, 2.0, 2.0)));
+    assert_eq!(a.expand(1.0), rect(-1.0, -1.0, 5.0, 5.0));
+    assert_eq!(a.expand(-1.0), rect(1.0, 1.0, 3.0, 3.0));
+}
+
+#[test]
+fn bounding() {
+    let p = Vec2::new(3.0, -1.0);
+
+    assert_eq!(bounding_box(&[p]), Some(Rect::new(p, p)));
+    assert_eq!(
+        bounding_box(&[p, Vec2::new(0.0, 2.0), Vec2::new(1.0, 9.0)]),
+        Some(rect(0.0, -1.0, 3.0, 9.0))
+    );
+    assert_eq!(bounding_box(&[]), None);
+}
+
+#[test]
+fn distance_point_rect() {
+    let r = rect(0.0, 0.0, 4.0, 2.0);
+    let outside = Vec2::new(7.0, 6.0);
+    let inside = Vec2::new(1.0, 1.5);
+
+    assert_eq!(
+        distance_between(&outside, &r),
+        (5.0, outside, Vec2::new(4.0, 2.0))
+    );
+    assert_eq!(
+        distance_between(&r, &inside),
+        (-0.5, Vec2::new(1.0, 2.0), inside)
+    );
+}
+
+#[test]
+fn distance_rect_rect() {
+    let a = rect(0.0, 0.0, 2.0, 2.0);
+
+    assert_eq!(
+        distance_between(&a, &rect(5.0, 1.0, 6.0, 4.0)),
+        (3.0, Vec2::new(2.0, 1.5), Vec2::new(5.0, 1.5))
+    );
+    assert_eq!(distance_between(&a, &rect(5.0, 6.0, 6.0, 7.0)).0, 5.0);
+    assert_eq!(
+        distance_between(&a, &rect(1.5, -1.0, 3.0, 1.0)),
+        (-0.5, Vec2::new(2.0, 0.5), Vec2::new(1.5, 0.5))
+    );
+}
+
+#[test]
+fn wrap() {
+    let world = rect(-10.0, 0.0, 10.0, 5.0);
+
+    assert_eq!(world.wrap(Vec2::new(12.0, -1.0)), Vec2::new(-8.0, 4.0));
+    assert_eq!(world.wrap(Vec2::new(10.0, 2.0)), Vec2::new(-10.0, 2.0));
+    assert_eq!(world.wrap(Vec2::new(3.0, 2.0)), Vec2::new(3.0, 2.0));
+}
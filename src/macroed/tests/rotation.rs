@@ -0,0 +1,63 @@
+use crate::macroed::*;
+
+fn close(a: Vec2, b: Vec2) -> bool {
+    (a - b).mag() < 1e-5
+}
+
+#[test]
+fn rotate() {
+    let v = Vec2::new(3.0, 4.0);
+
+    assert_eq!(Rotation2::IDENTITY * v, v);
+    assert_eq!(Rotation2::default(), Rotation2::IDENTITY);
+    assert!(close(
+        Rotation2::from_angle(Angle::HALF_TURN) * v,
+        Vec2::new(-3.0, -4.0)
+    ));
+    assert!(close(
+        Rotation2::from(-Angle::QUARTER_TURN).rotate(v),
+        Vec2::new(4.0, -3.0)
+    ));
+}
+
+#[test]
+fn compose() {
+    let a = Rotation2::from_angle(Angle::from_degrees(30.0));
+    let b = Rotation2::from_angle(Angle::from_degrees(60.0));
+    let v = Vec2::new(1.0, 2.0);
+
+    assert!(close((a * b) * v, a * (b * v)));
+    assert!(close(
+        (a * b) * v,
+        Rotation2::from_angle(Angle::QUARTER_TURN) * v
+    ));
+    assert!(close(a.inverse() * (a * v), v));
+
+    let mut c = Rotation2::IDENTITY;
+    c *= a;
+    c *= a;
+    assert!((c.angle().degrees() - 60.0).abs() < 1e-4);
+}
+
+#[test]
+fn direction() {
+    let up = UnitVec2::new(Vec2::new(0.0, 3.0)).unwrap();
+    let r = Rotation2::from_direction(up);
+
+    assert_eq!(r.cos(), 0.0);
+    assert_eq!(r.sin(), 1.0);
+    assert_eq!(r * Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn renormalized() {
+    let step = Rotation2::from_angle(Angle::from_degrees(1.0));
+    let mut r = Rotation2::IDENTITY;
+
+    for _ in 0..10_000 {
+        r *= step;
+    }
+
+    let r = r.renormalized();
+    assert!((Vec2::new(r.cos(), r.sin()).mag() - 1.0).abs() < 1e-6);
+}
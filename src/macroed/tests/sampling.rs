@@ -0,0 +1,55 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::macroed::*;
+
+fn rect(x1: f32, y1: f32, x2: f32, y2: f32) -> Rect {
+    Rect::new(Vec2::new(x1, y1), Vec2::new(x2, y2))
+}
+
+#[test]
+fn poisson_disk_spacing() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let area = rect(-20.0, 0.0, 20.0, 30.0);
+    let points = sampling::poisson_disk(&area, 3.0, &mut rng);
+
+    for (i, a) in points.iter().enumerate() {
+        assert!(area.contains(*a));
+
+        for b in &points[i + 1..] {
+            assert!((*a - *b).mag() >= 3.0);
+        }
+    }
+}
+
+#[test]
+fn poisson_disk_coverage() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let points = sampling::poisson_disk(&rect(0.0, 0.0, 50.0, 50.0), 2.0, &mut rng);
+
+    // There is no room left for another point, so every spot is close to one
+    for x in 0..50 {
+        for y in 0..50 {
+            let spot = Vec2::new(x as f32, y as f32);
+            assert!(points.iter().any(|p| (*p - spot).mag() < 4.0));
+        }
+    }
+}
+
+#[test]
+fn poisson_disk_degenerate() {
+    let mut rng = StdRng::seed_from_u64(3);
+
+    assert!(sampling::poisson_disk(&rect(1.0, 1.0, 0.0, 0.0), 1.0, &mut rng).is_empty());
+    assert_eq!(
+        sampling::poisson_disk(&rect(0.0, 0.0, 0.0, 0.0), 1.0, &mut rng),
+        vec![Vec2::new(0.0, 0.0)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn poisson_disk_zero_distance() {
+    let mut rng = StdRng::seed_from_u64(4);
+    sampling::poisson_disk(&rect(0.0, 0.0, 1.0, 1.0), 0.0, &mut rng);
+}
@@ -0,0 +1,109 @@
+use crate::macroed::*;
+
+fn seg(ax: f32, ay: f32, bx: f32, by: f32) -> Segment {
+    Segment::new(Vec2::new(ax, ay), Vec2::new(bx, by))
+}
+
+#[test]
+fn measure() {
+    let s = seg(1.0, 1.0, 4.0, 5.0);
+
+    assert_eq!(s.length(), 5.0);
+    assert_eq!(s.midpoint(), Vec2::new(2.5, 3.0));
+    assert_eq!(s.perimeter(), 5.0);
+    assert_eq!(s.area(), 0.0);
+}
+
+#[test]
+fn closest_point() {
+    let s = seg(0.0, 0.0, 4.0, 0.0);
+
+    assert_eq!(s.closest_point(Vec2::new(2.0, 3.0)), Vec2::new(2.0, 0.0));
+    assert_eq!(s.closest_point(Vec2::new(-2.0, 3.0)), Vec2::new(0.0, 0.0));
+    assert_eq!(s.closest_point(Vec2::new(9.0, -1.0)), Vec2::new(4.0, 0.0));
+    assert_eq!(
+        seg(1.0, 1.0, 1.0, 1.0).closest_point(Vec2::new(5.0, 5.0)),
+        Vec2::new(1.0, 1.0)
+    );
+}
+
+#[test]
+fn intersect() {
+    let s = seg(0.0, 0.0, 4.0, 0.0);
+
+    assert_eq!(
+        s.intersect(&seg(1.0, -1.0, 1.0, 1.0)),
+        Some(Vec2::new(1.0, 0.0))
+    );
+    assert_eq!(
+        s.intersect(&seg(4.0, 0.0, 5.0, 5.0)),
+        Some(Vec2::new(4.0, 0.0))
+    );
+    assert_eq!(s.intersect(&seg(5.0, -1.0, 5.0, 1.0)), None);
+    assert_eq!(s.intersect(&seg(0.0, 1.0, 4.0, 1.0)), None);
+}
+
+#[test]
+fn intersect_collinear() {
+    let s = seg(0.0, 0.0, 4.0, 0.0);
+
+    assert_eq!(
+        s.intersect(&seg(6.0, 0.0, 2.0, 0.0)),
+        Some(Vec2::new(2.0, 0.0))
+    );
+    assert_eq!(
+        s.intersect(&seg(-3.0, 0.0, 1.0, 0.0)),
+        Some(Vec2::new(0.0, 0.0))
+    );
+    assert_eq!(s.intersect(&seg(5.0, 0.0, 6.0, 0.0)), None);
+    assert_eq!(
+        s.intersect(&seg(3.0, 0.0, 3.0, 0.0)),
+        Some(Vec2::new(3.0, 0.0))
+    );
+}
+
+#[test]
+fn lines() {
+    let (p1, d1) = (Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0));
+    let (p2, d2) = (Vec2::new(0.0, 4.0), Vec2::new(1.0, -1.0));
+
+    assert_eq!(line_intersection_params(p1, d1, p2, d2), Some((0.5, 2.0)));
+    assert_eq!(line_intersection(p1, d1, p2, d2), Some(Vec2::new(2.0, 2.0)));
+
+    // Outside both segments, but the lines still cross
+    assert_eq!(
+        line_intersection(p1, d1, Vec2::new(10.0, 0.0), d2),
+        Some(Vec2::new(5.0, 5.0))
+    );
+    assert_eq!(line_intersection(p1, d1, p2, d1 * -3.0), None);
+    assert_eq!(line_intersection(p1, Vec2::new(0.0, 0.0), p2, d2), None);
+}
+
+#[test]
+fn distance() {
+    let s = seg(0.0, 0.0, 4.0, 0.0);
+
+    assert_eq!(
+        distance_between(&s, &seg(2.0, 3.0, 2.0, 1.0)),
+        (1.0, Vec2::new(2.0, 0.0), Vec2::new(2.0, 1.0))
+    );
+    assert_eq!(
+        distance_between(&Vec2::new(6.0, 0.0), &s),
+        (2.0, Vec2::new(6.0, 0.0), Vec2::new(4.0, 0.0))
+    );
+
+    let r = Rect::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+
+    assert_eq!(
+        distance_between(&r, &s),
+        (2.0, Vec2::new(1.0, 2.0), Vec2::new(1.0, 0.0))
+    );
+    assert_eq!(distance_between(&seg(0.0, 3.0, 9.0, 3.0), &r).0, 0.0);
+
+    let c = Circle::new(Vec2::new(2.0, 2.0), 1.0);
+
+    assert_eq!(
+        distance_between(&s, &c),
+        (1.0, Vec2::new(2.0, 0.0), Vec2::new(2.0, 1.0))
+    );
+}
@@ -0,0 +1,30 @@
+use crate::macroed::*;
+
+// Measurement //
+
+#[test]
+fn measure_point() {
+    let p = Vec2::new(2.0, 3.0);
+
+    assert_eq!(p.area(), 0.0);
+    assert_eq!(p.perimeter(), 0.0);
+    assert_eq!(p.centroid(), p);
+}
+
+// Distance //
+
+#[test]
+fn distance_point_point() {
+    let a = Vec2::new(1.0, 1.0);
+    let b = Vec2::new(4.0, 5.0);
+
+    assert_eq!(distance_between(&a, &b), (5.0, a, b));
+    assert_eq!(distance_between(&b, &a), (5.0, b, a));
+}
+
+#[test]
+fn distance_same_point() {
+    let a = Vec2::new(2.0, -3.0);
+
+    assert_eq!(distance_between(&a, &a), (0.0, a, a));
+}
@@ -0,0 +1,82 @@
+use crate::macroed::*;
+
+#[test]
+fn f32_ops() {
+    let a = Vec2f32::new(3.5, -2.0);
+    let b = Vec2f32::new(1.5, 4.0);
+
+    assert_eq!(a + b, Vec2f32::new(5.0, 2.0));
+    assert_eq!(a - b, Vec2f32::new(2.0, -6.0));
+    assert_eq!(a * b, Vec2f32::new(5.25, -8.0));
+    assert_eq!(a / b, Vec2f32::new(3.5 / 1.5, -0.5));
+    assert_eq!(a % b, Vec2f32::new(0.5, -2.0));
+    assert_eq!(a * 2.0, Vec2f32::new(7.0, -4.0));
+    assert_eq!(a.mag2(), 16.25);
+    assert_eq!(a.max(b), Vec2f32::new(3.5, 4.0));
+    assert_eq!(a.min(b), Vec2f32::new(1.5, -2.0));
+
+    let mut c = a;
+    c += b;
+    c -= 1.0;
+
+    assert_eq!(c, Vec2f32::new(4.0, 1.0));
+}
+
+#[test]
+fn f64_ops() {
+    let a = Vec2f64::new(3.5, -2.0);
+    let b = Vec2f64::new(1.5, 4.0);
+
+    assert_eq!(a + b, Vec2f64::new(5.0, 2.0));
+    assert_eq!(a - b, Vec2f64::new(2.0, -6.0));
+    assert_eq!(a * b, Vec2f64::new(5.25, -8.0));
+    assert_eq!(a / b, Vec2f64::new(3.5 / 1.5, -0.5));
+    assert_eq!(a % b, Vec2f64::new(0.5, -2.0));
+    assert_eq!(a / 2.0, Vec2f64::new(1.75, -1.0));
+    assert_eq!(a.mag2(), 16.25);
+    assert_eq!(a.clamp(Vec2f64::new(0.0, 0.0), b), Vec2f64::new(1.5, 0.0));
+
+    let mut c = a;
+    c *= b;
+    c /= 2.0;
+
+    assert_eq!(c, Vec2f64::new(2.625, -4.0));
+}
+
+#[test]
+fn max_min_match_scalar() {
+    let a = Vec2f32::new(1.0, 0.0);
+    let b = Vec2f32::new(f32::NAN, -0.0);
+
+    for (v, w) in [(a, b), (b, a)] {
+        let max = v.max(w);
+        let min = v.min(w);
+
+        assert_eq!(max.x.to_bits(), v.x.max(w.x).to_bits());
+        assert_eq!(max.y.to_bits(), v.y.max(w.y).to_bits());
+        assert_eq!(min.x.to_bits(), v.x.min(w.x).to_bits());
+        assert_eq!(min.y.to_bits(), v.y.min(w.y).to_bits());
+    }
+
+    assert_eq!(
+        Vec2f32::new(1.0, 1.0).max(Vec2f32::new(f32::NAN, f32::NAN)),
+        Vec2f32::new(1.0, 1.0)
+    );
+    assert_eq!(
+        Vec2f64::new(1.0, 1.0).min(Vec2f64::new(f64::NAN, f64::NAN)),
+        Vec2f64::new(1.0, 1.0)
+    );
+}
+
+#[test]
+fn dispatch_on_scalar() {
+    // A downstream vector reusing this crate's name with another scalar type
+    mod downstream {
+        crate::create_vec2!(Vec2f32, f64);
+    }
+
+    let a = downstream::Vec2f32::new(3.5, -2.0);
+
+    assert_eq!((a + a).x, 7.0);
+    assert_eq!(a.mag2(), 16.25);
+}
@@ -0,0 +1,74 @@
+use crate::macroed::*;
+
+#[test]
+fn non_negative() {
+    let size = Size2::new(-1.0, f32::NAN);
+
+    assert_eq!(size, Size2::ZERO);
+    assert_eq!(Size2::default(), Size2::ZERO);
+    assert_eq!(Size2::from_vec2(Vec2::new(3.0, -4.0)), Size2::new(3.0, 0.0));
+    assert_eq!(Size2f64::from(Vec2d::new(2.0, 5.0)).height(), 5.0);
+}
+
+#[test]
+fn measure() {
+    let size = Size2u32::new(1920, 1080);
+
+    assert_eq!(size.width(), 1920);
+    assert_eq!(size.height(), 1080);
+    assert_eq!(size.area(), 2_073_600);
+    assert_eq!(size.aspect_ratio(), 16.0 / 9.0);
+    assert_eq!(Vec2u32::from(size), Vec2u32::new(1920, 1080));
+
+    assert!(Size2::new(2.0, 0.0).aspect_ratio().is_infinite());
+    assert!(Size2::ZERO.aspect_ratio().is_nan());
+}
+
+#[test]
+fn large_area() {
+    assert_eq!(Size2u32::new(70_000, 70_000).area(), 4_900_000_000);
+    assert_eq!(
+        Size2usize::new(usize::MAX, 2).area(),
+        usize::MAX as u128 * 2
+    );
+}
+
+#[test]
+fn empty() {
+    assert!(Size2usize::ZERO.is_empty());
+    assert!(Size2usize::new(4, 0).is_empty());
+    assert!(!Size2usize::new(4, 1).is_empty());
+}
+
+#[test]
+fn fits_within() {
+    let window = Size2::new(800.0, 600.0);
+
+    assert!(window.fits_within(window));
+    assert!(Size2::new(600.0, 600.0).fits_within(window));
+    assert!(!Size2::new(600.0, 800.0).fits_within(window));
+    assert!(Size2::ZERO.fits_within(Size2::ZERO));
+}
+
+#[test]
+fn fit_and_fill() {
+    let video = Size2::new(1600.0, 900.0);
+    let phone = Size2::new(900.0, 1600.0);
+
+    assert_eq!(video.fit_within(phone), Vec2::new(900.0, 506.25));
+    assert_eq!(video.fill(phone), Vec2::new(2844.4446, 1600.0));
+    assert_eq!(video.letterbox_offset(phone), Vec2::new(0.0, 546.875));
+    assert_eq!(video.fit_within(video), video.to_vec2());
+}
+
+#[test]
+fn fit_empty() {
+    let window = Size2u32::new(800, 600);
+
+    assert_eq!(Size2u32::ZERO.fit_within(window), Vec2d::new(0.0, 0.0));
+    assert_eq!(
+        Size2u32::ZERO.letterbox_offset(window),
+        Vec2d::new(400.0, 300.0)
+    );
+    assert_eq!(window.fit_within(Size2u32::ZERO), Vec2d::new(0.0, 0.0));
+}
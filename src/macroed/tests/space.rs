@@ -0,0 +1,42 @@
+use crate::macroed::*;
+
+type World = TypedVec2<Vec2, WorldSpace>;
+
+#[test]
+fn typed_ops() {
+    let mut a = World::new(Vec2::new(1.0, 2.0));
+    let b = World::new(Vec2::new(3.0, 4.0));
+
+    assert_eq!((a + b).vec, Vec2::new(4.0, 6.0));
+    assert_eq!((b - a).vec, Vec2::new(2.0, 2.0));
+    assert_eq!((a * 2.0).vec, Vec2::new(2.0, 4.0));
+    assert_eq!((a * Vec2::new(2.0, 3.0)).vec, Vec2::new(2.0, 6.0));
+    assert_eq!((-a).vec, Vec2::new(-1.0, -2.0));
+
+    a += b;
+    a -= b;
+
+    assert_eq!(a.vec, Vec2::new(1.0, 2.0));
+}
+
+#[test]
+fn cast_space() {
+    let world = World::new(Vec2::new(1.0, 2.0));
+    let uv: TypedVec2<Vec2, UvSpace> = world.cast_space();
+
+    assert_eq!(uv.vec, world.vec);
+}
+
+#[test]
+fn transform_chain() {
+    let to_screen =
+        SpaceTransform::<WorldSpace, ScreenSpace>::new(Vec2::new(2.0, 2.0), Vec2::new(10.0, 0.0));
+    let to_uv =
+        SpaceTransform::<ScreenSpace, UvSpace>::new(Vec2::new(0.5, 0.25), Vec2::new(0.0, 1.0));
+
+    let world = World::new(Vec2::new(3.0, 4.0));
+    let chained = to_screen.then(&to_uv);
+
+    assert_eq!(chained.apply(world), to_uv.apply(to_screen.apply(world)));
+    assert_eq!(chained.inverse().apply(chained.apply(world)), world);
+}
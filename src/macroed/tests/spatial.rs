@@ -0,0 +1,99 @@
+use crate::macroed::*;
+
+fn sorted<'a>(items: impl Iterator<Item = (Vec2, &'a u32)>) -> Vec<u32> {
+    let mut items: Vec<u32> = items.map(|(_, item)| *item).collect();
+    items.sort();
+    items
+}
+
+fn filled() -> SpatialHash<u32> {
+    let mut hash = SpatialHash::new(2.0);
+
+    hash.insert(Vec2::new(0.5, 0.5), 0);
+    hash.insert(Vec2::new(-0.5, 0.5), 1);
+    hash.insert(Vec2::new(3.0, 3.0), 2);
+    hash.insert(Vec2::new(-10.0, 7.5), 3);
+    hash.insert(Vec2::new(1.0, 0.0), 4);
+
+    hash
+}
+
+#[test]
+fn cells() {
+    let hash: SpatialHash<()> = SpatialHash::new(2.0);
+
+    assert_eq!(hash.cell_size(), 2.0);
+    assert_eq!(hash.cell_of(Vec2::new(0.0, 1.9)), Vec2i32::new(0, 0));
+    assert_eq!(hash.cell_of(Vec2::new(-0.1, 4.0)), Vec2i32::new(-1, 2));
+}
+
+#[test]
+fn far_away() {
+    let mut hash = filled();
+    hash.insert(Vec2::new(1e30, -1e30), 5);
+
+    assert_eq!(
+        hash.cell_of(Vec2::new(1e30, f32::NEG_INFINITY)),
+        Vec2i32::new(i32::MAX, i32::MIN)
+    );
+    assert_eq!(hash.cell_of(Vec2::new(f32::NAN, 0.0)), Vec2i32::new(0, 0));
+    assert_eq!(
+        sorted(hash.query_radius(Vec2::new(0.0, 0.0), f32::INFINITY)),
+        [0, 1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        sorted(hash.query_rect(&Rect::new(Vec2::new(-1e30, -1e30), Vec2::new(1e30, 0.0)))),
+        [4, 5]
+    );
+}
+
+#[test]
+#[should_panic]
+fn zero_cell_size() {
+    let _: SpatialHash<()> = SpatialHash::new(0.0);
+}
+
+#[test]
+fn insert_and_retain() {
+    let mut hash = filled();
+
+    assert_eq!(hash.len(), 5);
+    assert_eq!(sorted(hash.iter()), [0, 1, 2, 3, 4]);
+
+    hash.retain(|position, _| position.x >= 0.0);
+
+    assert_eq!(hash.len(), 3);
+    assert_eq!(sorted(hash.iter()), [0, 2, 4]);
+
+    hash.clear();
+
+    assert!(hash.is_empty());
+    assert_eq!(hash.iter().count(), 0);
+}
+
+#[test]
+fn query_rect() {
+    let hash = filled();
+    let rect = Rect::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 1.0));
+
+    assert_eq!(sorted(hash.query_rect(&rect)), [0, 1, 4]);
+
+    // Large enough to scan the occupied cells instead of looking each one up
+    let huge = Rect::new(Vec2::new(-1000.0, -1000.0), Vec2::new(1000.0, 5.0));
+    assert_eq!(sorted(hash.query_rect(&huge)), [0, 1, 2, 4]);
+
+    let empty = Rect::new(Vec2::new(1.0, 1.0), Vec2::new(-1.0, -1.0));
+    assert_eq!(hash.query_rect(&empty).count(), 0);
+}
+
+#[test]
+fn query_radius() {
+    let hash = filled();
+
+    assert_eq!(
+        sorted(hash.query_radius(Vec2::new(0.0, 0.0), 1.0)),
+        [0, 1, 4]
+    );
+    assert_eq!(sorted(hash.query_radius(Vec2::new(2.0, 2.0), 1.5)), [2]);
+    assert_eq!(sorted(hash.query_radius(Vec2::new(-10.0, 7.0), 0.5)), [3]);
+}
@@ -0,0 +1,27 @@
+use proptest::prelude::*;
+
+use crate::macroed::strategy::*;
+use crate::macroed::*;
+
+proptest! {
+    #[test]
+    fn vec2_in_range(v in vec2_in::<_, Vec2u8>(10..20)) {
+        prop_assert!((10..20).contains(&v.x));
+        prop_assert!((10..20).contains(&v.y));
+    }
+
+    #[test]
+    fn finite_f32(v in finite_vec2f32()) {
+        prop_assert!(v.x.is_finite() && v.y.is_finite());
+    }
+
+    #[test]
+    fn finite_f64(v in finite_vec2f64()) {
+        prop_assert!(v.x.is_finite() && v.y.is_finite());
+    }
+
+    #[test]
+    fn arbitrary(v in any::<Vec2i16>()) {
+        prop_assert_eq!(Vec2i16::from(<(i16, i16)>::from(v)), v);
+    }
+}
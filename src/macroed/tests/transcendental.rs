@@ -0,0 +1,53 @@
+use crate::macroed::*;
+
+fn close(a: Vec2d, b: Vec2d) -> bool {
+    (a - b).mag() < 1e-12
+}
+
+#[test]
+fn trig() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    let v = Vec2d::new(FRAC_PI_2, PI);
+
+    assert!(close(v.sin(), Vec2d::new(1.0, 0.0)));
+    assert!(close(v.cos(), Vec2d::new(0.0, -1.0)));
+    assert!(close(
+        Vec2d::new(FRAC_PI_4, 0.0).tan(),
+        Vec2d::new(1.0, 0.0)
+    ));
+
+    let (sin, cos) = v.sin_cos();
+    assert!(close(sin, v.sin()) && close(cos, v.cos()));
+}
+
+#[test]
+fn exp_and_ln() {
+    let v = Vec2d::new(0.0, 2.0);
+
+    assert!(close(
+        v.exp(),
+        Vec2d::new(1.0, std::f64::consts::E * std::f64::consts::E)
+    ));
+    assert!(close(v.exp().ln(), v));
+    assert_eq!(Vec2::new(1.0, 0.0).ln(), Vec2::new(0.0, f32::NEG_INFINITY));
+}
+
+#[test]
+fn logs() {
+    let v = Vec2d::new(8.0, 1000.0);
+
+    assert_eq!(v.log2().x, 3.0);
+    assert_eq!(v.log10().y, 3.0);
+    assert!(close(
+        Vec2d::new(9.0, 1.0 / 3.0).log(3.0),
+        Vec2d::new(2.0, -1.0)
+    ));
+    assert!(Vec2::new(-1.0, 1.0).ln().x.is_nan());
+}
+
+#[test]
+fn cbrt() {
+    assert_eq!(Vec2d::new(27.0, -8.0).cbrt(), Vec2d::new(3.0, -2.0));
+    assert_eq!(Vec2::new(0.0, 1.0).cbrt(), Vec2::new(0.0, 1.0));
+}
@@ -0,0 +1,128 @@
+use crate::macroed::*;
+
+fn right() -> Triangle {
+    Triangle::new(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(0.0, 3.0),
+    )
+}
+
+#[test]
+fn measure() {
+    let t = right();
+    let flipped = Triangle::new(t.a, t.c, t.b);
+
+    assert_eq!(t.signed_area(), 6.0);
+    assert_eq!(flipped.signed_area(), -6.0);
+    assert_eq!(flipped.area(), 6.0);
+    assert_eq!(t.perimeter(), 12.0);
+    assert_eq!(t.centroid(), Vec2::new(4.0 / 3.0, 1.0));
+}
+
+#[test]
+fn centers() {
+    let t = right();
+
+    assert_eq!(t.circumcenter(), Some(Vec2::new(2.0, 1.5)));
+    assert_eq!(t.incenter(), Some(Vec2::new(1.0, 1.0)));
+    assert_eq!(
+        t.circumcircle(),
+        Some(Circle::new(Vec2::new(2.0, 1.5), 2.5))
+    );
+}
+
+#[test]
+fn degenerate() {
+    let line = Triangle::new(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(3.0, 3.0),
+    );
+    let point = Triangle::new(
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 1.0),
+    );
+
+    assert_eq!(line.signed_area(), 0.0);
+    assert_eq!(line.circumcenter(), None);
+    assert_eq!(line.circumcircle(), None);
+    assert_eq!(
+        line.bounding_circle(),
+        Circle::new(Vec2::new(1.5, 1.5), 4.5f32.sqrt())
+    );
+    assert_eq!(point.incenter(), None);
+}
+
+#[test]
+fn bounding_circle() {
+    let acute = Triangle::new(
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 2.0),
+    );
+    let obtuse = Triangle::new(
+        Vec2::new(-2.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    );
+
+    assert_eq!(
+        acute.bounding_circle(),
+        Circle::new(Vec2::new(0.0, 0.75), 1.25)
+    );
+    assert_eq!(right().bounding_circle(), right().circumcircle().unwrap());
+    assert_eq!(
+        obtuse.bounding_circle(),
+        Circle::new(Vec2::new(0.0, 0.0), 2.0)
+    );
+}
+
+#[test]
+fn contains() {
+    let t = right();
+
+    assert!(t.contains_point(Vec2::new(1.0, 1.0)));
+    assert!(t.contains_point(Vec2::new(2.0, 0.0)));
+    assert!(!t.contains_point(Vec2::new(3.0, 3.0)));
+    assert!(Triangle::new(t.a, t.c, t.b).contains_point(Vec2::new(1.0, 1.0)));
+}
+
+#[test]
+fn distance() {
+    let t = right();
+    let point = Vec2::new(-2.0, 1.0);
+    let rect = Rect::new(Vec2::new(5.0, 0.0), Vec2::new(6.0, 1.0));
+
+    assert_eq!(distance_between(&t, &point).0, 2.0);
+    assert_eq!(distance_between(&rect, &t).0, 1.0);
+    assert_eq!(
+        distance_between(&t, &Circle::new(Vec2::new(0.0, -3.0), 1.0)).0,
+        2.0
+    );
+}
+
+#[test]
+fn barycentric_weights() {
+    let t = right();
+
+    assert_eq!(t.barycentric(t.a), Some([1.0, 0.0, 0.0]));
+    assert_eq!(t.barycentric(t.b), Some([0.0, 1.0, 0.0]));
+    assert_eq!(t.barycentric(t.c), Some([0.0, 0.0, 1.0]));
+    assert_eq!(t.barycentric(Vec2::new(-4.0, 0.0)), Some([2.0, -1.0, 0.0]));
+
+    // The order of the vertices doesn't change the result
+    let [wa, wc, wb] = barycentric(t.centroid(), t.a, t.c, t.b).unwrap();
+    assert!(Vec2::new(wa, wb).approx_eq((1.0 / 3.0, 1.0 / 3.0), 1e-6));
+    assert!((wc - 1.0 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn barycentric_round_trip() {
+    let t = right();
+    let p = Vec2::new(1.0, 0.75);
+    let weights = t.barycentric(p).unwrap();
+
+    assert_eq!(interpolate_barycentric(weights, t.vertices()), p);
+}
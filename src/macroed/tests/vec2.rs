@@ -57,6 +57,12 @@ fn mag2() {
     assert_eq!(Vec2::new(2.0, 3.0).mag2(), 13.0);
 }
 
+#[test]
+fn dot() {
+    assert_eq!(Vec2::new(2.0, 3.0).dot((4.0, -1.0)), 5.0);
+    assert_eq!(Vec2i::new(1, 0).dot(Vec2i::new(0, 7)), 0);
+}
+
 #[test]
 fn max() {
     assert_eq!(Vec2::new(4.0, 7.0).max([5.0; 2]), Vec2::new(5.0, 7.0));
@@ -98,24 +104,428 @@ fn mag() {
     assert_eq!(Vec2::new(6.0, 8.0).mag(), 10.0);
 }
 
+#[test]
+fn mul_add() {
+    let v = Vec2::new(0.1, -2.0);
+
+    assert_eq!(
+        v.mul_add(Vec2::new(10.0, 0.5), (-1.0, 1.0)),
+        Vec2::new(0.1f32.mul_add(10.0, -1.0), 0.0)
+    );
+    assert_ne!(v.mul_add((10.0, 10.0), (-1.0, -1.0)).x, v.x * 10.0 - 1.0);
+}
+
+#[test]
+fn mag_precise() {
+    assert_eq!(Vec2::new(6.0, 8.0).mag_precise(), 10.0);
+    assert_eq!(Vec2::new(3e30, 4e30).mag_precise(), 5e30);
+    assert_eq!(Vec2::new(3e30, 4e30).mag(), f32::INFINITY);
+}
+
 #[test]
 fn norm() {
     // Difficult to test with exact float, this works for now by comparing slopes
     assert_eq!(Vec2::new(2.0, 4.0).norm(), Vec2::new(4.0, 8.0).norm());
 }
 
+#[test]
+fn sqrt() {
+    assert_eq!(Vec2::new(9.0, 0.0).sqrt(), Vec2::new(3.0, 0.0));
+    assert!(Vec2d::new(-1.0, 4.0).sqrt().x.is_nan());
+}
+
 #[test]
 fn floor() {
-    assert_eq!(Vec2::new(3.14159, 2.0).floor(), Vec2::new(3.0, 2.0));
+    assert_eq!(Vec2::new(3.25, 2.0).floor(), Vec2::new(3.0, 2.0));
 }
 
 #[test]
 fn ceil() {
-    assert_eq!(Vec2::new(3.14159, 6.0).ceil(), Vec2::new(4.0, 6.0));
+    assert_eq!(Vec2::new(3.25, 6.0).ceil(), Vec2::new(4.0, 6.0));
+}
+
+#[test]
+fn mix() {
+    let a = Vec2::new(2.0, -4.0);
+    let b = Vec2::new(6.0, 4.0);
+
+    assert_eq!(a.mix(b, 0.0), a);
+    assert_eq!(a.mix(b, 1.0), b);
+    assert_eq!(a.mix((6.0, 4.0), 0.5), Vec2::new(4.0, 0.0));
+    assert_eq!(a.mix(b, Vec2::new(0.0, 0.75)), Vec2::new(2.0, 2.0));
+    assert_eq!(a.mix(b, [1.0, 0.25]), Vec2::new(6.0, -2.0));
+}
+
+#[test]
+fn faceforward() {
+    let n = Vec2::new(1.0, 0.0);
+
+    assert_eq!(n.faceforward((-1.0, 0.5), n), n);
+    assert_eq!(n.faceforward((1.0, 0.5), n), -n);
+    // A grazing ray counts as facing the same way
+    assert_eq!(n.faceforward((0.0, 1.0), n), -n);
+    assert_eq!(n.faceforward((1.0, 0.0), (-1.0, 0.0)), n);
+}
+
+#[test]
+fn reflect() {
+    assert_eq!(
+        Vec2::new(3.0, -2.0).reflect((0.0, 1.0)),
+        Vec2::new(3.0, 2.0)
+    );
+    assert_eq!(
+        Vec2::new(-1.0, 5.0).reflect((1.0, 0.0)),
+        Vec2::new(1.0, 5.0)
+    );
+}
+
+#[test]
+fn refract() {
+    let normal = Vec2d::new(0.0, 1.0);
+
+    // Head-on rays don't bend
+    assert_eq!(
+        Vec2d::new(0.0, -1.0).refract(normal, 1.5),
+        Vec2d::new(0.0, -1.0)
+    );
+
+    // Snell's law: sin(out) = eta * sin(in)
+    let ray = Vec2d::new(0.6, -0.8);
+    let out = ray.refract(normal, 0.5);
+    assert!((out.x - 0.3).abs() < 1e-12);
+    assert!((out.mag() - 1.0).abs() < 1e-12);
+    assert!(out.y < 0.0);
+
+    // Total internal reflection
+    assert_eq!(ray.refract(normal, 1.7), Vec2d::new(0.0, 0.0));
+}
+
+#[test]
+fn mod_floored() {
+    let v = Vec2::new(-1.5, 7.0);
+
+    assert_eq!(v.mod_floored((4.0, 4.0)), Vec2::new(2.5, 3.0));
+    assert_eq!(v.mod_floored((-4.0, -4.0)), Vec2::new(-1.5, -1.0));
+    assert_eq!(
+        Vec2d::new(-0.25, 0.0).mod_floored((1.0, 1.0)),
+        Vec2d::new(0.75, 0.0)
+    );
+}
+
+#[test]
+fn saturate() {
+    assert_eq!(Vec2::new(0.25, 0.75).saturate(), Vec2::new(0.25, 0.75));
+    assert_eq!(Vec2::new(-3.0, 3.0).saturate(), Vec2::new(0.0, 1.0));
+    assert_eq!(Vec2d::new(1.0, 0.0).saturate(), Vec2d::new(1.0, 0.0));
+}
+
+#[test]
+fn remap() {
+    let v = Vec2::new(5.0, 0.0);
+
+    assert_eq!(
+        v.remap((0.0, 0.0), (10.0, 10.0), (0.0, 0.0), (1.0, 1.0)),
+        Vec2::new(0.5, 0.0)
+    );
+    // Flipped and extrapolated ranges
+    assert_eq!(
+        v.remap((0.0, 0.0), (10.0, 10.0), (1.0, 1.0), (-1.0, -1.0)),
+        Vec2::new(0.0, 1.0)
+    );
+    assert_eq!(
+        Vec2d::new(30.0, -5.0).remap((10.0, 0.0), (20.0, 10.0), (0.0, 0.0), (100.0, 1.0)),
+        Vec2d::new(200.0, -0.5)
+    );
+}
+
+#[test]
+fn snap() {
+    let v = Vec2::new(1.2, -0.7);
+
+    assert_eq!(v.snap((0.5, 0.5)), Vec2::new(1.0, -0.5));
+    assert_eq!(v.snap_floor((0.5, 0.5)), Vec2::new(1.0, -1.0));
+    assert_eq!(v.snap_ceil((0.5, 0.5)), Vec2::new(1.5, -0.5));
+
+    // Each axis can have its own cell size, and halfway rounds up
+    assert_eq!(Vec2::new(5.0, 5.0).snap((10.0, 4.0)), Vec2::new(10.0, 4.0));
+    assert_eq!(Vec2::new(-5.0, 8.0).snap((10.0, 4.0)), Vec2::new(0.0, 8.0));
+}
+
+#[test]
+fn approx_eq() {
+    let v = Vec2d::new(1.0, 2.0);
+
+    assert!(v.approx_eq((1.04, 1.96), 0.05));
+    assert!(!v.approx_eq((1.0, 2.2), 0.1));
+    assert!(v.approx_eq(v, 0.0));
+    assert!(!Vec2::new(f32::NAN, 0.0).approx_eq((f32::NAN, 0.0), 1.0));
+}
+
+#[test]
+fn ping_pong() {
+    let length = (2.0, 2.0);
+
+    assert_eq!(Vec2::new(0.5, 2.0).ping_pong(length), Vec2::new(0.5, 2.0));
+    assert_eq!(Vec2::new(3.0, 4.5).ping_pong(length), Vec2::new(1.0, 0.5));
+    assert_eq!(Vec2::new(-0.5, -3.0).ping_pong(length), Vec2::new(0.5, 1.0));
+    assert_eq!(
+        Vec2d::new(7.0, 7.0).ping_pong((3.0, 10.0)),
+        Vec2d::new(1.0, 7.0)
+    );
+}
+
+#[test]
+fn step() {
+    let v = Vec2::new(0.5, -2.0);
+
+    // The edge itself counts as reached
+    assert_eq!(v.step((0.5, 0.5)), Vec2::new(1.0, 0.0));
+    assert_eq!(v.step((1.0, -3.0)), Vec2::new(0.0, 1.0));
+    assert_eq!(Vec2d::new(3.0, 1.0).step((2.0, 2.0)), Vec2d::new(1.0, 0.0));
+}
+
+#[test]
+fn smoothstep() {
+    assert_eq!(
+        Vec2::new(0.5, 0.25).smoothstep((0.0, 0.0), (1.0, 1.0)),
+        Vec2::new(0.5, 0.15625)
+    );
+    assert_eq!(
+        Vec2::new(-5.0, 5.0).smoothstep((0.0, 0.0), (1.0, 1.0)),
+        Vec2::new(0.0, 1.0)
+    );
+    assert_eq!(
+        Vec2d::new(15.0, 15.0).smoothstep((10.0, 20.0), (20.0, 10.0)),
+        Vec2d::new(0.5, 0.5)
+    );
+}
+
+// Integers //
+
+#[test]
+fn abs_diff() {
+    assert_eq!(
+        Vec2u8::new(0, 255).abs_diff((255, 0)),
+        Vec2u8::new(255, 255)
+    );
+    assert_eq!(
+        Vec2i8::new(i8::MIN, 0).abs_diff((i8::MAX, -3)),
+        Vec2u8::new(255, 3)
+    );
+    assert_eq!(
+        Vec2::new(1.5, -2.0).abs_diff((0.5, 2.0)),
+        Vec2::new(1.0, 4.0)
+    );
+}
+
+#[test]
+fn primitive_direction() {
+    assert_eq!(Vec2i::new(12, -18).primitive_direction(), Vec2i::new(2, -3));
+    assert_eq!(Vec2i::new(-7, 0).primitive_direction(), Vec2i::new(-1, 0));
+    assert_eq!(Vec2i::new(0, 0).primitive_direction(), Vec2i::new(0, 0));
+    assert_eq!(
+        Vec2i8::new(i8::MIN, 0).primitive_direction(),
+        Vec2i8::new(-1, 0)
+    );
+    assert_eq!(
+        Vec2i8::new(i8::MIN, 1).primitive_direction(),
+        Vec2i8::new(i8::MIN, 1)
+    );
+    assert_eq!(
+        Vec2i8::new(i8::MIN, i8::MIN).primitive_direction(),
+        Vec2i8::new(-1, -1)
+    );
+}
+
+#[test]
+fn lattice_points_between() {
+    assert_eq!(Vec2i::new(0, 0).lattice_points_between((0, 0)), Some(1));
+    assert_eq!(Vec2i::new(-3, 2).lattice_points_between((3, -2)), Some(3));
+    assert_eq!(Vec2i::new(0, 0).lattice_points_between((0, -4)), Some(5));
+    assert_eq!(Vec2u::new(2, 9).lattice_points_between((8, 0)), Some(4));
+    assert_eq!(
+        Vec2i8::new(i8::MIN, 0).lattice_points_between((i8::MAX - 1, 0)),
+        Some(255)
+    );
+    assert_eq!(Vec2u8::new(0, 0).lattice_points_between((255, 0)), None);
+    assert_eq!(
+        Vec2i128::new(i128::MIN, 0).lattice_points_between((i128::MAX, 0)),
+        None
+    );
+}
+
+#[test]
+fn manhattan() {
+    let a = Vec2u8::new(10, 2);
+
+    assert_eq!(a.manhattan_length(), 12);
+    assert_eq!(a.manhattan_distance((3, 5)), 10);
+    assert_eq!(Vec2i::new(-3, 4).manhattan_length(), 7);
+    assert_eq!(Vec2i::new(-3, 4).manhattan_distance((2, 2)), 7);
+    assert_eq!(Vec2::new(0.5, -1.5).manhattan_distance((-1.0, 0.0)), 3.0);
+    assert_eq!(Vec2i8::new(-100, 0).manhattan_distance((100, 0)), 200u8);
+    assert_eq!(Vec2i8::new(i8::MIN, 0).manhattan_length(), 128u8);
+}
+
+#[test]
+fn checked_manhattan() {
+    let min = Vec2i32::new(i32::MIN, i32::MIN);
+    let max = Vec2i32::new(i32::MAX, i32::MAX);
+
+    assert_eq!(min.checked_manhattan_length(), None);
+    assert_eq!(min.checked_manhattan_distance(max), None);
+    assert_eq!(
+        Vec2i32::new(i32::MIN, i32::MAX).checked_manhattan_length(),
+        Some(u32::MAX)
+    );
+    assert_eq!(
+        Vec2i32::new(i32::MIN, 0).checked_manhattan_distance((i32::MAX, 0)),
+        Some(u32::MAX)
+    );
+    assert_eq!(Vec2u8::new(200, 200).checked_manhattan_length(), None);
+    assert_eq!(Vec2u8::new(0, 0).checked_manhattan_distance((255, 1)), None);
+    assert_eq!(Vec2u8::new(200, 55).checked_manhattan_length(), Some(255));
+    assert_eq!(Vec2u8::MAX.checked_manhattan_distance((0, 255)), Some(255));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn manhattan_overflow() {
+    Vec2i32::new(i32::MIN, i32::MIN).manhattan_length();
+}
+
+#[test]
+fn chebyshev() {
+    let a = Vec2u8::new(10, 2);
+
+    assert_eq!(a.chebyshev_length(), 10);
+    assert_eq!(a.chebyshev_distance((3, 5)), 7);
+    assert_eq!(Vec2i::new(-5, 4).chebyshev_length(), 5);
+    assert_eq!(Vec2i::new(-3, 4).chebyshev_distance((2, 2)), 5);
+    assert_eq!(Vec2::new(0.5, -1.5).chebyshev_distance((-1.0, 0.0)), 1.5);
+    assert_eq!(Vec2i8::new(i8::MIN, 3).chebyshev_length(), 128u8);
+    assert_eq!(
+        Vec2i8::new(i8::MIN, 0).chebyshev_distance((i8::MAX, 1)),
+        255u8
+    );
+}
+
+#[test]
+fn neighbors() {
+    let corner = Vec2u8::new(0, 0);
+
+    assert_eq!(
+        corner.neighbors4().collect::<Vec<_>>(),
+        [Vec2u8::new(1, 0), Vec2u8::new(0, 1)]
+    );
+    assert_eq!(corner.neighbors8().count(), 3);
+    assert_eq!(Vec2i::new(5, 5).neighbors8().count(), 8);
+    assert_eq!(Vec2u8::new(255, 3).neighbors4().count(), 3);
+}
+
+#[test]
+fn neighbors_within() {
+    let size = Vec2i::new(3, 2);
+
+    assert_eq!(
+        Vec2i::new(2, 1).neighbors4_within(size).collect::<Vec<_>>(),
+        [Vec2i::new(1, 1), Vec2i::new(2, 0)]
+    );
+    assert_eq!(Vec2i::new(1, 0).neighbors8_within(size).count(), 5);
+}
+
+#[test]
+fn line_to() {
+    let line: Vec<_> = Vec2i::new(1, 1).line_to((-2, 5)).collect();
+
+    assert_eq!(line.first(), Some(&Vec2i::new(1, 1)));
+    assert_eq!(line.last(), Some(&Vec2i::new(-2, 5)));
+    assert_eq!(line.len(), 5);
+
+    for (a, b) in line.iter().zip(&line[1..]) {
+        assert_eq!(a.chebyshev_distance(*b), 1);
+    }
+
+    assert_eq!(
+        Vec2u8::new(255, 0).line_to((253, 0)).collect::<Vec<_>>(),
+        [
+            Vec2u8::new(255, 0),
+            Vec2u8::new(254, 0),
+            Vec2u8::new(253, 0)
+        ]
+    );
+    assert_eq!(Vec2u8::new(4, 4).line_to((4, 4)).count(), 1);
+}
+
+#[test]
+fn line_to_full_range() {
+    let mut line = Vec2i128::new(i128::MIN, 0).line_to((i128::MAX, 1));
+
+    assert_eq!(line.next(), Some(Vec2i128::new(i128::MIN, 0)));
+    assert_eq!(line.next(), Some(Vec2i128::new(i128::MIN + 1, 0)));
+
+    let big = u128::MAX - 2;
+    let line: Vec<_> = Vec2u128::new(big, 0).line_to((u128::MAX, 3)).collect();
+
+    assert_eq!(line.len(), 4);
+    assert_eq!(line.last(), Some(&Vec2u128::new(u128::MAX, 3)));
+    assert!(line.iter().all(|cell| cell.x >= big));
+
+    let line: Vec<_> = Vec2u128::new(0, u128::MAX)
+        .line_to((2, u128::MAX - 6))
+        .collect();
+
+    assert_eq!(line.len(), 7);
+    assert_eq!(line[3], Vec2u128::new(1, u128::MAX - 3));
+}
+
+#[test]
+fn overflowing() {
+    let v = Vec2u8::new(250, 10);
+
+    assert_eq!(v.overflowing_add((10, 10)), (Vec2u8::new(4, 20), true));
+    assert_eq!(v.overflowing_sub((0, 11)), (Vec2u8::new(250, 255), true));
+    assert_eq!(v.overflowing_mul((1, 2)), (Vec2u8::new(250, 20), false));
+    assert_eq!(
+        Vec2i8::new(-128, 0).overflowing_mul((-1, 5)),
+        (Vec2i8::new(-128, 0), true)
+    );
 }
 
 // Signed Integers //
 
+#[test]
+fn euclid() {
+    let v = Vec2i::new(-7, 7);
+
+    assert_eq!(v.div_euclid((2, -2)), Vec2i::new(-4, -3));
+    assert_eq!(v.rem_euclid((2, -2)), Vec2i::new(1, 1));
+    assert_eq!(
+        Vec2::new(-0.5, 2.5).div_euclid((2.0, 2.0)),
+        Vec2::new(-1.0, 1.0)
+    );
+    assert_eq!(
+        Vec2::new(-0.5, 2.5).rem_euclid((2.0, 2.0)),
+        Vec2::new(1.5, 0.5)
+    );
+}
+
+#[test]
+fn wrap() {
+    let v = Vec2i::new(-1, 10);
+
+    assert_eq!(v.wrap((0, 0), (10, 10)), Vec2i::new(9, 0));
+    assert_eq!(
+        Vec2i::new(5, -25).wrap((-10, -10), (10, 10)),
+        Vec2i::new(5, -5)
+    );
+    assert_eq!(
+        Vec2::new(-0.25, 3.5).wrap((0.0, 1.0), (1.0, 2.0)),
+        Vec2::new(0.75, 1.5)
+    );
+}
+
 #[test]
 fn perp() {
     let v = Vec2i::new(4, 6);
@@ -189,6 +599,33 @@ fn rem() {
     assert_eq!(v1, out);
 }
 
+#[test]
+fn scalar_lhs() {
+    let v = Vec2::new(2.0, 4.0);
+
+    assert_eq!(2.0 * v, v * 2.0);
+    assert_eq!(1.0 + v, Vec2::new(3.0, 5.0));
+    assert_eq!(8.0 - v, Vec2::new(6.0, 4.0));
+    assert_eq!(8.0 / v, Vec2::new(4.0, 2.0));
+    assert_eq!(5 % Vec2i::new(2, 3), Vec2i::new(1, 2));
+    assert_eq!(1 << Vec2u::new(1, 2), Vec2u::new(2, 4));
+}
+
+#[test]
+fn operators_into() {
+    let mut v = Vec2::new(1.0, 2.0);
+
+    assert_eq!(v + (1.0, 2.0), Vec2::new(2.0, 4.0));
+    assert_eq!(v * [2.0, 3.0], Vec2::new(2.0, 6.0));
+    assert_eq!(Vec2i::new(7, 9) % (4, 5), Vec2i::new(3, 4));
+
+    v -= (1.0, 1.0);
+    assert_eq!(v, Vec2::new(0.0, 1.0));
+
+    v /= [1.0, 2.0];
+    assert_eq!(v, Vec2::new(0.0, 0.5));
+}
+
 #[test]
 fn neg() {
     let v = Vec2i::new(5, -8);
@@ -231,6 +668,37 @@ fn conv_array() {
     assert_eq!(a1, a2);
 }
 
+#[test]
+fn deref() {
+    let mut v = Vec2i::new(3, -4);
+
+    assert_eq!(v[0], 3);
+    assert_eq!(v.len(), 2);
+    assert!(v.contains(&-4));
+    assert_eq!(v.iter().sum::<isize>(), -1);
+    assert_eq!(*v, [3, -4]);
+
+    v[1] = 7;
+    v.swap(0, 1);
+    assert_eq!(v, Vec2i::new(7, 3));
+
+    for c in v.iter_mut() {
+        *c *= 2;
+    }
+    assert_eq!(v, Vec2i::new(14, 6));
+}
+
+#[test]
+fn conv_slice() {
+    let components = [1.0, 2.0, 3.0];
+
+    assert_eq!(Vec2::try_from(&components[1..]), Ok(Vec2::new(2.0, 3.0)));
+    assert_eq!(
+        Vec2::try_from(&components[..1]),
+        Err(crate::SliceLengthError { found: 1 })
+    );
+}
+
 #[test]
 fn conv_std_vec() {
     let std_vec1: Vec<f32> = vec![4.0, 19.0];
@@ -250,6 +718,68 @@ fn conv_std_vec_err() {
     Vec2::try_from(std_vec).unwrap();
 }
 
+#[test]
+fn from_str() {
+    let out = Vec2::new(1.5, 2.0);
+
+    assert_eq!("(1.5, 2)".parse::<Vec2>(), Ok(out));
+    assert_eq!("1.5,2".parse::<Vec2>(), Ok(out));
+    assert_eq!("  1.5 2 ".parse::<Vec2>(), Ok(out));
+    assert_eq!(out.to_string().parse::<Vec2>(), Ok(out));
+    assert_eq!("-3, 4".parse::<Vec2i>(), Ok(Vec2i::new(-3, 4)));
+}
+
+#[test]
+fn from_str_err() {
+    assert_eq!("1".parse::<Vec2>(), Err(ParseVec2Error::WrongCount(1)));
+    assert_eq!(
+        "(1, 2, 3)".parse::<Vec2>(),
+        Err(ParseVec2Error::WrongCount(3))
+    );
+    assert_eq!(
+        "1, x".parse::<Vec2>(),
+        Err(ParseVec2Error::InvalidComponent("x".to_string()))
+    );
+    assert_eq!(
+        "-1, 2".parse::<Vec2u8>(),
+        Err(ParseVec2Error::InvalidComponent("-1".to_string()))
+    );
+    assert_eq!(
+        "Vec2i32(1, 2)".parse::<Vec2f32>(),
+        Err(ParseVec2Error::WrongName("Vec2i32".to_string()))
+    );
+    assert_eq!(
+        "garbage (1, 2)".parse::<Vec2f32>(),
+        Err(ParseVec2Error::WrongName("garbage".to_string()))
+    );
+    assert_eq!(
+        "Vec2f32 (1, 2)".parse::<Vec2f32>(),
+        Ok(Vec2f32::new(1.0, 2.0))
+    );
+}
+
+// Vector2 Trait //
+
+fn scaled_sum<V: crate::Vector2<S>, S: Copy>(vs: &[V], scale: S) -> V {
+    vs.iter()
+        .fold(V::splat(scale) - V::splat(scale), |acc, &v| acc + v * scale)
+}
+
+#[test]
+fn vector2_trait() {
+    let v: Vec2i32 = crate::Vector2::new(3, -4);
+
+    assert_eq!(crate::Vector2::x(&v), 3);
+    assert_eq!(crate::Vector2::y(&v), -4);
+    assert_eq!(crate::Vector2::mag2(&v), 25);
+    assert_eq!(crate::Vector2::to_array(&v), [3, -4]);
+    assert_eq!(
+        scaled_sum(&[Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)], 0.5),
+        Vec2::new(2.0, 3.0)
+    );
+    assert_eq!(scaled_sum(&[Vec2u8::new(1, 2)], 3), Vec2u8::new(3, 6));
+}
+
 // Other //
 
 #[test]
@@ -259,12 +789,47 @@ fn display() {
     assert_eq!(format!("{}", v), "Vec2f32(4.1, 8.8)");
 }
 
+#[test]
+fn display_flags() {
+    let v = Vec2f32::new(4.125, -8.0);
+
+    assert_eq!(format!("{:.2}", v), "Vec2f32(4.12, -8.00)");
+    assert_eq!(format!("{:*<4}", Vec2u8::new(1, 20)), "Vec2u8(1***, 20**)");
+    assert_eq!(format!("{:#}", v), "4.125, -8");
+    assert_eq!(format!("{:#.1}", v), "4.1, -8.0");
+}
+
+#[test]
+fn exp() {
+    let v = Vec2f64::new(0.00012, 34000.0);
+
+    assert_eq!(format!("{:e}", v), "Vec2f64(1.2e-4, 3.4e4)");
+    assert_eq!(format!("{:E}", v), "Vec2f64(1.2E-4, 3.4E4)");
+    assert_eq!(format!("{:#.2e}", v), "1.20e-4, 3.40e4");
+}
+
+#[test]
+fn radix() {
+    let v = Vec2u16::new(0xbeef, 10);
+
+    assert_eq!(format!("{:x}", v), "Vec2u16(beef, a)");
+    assert_eq!(format!("{:X}", v), "Vec2u16(BEEF, A)");
+    assert_eq!(format!("{:o}", Vec2u8::new(8, 9)), "Vec2u8(10, 11)");
+    assert_eq!(
+        format!("{:#06b}", Vec2i8::new(5, -1)),
+        "Vec2i8(0b0101, 0b11111111)"
+    );
+    assert_eq!(format!("{:#x}", v), "Vec2u16(0xbeef, 0xa)");
+    assert_eq!(format!("{:04X}", v.bare()), "BEEF, 000A");
+    assert_eq!(format!("{:#o}", Vec2u8::new(8, 9).bare()), "0o10, 0o11");
+}
+
 #[test]
 fn debug() {
     let v = Vec2f32::new(7.4, 3.9);
 
     // Output may change (because derived), but it
     // should at least still work
-    format!("{:?}", v);
-    format!("{:#?}", v);
+    let _ = format!("{:?}", v);
+    let _ = format!("{:#?}", v);
 }
@@ -0,0 +1,42 @@
+use crate::macroed::*;
+
+#[test]
+fn new_normalizes() {
+    let pos = WorldPos::<10>::new(Vec2i32::new(2, 2), Vec2::new(25.0, -5.0));
+
+    assert_eq!(pos.chunk, Vec2i32::new(4, 1));
+    assert_eq!(pos.local, Vec2::new(5.0, 5.0));
+}
+
+#[test]
+fn world_round_trip() {
+    let world = Vec2d::new(-1_000_000.5, 3_000_000_000.25);
+    let pos = WorldPos::<32>::from_world(world);
+
+    assert!(pos.local.x >= 0.0 && pos.local.x < 32.0);
+    assert!(pos.local.y >= 0.0 && pos.local.y < 32.0);
+    assert_eq!(pos.to_world(), world);
+}
+
+#[test]
+fn add_across_chunks() {
+    let mut pos: WorldPos = WorldPos::new(Vec2i32::new(0, 0), Vec2::new(31.5, 0.5));
+
+    pos += Vec2::new(1.0, -1.0);
+
+    assert_eq!(pos.chunk, Vec2i32::new(1, -1));
+    assert_eq!(pos.local, Vec2::new(0.5, 31.5));
+
+    pos -= Vec2::new(1.0, -1.0);
+
+    assert_eq!(pos.chunk, Vec2i32::new(0, 0));
+    assert_eq!(pos.local, Vec2::new(31.5, 0.5));
+}
+
+#[test]
+fn sub_far_from_origin() {
+    let a = WorldPos::<32>::new(Vec2i32::new(100_000_000, 0), Vec2::new(0.25, 0.0));
+    let b = WorldPos::<32>::new(Vec2i32::new(99_999_999, 0), Vec2::new(31.75, 0.0));
+
+    assert_eq!(a - b, Vec2::new(0.5, 0.0));
+}
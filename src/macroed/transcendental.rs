@@ -0,0 +1,112 @@
+use super::math::Transcendental;
+use super::*;
+
+/// Implements component-wise versions of the scalar math functions.
+///
+/// These go through [`Transcendental`], so they give bit-identical results everywhere with the
+/// `deterministic` feature.
+macro_rules! impl_transcendental_methods {
+    ($name:ident, $type_:ident) => {
+        impl $name {
+            /// Returns the sine of each component, in radians.
+            #[inline]
+            pub fn sin(self) -> Self {
+                $name::new(Transcendental::sin(self.x), Transcendental::sin(self.y))
+            }
+
+            /// Returns the cosine of each component, in radians.
+            #[inline]
+            pub fn cos(self) -> Self {
+                $name::new(Transcendental::cos(self.x), Transcendental::cos(self.y))
+            }
+
+            /// Returns the tangent of each component, in radians.
+            #[inline]
+            pub fn tan(self) -> Self {
+                $name::new(Transcendental::tan(self.x), Transcendental::tan(self.y))
+            }
+
+            /// Returns the sine and cosine of each component, in radians, which is faster than
+            /// calling [`sin`](Self::sin) and [`cos`](Self::cos) separately.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+            #[doc = concat!("let (sin, cos) = ", stringify!($name), "::new(0.0, 0.0).sin_cos();")]
+            ///
+            #[doc = concat!("assert_eq!(sin, ", stringify!($name), "::new(0.0, 0.0));")]
+            #[doc = concat!("assert_eq!(cos, ", stringify!($name), "::new(1.0, 1.0));")]
+            /// ```
+            #[inline]
+            pub fn sin_cos(self) -> (Self, Self) {
+                let (x_sin, x_cos) = Transcendental::sin_cos(self.x);
+                let (y_sin, y_cos) = Transcendental::sin_cos(self.y);
+
+                ($name::new(x_sin, y_sin), $name::new(x_cos, y_cos))
+            }
+
+            /// Returns the cube root of each component, which is negative for negative
+            /// components.
+            #[inline]
+            pub fn cbrt(self) -> Self {
+                $name::new(Transcendental::cbrt(self.x), Transcendental::cbrt(self.y))
+            }
+
+            /// Returns `e` raised to the power of each component.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+            #[doc = concat!("let v = ", stringify!($name), "::new(0.0, 1.0).exp();")]
+            ///
+            /// assert_eq!(v.x, 1.0);
+            #[doc = concat!("assert!((v.y - std::", stringify!($type_), "::consts::E).abs() < 1e-6);")]
+            /// ```
+            #[inline]
+            pub fn exp(self) -> Self {
+                $name::new(Transcendental::exp(self.x), Transcendental::exp(self.y))
+            }
+
+            /// Returns the natural logarithm of each component.
+            #[inline]
+            pub fn ln(self) -> Self {
+                $name::new(Transcendental::ln(self.x), Transcendental::ln(self.y))
+            }
+
+            /// Returns the logarithm of each component in the given base.
+            ///
+            /// This is computed as `ln(x) / ln(base)`, so [`log2`](Self::log2) and
+            /// [`log10`](Self::log10) are more accurate for those bases.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+            #[doc = concat!("let v = ", stringify!($name), "::new(1.0, 25.0);")]
+            ///
+            #[doc = concat!("assert_eq!(v.log(5.0), ", stringify!($name), "::new(0.0, 2.0));")]
+            /// ```
+            #[inline]
+            pub fn log(self, base: $type_) -> Self {
+                self.ln() / Transcendental::ln(base)
+            }
+
+            /// Returns the base 2 logarithm of each component.
+            #[inline]
+            pub fn log2(self) -> Self {
+                $name::new(Transcendental::log2(self.x), Transcendental::log2(self.y))
+            }
+
+            /// Returns the base 10 logarithm of each component.
+            #[inline]
+            pub fn log10(self) -> Self {
+                $name::new(Transcendental::log10(self.x), Transcendental::log10(self.y))
+            }
+        }
+    };
+}
+
+impl_transcendental_methods!(Vec2f32, f32);
+impl_transcendental_methods!(Vec2f64, f64);
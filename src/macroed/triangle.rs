@@ -0,0 +1,211 @@
+use std::ops::{Add, Mul};
+
+use super::segment::cross;
+use super::{point_in_triangle, Circle, Segment, Shape2, ShapeRef, Vec2f32};
+
+/// A triangle made of three vertices.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Circle, Triangle, Vec2f32};
+/// let t = Triangle::new(Vec2f32::new(0.0, 0.0), Vec2f32::new(4.0, 0.0), Vec2f32::new(0.0, 3.0));
+///
+/// assert_eq!(t.signed_area(), 6.0);
+/// assert_eq!(t.circumcenter(), Some(Vec2f32::new(2.0, 1.5)));
+/// assert_eq!(t.incenter(), Some(Vec2f32::new(1.0, 1.0)));
+/// assert_eq!(t.circumcircle(), Some(Circle::new(Vec2f32::new(2.0, 1.5), 2.5)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle {
+    /// The first vertex.
+    pub a: Vec2f32,
+    /// The second vertex.
+    pub b: Vec2f32,
+    /// The third vertex.
+    pub c: Vec2f32,
+}
+
+impl Triangle {
+    /// Creates a new Triangle.
+    pub const fn new(a: Vec2f32, b: Vec2f32, c: Vec2f32) -> Self {
+        Triangle { a, b, c }
+    }
+
+    /// Returns the vertices as an array.
+    pub const fn vertices(&self) -> [Vec2f32; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Returns the edges `a` to `b`, `b` to `c`, and `c` to `a`.
+    pub fn edges(&self) -> [Segment; 3] {
+        [
+            Segment::new(self.a, self.b),
+            Segment::new(self.b, self.c),
+            Segment::new(self.c, self.a),
+        ]
+    }
+
+    /// Returns the area of the triangle, which is positive if the vertices go around
+    /// counterclockwise and negative if they go around clockwise.
+    pub fn signed_area(&self) -> f32 {
+        cross(self.b - self.a, self.c - self.a) / 2.0
+    }
+
+    /// Returns true if `point` is inside the triangle or on one of its edges.
+    pub fn contains_point(&self, point: Vec2f32) -> bool {
+        point_in_triangle(point, self.a, self.b, self.c)
+    }
+
+    /// Returns the barycentric weights of `point` in the triangle, or [`None`] if the vertices
+    /// are in a line.
+    ///
+    /// See [`barycentric`] for details.
+    pub fn barycentric(&self, point: Vec2f32) -> Option<[f32; 3]> {
+        barycentric(point, self.a, self.b, self.c)
+    }
+
+    /// Returns the point the same distance from all three vertices, or [`None`] if the vertices
+    /// are in a line.
+    pub fn circumcenter(&self) -> Option<Vec2f32> {
+        let b = self.b - self.a;
+        let c = self.c - self.a;
+        let d = 2.0 * cross(b, c);
+
+        if d == 0.0 {
+            return None;
+        }
+
+        let (b2, c2) = (b.mag2(), c.mag2());
+        let offset = Vec2f32::new(c.y * b2 - b.y * c2, b.x * c2 - c.x * b2) / d;
+
+        Some(self.a + offset)
+    }
+
+    /// Returns the circle passing through all three vertices, or [`None`] if the vertices are in
+    /// a line.
+    ///
+    /// This is the circle used by Delaunay triangulation.
+    pub fn circumcircle(&self) -> Option<Circle> {
+        let center = self.circumcenter()?;
+        Some(Circle::new(center, (self.a - center).mag()))
+    }
+
+    /// Returns the center of the largest circle that fits inside the triangle, or [`None`] if
+    /// the vertices are all the same point.
+    pub fn incenter(&self) -> Option<Vec2f32> {
+        // Each vertex is weighted by the length of the opposite side
+        let la = (self.c - self.b).mag();
+        let lb = (self.a - self.c).mag();
+        let lc = (self.b - self.a).mag();
+        let total = la + lb + lc;
+
+        if total == 0.0 {
+            return None;
+        }
+
+        Some((self.a * la + self.b * lb + self.c * lc) / total)
+    }
+
+    /// Returns the smallest circle containing the whole triangle.
+    ///
+    /// This is the circumcircle for acute triangles, and the circle around the longest side for
+    /// right and obtuse ones.
+    pub fn bounding_circle(&self) -> Circle {
+        let [ab, bc, ca] = self.edges();
+
+        let longest = [ab, bc, ca]
+            .into_iter()
+            .max_by(|x, y| x.delta().mag2().total_cmp(&y.delta().mag2()))
+            .unwrap();
+
+        let around_longest = Circle::new(longest.midpoint(), longest.length() / 2.0);
+
+        if self.vertices().into_iter().all(|v| {
+            // Allow for rounding in the vertices that are exactly on the circle
+            (v - around_longest.center).mag() <= around_longest.radius * (1.0 + f32::EPSILON)
+        }) {
+            around_longest
+        } else {
+            // Acute, so the circumcircle is smaller, and it exists since the vertices aren't
+            // in a line
+            self.circumcircle().unwrap_or(around_longest)
+        }
+    }
+}
+
+/// Returns the weights of `a`, `b`, and `c` that average to `p`, or [`None`] if the vertices are
+/// in a line.
+///
+/// The weights always add up to one. They are all between zero and one when `p` is inside the
+/// triangle, and one of them is negative when it is outside. Pass them to
+/// [`interpolate_barycentric`] to blend values stored at the vertices.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{barycentric, Vec2f32};
+/// let a = Vec2f32::new(0.0, 0.0);
+/// let b = Vec2f32::new(4.0, 0.0);
+/// let c = Vec2f32::new(0.0, 4.0);
+///
+/// assert_eq!(barycentric(Vec2f32::new(1.0, 2.0), a, b, c), Some([0.25, 0.25, 0.5]));
+/// assert_eq!(barycentric(a, a, b, Vec2f32::new(8.0, 0.0)), None);
+/// ```
+pub fn barycentric(p: Vec2f32, a: Vec2f32, b: Vec2f32, c: Vec2f32) -> Option<[f32; 3]> {
+    let area = cross(b - a, c - a);
+
+    if area == 0.0 {
+        return None;
+    }
+
+    // Each weight is the area of the triangle opposite its vertex, relative to the whole
+    let wb = cross(p - a, c - a) / area;
+    let wc = cross(b - a, p - a) / area;
+
+    Some([1.0 - wb - wc, wb, wc])
+}
+
+/// Blends the values at the three vertices of a triangle using weights from [`barycentric`].
+///
+/// This works with anything that can be scaled by an `f32`, like colors, texture coordinates, or
+/// vectors.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{barycentric, interpolate_barycentric, Vec2f32};
+/// let a = Vec2f32::new(0.0, 0.0);
+/// let b = Vec2f32::new(4.0, 0.0);
+/// let c = Vec2f32::new(0.0, 4.0);
+///
+/// let weights = barycentric(Vec2f32::new(1.0, 2.0), a, b, c).unwrap();
+/// let heights = [10.0, 20.0, 40.0];
+///
+/// assert_eq!(interpolate_barycentric(weights, heights), 27.5);
+/// ```
+pub fn interpolate_barycentric<T>(weights: [f32; 3], values: [T; 3]) -> T
+where
+    T: Mul<f32, Output = T> + Add<Output = T>,
+{
+    let [va, vb, vc] = values;
+    va * weights[0] + vb * weights[1] + vc * weights[2]
+}
+
+impl Shape2 for Triangle {
+    fn as_shape(&self) -> ShapeRef<'_> {
+        ShapeRef::Triangle(*self)
+    }
+
+    fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f32 {
+        self.edges().iter().map(Segment::length).sum()
+    }
+
+    fn centroid(&self) -> Vec2f32 {
+        (self.a + self.b + self.c) / 3.0
+    }
+}
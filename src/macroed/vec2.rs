@@ -1,13 +1,52 @@
-use std::fmt;
-use std::fmt::Formatter;
-use std::ops::*;
-
-/// A macro for creating [`Vec2`] structs.
+/// Creates a Vec2 struct with components of type `$type_`.
+///
+/// This is the macro that defines every vector in [`macroed`](crate::macroed), and it can be
+/// used to create vectors for other scalar types, such as fixed-point numbers or unit-of-measure
+/// newtypes. Use [`add_vec2_feature!`](crate::add_vec2_feature) afterwards to add the methods that depend on the kind of
+/// number.
+///
+/// The vector gets [`new`](crate::macroed::Vec2f32::new), `get` and `set` by
+/// [`Axis2`](crate::macroed::Axis2), `mag2`, `dot`, `max`, `min`, `clamp`, the arithmetic
+/// operators with scalars and anything that converts into the vector (such as tuples and
+/// arrays), [`Deref`](std::ops::Deref) to `[$type_; 2]`, conversions to and from tuples, arrays,
+/// and [`Vec`]s, conversions from slices, [`Display`](std::fmt::Display),
+/// [`FromStr`](std::str::FromStr), and [`Vector2`](crate::Vector2).
+///
+/// The scalar type must be [`Copy`], [`Debug`](std::fmt::Debug), [`PartialEq`],
+/// [`Display`](std::fmt::Display), and [`FromStr`](std::str::FromStr), must support the
+/// arithmetic operators and their assigning versions, and must have `max` and `min` methods that
+/// take another scalar by value.
+///
+/// # Stability
+///
+/// The invocation syntax and the requirements on the scalar type follow semantic versioning.
+/// New methods may be added to generated vectors in minor releases, like any other type in this
+/// crate.
+///
+/// # Example
+///
+/// ```
+/// use manyvecs::{add_vec2_feature, create_vec2};
+///
+/// create_vec2!(
+///     /// A position on the map, in meters.
+///     MapPos,
+///     f32
+/// );
+/// add_vec2_feature!(MapPos, f32, "floating");
+///
+/// let a = MapPos::new(3.0, 4.0);
+///
+/// assert_eq!(a.mag(), 5.0);
+/// assert_eq!(a + MapPos::new(1.0, 1.0), MapPos::new(4.0, 5.0));
+/// ```
+#[macro_export]
 macro_rules! create_vec2 {
     // Match a name and a type, optionally taking documentation and metadata.
     ($(#[$meta:meta])* $name:ident, $type_:ty) => {
         $(#[$meta])*
         #[derive(Copy, Clone, Debug)]
+        #[repr(C)]
         pub struct $name {
             pub x: $type_,
             pub y: $type_,
@@ -22,9 +61,49 @@ macro_rules! create_vec2 {
                 }
             }
 
+            /// Returns the component along the given axis.
+            pub fn get(&self, axis: $crate::macroed::Axis2) -> $type_ {
+                match axis {
+                    $crate::macroed::Axis2::X => self.x,
+                    $crate::macroed::Axis2::Y => self.y,
+                }
+            }
+
+            /// Sets the component along the given axis.
+            pub fn set(&mut self, axis: $crate::macroed::Axis2, v: $type_) {
+                *self.axis_mut(axis) = v;
+            }
+
+            /// Returns a mutable reference to the component along the given axis.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::{Axis2, Vec2f32};
+            /// let mut v = Vec2f32::new(1.0, 2.0);
+            ///
+            /// for axis in Axis2::ALL {
+            ///     *v.axis_mut(axis) *= 10.0;
+            /// }
+            ///
+            /// assert_eq!(v, Vec2f32::new(10.0, 20.0));
+            /// ```
+            pub fn axis_mut(&mut self, axis: $crate::macroed::Axis2) -> &mut $type_ {
+                match axis {
+                    $crate::macroed::Axis2::X => &mut self.x,
+                    $crate::macroed::Axis2::Y => &mut self.y,
+                }
+            }
+
             /// Returns `x` squared plus `y` squared. (`x^2 + y^2` where `^` represents an exponent.)
             pub fn mag2(&self) -> $type_ {
-                self.x * self.x + self.y * self.y
+                $crate::simd_or!(@scalar $type_, mag2(*self), self.x * self.x + self.y * self.y)
+            }
+
+            /// Returns the dot product of two vectors, `x1 * x2 + y1 * y2`.
+            pub fn dot<V: Into<Self>>(&self, other: V) -> $type_ {
+                let other: Self = other.into();
+                self.x * other.x + self.y * other.y
             }
 
             /// Returns the larger of each number from a given Vec2.
@@ -42,10 +121,7 @@ macro_rules! create_vec2 {
             pub fn max<V: Into<Self>>(&self, other: V) -> Self {
                 let other: Self = other.into();
 
-                Self::new(
-                    self.x.max(other.x),
-                    self.y.max(other.y),
-                )
+                Self::new(self.x.max(other.x), self.y.max(other.y))
             }
 
             /// Returns the smaller of each number from a given Vec2.
@@ -63,10 +139,7 @@ macro_rules! create_vec2 {
             pub fn min<V: Into<Self>>(&self, other: V) -> Self {
                 let other: Self = other.into();
 
-                Self::new(
-                    self.x.min(other.x),
-                    self.y.min(other.y),
-                )
+                Self::new(self.x.min(other.x), self.y.min(other.y))
             }
 
             /// Constrains the values of a vector to be between the min and the max.
@@ -95,17 +168,35 @@ macro_rules! create_vec2 {
             }
         }
 
+        impl $crate::Vector2<$type_> for $name {
+            fn new(x: $type_, y: $type_) -> Self {
+                $name::new(x, y)
+            }
+
+            fn x(&self) -> $type_ {
+                self.x
+            }
+
+            fn y(&self) -> $type_ {
+                self.y
+            }
+
+            fn mag2(&self) -> $type_ {
+                $name::mag2(self)
+            }
+        }
+
         // Operators //
 
         // All vectors should at least support these operators
-        apply_operator!($name, $type_, Add, add, +, AddAssign, add_assign, +=);
-        apply_operator!($name, $type_, Sub, sub, -, SubAssign, sub_assign, -=);
-        apply_operator!($name, $type_, Mul, mul, *, MulAssign, mul_assign, *=);
-        apply_operator!($name, $type_, Div, div, /, DivAssign, div_assign, /=);
-        apply_operator!($name, $type_, Rem, rem, %, RemAssign, rem_assign, %=);
+        $crate::apply_operator!($name, $type_, Add, add, +, AddAssign, add_assign, +=);
+        $crate::apply_operator!($name, $type_, Sub, sub, -, SubAssign, sub_assign, -=);
+        $crate::apply_operator!($name, $type_, Mul, mul, *, MulAssign, mul_assign, *=);
+        $crate::apply_operator!($name, $type_, Div, div, /, DivAssign, div_assign, /=);
+        $crate::apply_operator!($name, $type_, Rem, rem, %, RemAssign, rem_assign, %=);
 
         // And all vectors should be able to use `==`
-        impl std::cmp::PartialEq<Self> for $name {
+        impl ::std::cmp::PartialEq<Self> for $name {
             fn eq(&self, other: &Self) -> bool {
                 self.x == other.x && self.y == other.y
             }
@@ -150,8 +241,38 @@ macro_rules! create_vec2 {
             }
         }
 
+        // The vector can be used as an array, so slice methods like `iter` and indexing work
+        impl ::std::ops::Deref for $name {
+            type Target = [$type_; 2];
+
+            fn deref(&self) -> &[$type_; 2] {
+                // SAFETY: The vector is `#[repr(C)]` with exactly two fields of type `$type_`, so
+                // it has the same layout as `[$type_; 2]`.
+                unsafe { &*(self as *const Self).cast::<[$type_; 2]>() }
+            }
+        }
+
+        impl ::std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut [$type_; 2] {
+                // SAFETY: See `deref`.
+                unsafe { &mut *(self as *mut Self).cast::<[$type_; 2]>() }
+            }
+        }
+
+        // Slice
+        impl ::std::convert::TryFrom<&[$type_]> for $name {
+            type Error = $crate::SliceLengthError;
+
+            fn try_from(s: &[$type_]) -> Result<$name, Self::Error> {
+                match s {
+                    [x, y] => Ok(Self::new(*x, *y)),
+                    _ => Err($crate::SliceLengthError { found: s.len() }),
+                }
+            }
+        }
+
         // Vec
-        impl TryFrom<Vec<$type_>> for $name {
+        impl ::std::convert::TryFrom<Vec<$type_>> for $name {
             type Error = String;
 
             fn try_from(v: Vec<$type_>) -> Result<$name, Self::Error> {
@@ -171,14 +292,87 @@ macro_rules! create_vec2 {
 
         // Other Compatability //
 
-        impl fmt::Display for $name {
-            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-                write!(f, concat!(stringify!($name), "({}, {})"), self.x, self.y)
+        $crate::impl_format!($name, Display);
+
+        /// Parses a vector from two numbers separated by a comma or whitespace, optionally
+        /// wrapped in parentheses. This accepts `"1, 2"`, `"1 2"`, `"(1, 2)"`, and the output of
+        /// [`Display`](::std::fmt::Display) such as `"Vec2f32(1, 2)"`. A name before the
+        /// parentheses must be this vector's name.
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::macroed::ParseVec2Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut s = s.trim();
+
+                // Strip the parentheses, and this vector's name if it comes before them
+                if let (Some(start), Some(inner)) = (s.find('('), s.strip_suffix(')')) {
+                    let prefix = s[..start].trim_end();
+
+                    if !prefix.is_empty() && prefix != stringify!($name) {
+                        return Err($crate::macroed::ParseVec2Error::WrongName(prefix.to_string()));
+                    }
+
+                    s = &inner[start + 1..];
+                }
+
+                let parts: Vec<&str> = if s.contains(',') {
+                    s.split(',').map(str::trim).collect()
+                } else {
+                    s.split_whitespace().collect()
+                };
+
+                if parts.len() != 2 {
+                    return Err($crate::macroed::ParseVec2Error::WrongCount(parts.len()));
+                }
+
+                let parse = |part: &str| {
+                    part.parse::<$type_>()
+                        .map_err(|_| $crate::macroed::ParseVec2Error::InvalidComponent(part.to_string()))
+                };
+
+                Ok(Self::new(parse(parts[0])?, parse(parts[1])?))
             }
         }
     };
 }
 
+/// Adds methods and traits to a vector created with [`create_vec2!`](crate::create_vec2), depending on the kind of
+/// number it stores.
+///
+/// The features are:
+///
+/// - `"floating"`: `mag`, `mag_precise`, `mul_add`, `norm`, `floor`, `ceil`, and exponent
+///   formatting. This includes `"signed"`. The scalar type must implement
+///   [`Real`](crate::macroed::Real), [`LowerExp`](std::fmt::LowerExp), and
+///   [`UpperExp`](std::fmt::UpperExp), and have `floor` and `ceil` methods.
+/// - `"signed"`: `perp`, Manhattan and Chebyshev distances, `div_euclid`, `rem_euclid`, and
+///   negation. The scalar type must implement [`Neg`](std::ops::Neg) and
+///   [`Magnitude`](crate::macroed::Magnitude), and have `div_euclid` and `rem_euclid` methods.
+/// - `"integer"`: `overflowing_*` arithmetic, checked Manhattan distances, grid neighbors,
+///   `line_to`, [`Eq`], and [`Hash`](std::hash::Hash). This includes `"bitwise"`, and only
+///   supports primitive integers.
+/// - `"unsigned"`: Manhattan and Chebyshev distances for primitive unsigned integers, which
+///   can't use `"signed"`.
+/// - `"bitwise"`: the bitwise and shift operators, and binary, octal, and hex formatting.
+///
+/// See [`create_vec2!`](crate::create_vec2) for stability guarantees.
+///
+/// # Example
+///
+/// ```
+/// use manyvecs::{add_vec2_feature, create_vec2};
+///
+/// create_vec2!(TileCoord, i16);
+/// add_vec2_feature!(TileCoord, i16, "integer");
+/// add_vec2_feature!(TileCoord, i16, "signed");
+///
+/// let tile = TileCoord::new(2, -3);
+///
+/// assert_eq!(tile.manhattan_length(), 5);
+/// assert_eq!(tile.neighbors4().count(), 4);
+/// assert_eq!(-tile, TileCoord::new(-2, 3));
+/// ```
+#[macro_export]
 macro_rules! add_vec2_feature {
     ($name:ident, $type_:ty, "floating") => {
         impl $name {
@@ -187,14 +381,87 @@ macro_rules! add_vec2_feature {
             /// This is equivalent to the [Pythagorean Theorem](https://en.wikipedia.org/wiki/Pythagorean_theorem),
             /// so it returns `sqrt(x^2 + y^2)` where `^` signifies an exponent.
             pub fn mag(&self) -> $type_ {
-                self.mag2().sqrt()
+                $crate::macroed::Real::sqrt(self.mag2())
+            }
+
+            /// Returns the magnitude of a vector, like [`mag`](Self::mag), but without
+            /// overflowing for huge components or losing precision for tiny ones.
+            ///
+            /// This is slower than `mag`, so prefer that unless the components can be very large
+            /// or very small.
+            pub fn mag_precise(&self) -> $type_ {
+                $crate::macroed::Real::hypot(self.x, self.y)
+            }
+
+            /// Returns `self * a + b` component-wise, computing each component with a single
+            /// rounding error instead of two.
+            ///
+            /// This can be faster than separate multiplication and addition on targets with
+            /// fused multiply-add instructions, and slower on those without.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let v = Vec2f32::new(2.0, 3.0);
+            ///
+            /// assert_eq!(v.mul_add(Vec2f32::new(4.0, 5.0), (1.0, 1.0)), Vec2f32::new(9.0, 16.0));
+            /// ```
+            pub fn mul_add<A: Into<Self>, B: Into<Self>>(&self, a: A, b: B) -> Self {
+                let a: Self = a.into();
+                let b: Self = b.into();
+
+                Self::new(
+                    $crate::macroed::Real::mul_add(self.x, a.x, b.x),
+                    $crate::macroed::Real::mul_add(self.y, a.y, b.y),
+                )
             }
 
             /// Normalizes a vector so that its magnitude is 0.
             pub fn norm(&self) -> Self {
-                // Figure out way to find 1.09
-                let r = 1.0 / self.mag();
-                Self::new(self.x * r, self.y * r)
+                let m = self.mag();
+                Self::new(self.x / m, self.y / m)
+            }
+
+            /// Returns the absolute difference of each component.
+            pub fn abs_diff<V: Into<Self>>(&self, other: V) -> Self {
+                let other: Self = other.into();
+                Self::new((self.x - other.x).abs(), (self.y - other.y).abs())
+            }
+
+            /// Returns `true` if each component differs from `other`'s by at most `epsilon`.
+            ///
+            /// Vectors with NaN components are never approximately equal.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let v = Vec2f32::new(1.0, 2.0);
+            ///
+            /// assert!(v.approx_eq((1.0001, 1.9999), 1e-3));
+            /// assert!(!v.approx_eq((1.1, 2.0), 1e-3));
+            /// ```
+            pub fn approx_eq<V: Into<Self>>(&self, other: V, epsilon: $type_) -> bool {
+                let diff = self.abs_diff(other);
+                diff.x <= epsilon && diff.y <= epsilon
+            }
+
+            /// Returns the square root of each component.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let variance = Vec2f32::new(4.0, 0.25);
+            ///
+            /// assert_eq!(variance.sqrt(), Vec2f32::new(2.0, 0.5));
+            /// ```
+            pub fn sqrt(&self) -> Self {
+                Self::new(
+                    $crate::macroed::Real::sqrt(self.x),
+                    $crate::macroed::Real::sqrt(self.y),
+                )
             }
 
             /// Returns a vector where the `x` and `y` values are rounded down.
@@ -206,10 +473,288 @@ macro_rules! add_vec2_feature {
             pub fn ceil(&self) -> Self {
                 Self::new(self.x.ceil(), self.y.ceil())
             }
+
+            /// Linearly interpolates between `self` at `t = 0` and `other` at `t = 1`, like
+            /// GLSL's `mix`.
+            ///
+            /// `t` can be a scalar, or a vector to blend each axis by a different amount.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let a = Vec2f32::new(0.0, 10.0);
+            /// let b = Vec2f32::new(4.0, 20.0);
+            ///
+            /// assert_eq!(a.mix(b, 0.25), Vec2f32::new(1.0, 12.5));
+            /// assert_eq!(a.mix(b, (0.5, 1.0)), Vec2f32::new(2.0, 20.0));
+            /// ```
+            pub fn mix<V: Into<Self>, T>(&self, other: V, t: T) -> Self
+            where
+                Self: ::std::ops::Mul<T, Output = Self>,
+            {
+                *self + (other.into() - *self) * t
+            }
+
+            /// Returns the vector if it points against `incident` according to `reference`, or
+            /// the negated vector otherwise, like GLSL's `faceforward`.
+            ///
+            /// This is usually called on a surface normal, with the surface's geometric normal
+            /// as `reference`, to make the normal face the incoming ray.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let normal = Vec2f32::new(0.0, 1.0);
+            ///
+            /// // A ray coming down hits the top of the surface
+            /// assert_eq!(normal.faceforward((0.0, -1.0), normal), normal);
+            /// // A ray coming up hits the bottom
+            /// assert_eq!(normal.faceforward((0.0, 1.0), normal), -normal);
+            /// ```
+            pub fn faceforward<I: Into<Self>, R: Into<Self>>(&self, incident: I, reference: R) -> Self {
+                if reference.into().dot(incident) < <$type_ as $crate::macroed::Real>::ZERO {
+                    *self
+                } else {
+                    -*self
+                }
+            }
+
+            /// Reflects the vector off a surface with the given normal, like GLSL's `reflect`.
+            ///
+            /// The normal should be normalized for the result to keep the vector's magnitude.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let ray = Vec2f32::new(1.0, -1.0);
+            ///
+            /// assert_eq!(ray.reflect((0.0, 1.0)), Vec2f32::new(1.0, 1.0));
+            /// ```
+            pub fn reflect<V: Into<Self>>(&self, normal: V) -> Self {
+                let normal: Self = normal.into();
+                let d = normal.dot(*self);
+
+                *self - normal * (d + d)
+            }
+
+            /// Refracts the vector through a surface with the given normal, like GLSL's
+            /// `refract`.
+            ///
+            /// `eta` is the ratio of the refractive indices, from the side the vector comes from
+            /// to the side it enters. Both the vector and the normal should be normalized.
+            /// Returns the zero vector on total internal reflection.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let ray = Vec2f32::new(0.6, -0.8);
+            /// let normal = Vec2f32::new(0.0, 1.0);
+            ///
+            /// // Going straight through when the indices match
+            /// assert_eq!(ray.refract(normal, 1.0), ray);
+            /// // Too shallow to leave a dense medium
+            /// assert_eq!(ray.refract(normal, 2.0), Vec2f32::new(0.0, 0.0));
+            /// ```
+            pub fn refract<V: Into<Self>>(&self, normal: V, eta: $type_) -> Self {
+                let normal: Self = normal.into();
+                let zero = <$type_ as $crate::macroed::Real>::ZERO;
+                let one = <$type_ as $crate::macroed::Real>::ONE;
+
+                let d = normal.dot(*self);
+                let k = one - eta * eta * (one - d * d);
+
+                if k < zero {
+                    Self::new(zero, zero)
+                } else {
+                    *self * eta - normal * (eta * d + $crate::macroed::Real::sqrt(k))
+                }
+            }
+
+            /// Returns the remainder of dividing by `rhs`, computed as `x - rhs * floor(x / rhs)`
+            /// like GLSL's `mod`.
+            ///
+            /// Unlike `%`, the result has the sign of `rhs`, so it is never negative for a
+            /// positive `rhs`. This is what wraps texture coordinates and angles.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let uv = Vec2f32::new(-0.25, 1.5);
+            ///
+            /// assert_eq!(uv.mod_floored((1.0, 1.0)), Vec2f32::new(0.75, 0.5));
+            /// assert_eq!(uv % Vec2f32::new(1.0, 1.0), Vec2f32::new(-0.25, 0.5));
+            /// ```
+            pub fn mod_floored<V: Into<Self>>(&self, rhs: V) -> Self {
+                let rhs: Self = rhs.into();
+                *self - rhs * (*self / rhs).floor()
+            }
+
+            /// Clamps each component to the range `[0, 1]`, like HLSL's `saturate`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let uv = Vec2f32::new(-0.5, 1.25);
+            ///
+            /// assert_eq!(uv.saturate(), Vec2f32::new(0.0, 1.0));
+            /// ```
+            pub fn saturate(&self) -> Self {
+                let zero = <$type_ as $crate::macroed::Real>::ZERO;
+                let one = <$type_ as $crate::macroed::Real>::ONE;
+
+                self.clamp((zero, zero), (one, one))
+            }
+
+            /// Linearly maps each component from the range `in_min..in_max` to the range
+            /// `out_min..out_max`.
+            ///
+            /// Components outside the input range are extrapolated, not clamped. The result is
+            /// undefined if `in_min` and `in_max` are equal.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let screen = Vec2f32::new(400.0, 150.0);
+            ///
+            /// // From an 800x600 window to normalized device coordinates
+            /// let ndc = screen.remap((0.0, 600.0), (800.0, 0.0), (-1.0, -1.0), (1.0, 1.0));
+            ///
+            /// assert_eq!(ndc, Vec2f32::new(0.0, 0.5));
+            /// ```
+            pub fn remap<A, B, C, D>(&self, in_min: A, in_max: B, out_min: C, out_max: D) -> Self
+            where
+                A: Into<Self>,
+                B: Into<Self>,
+                C: Into<Self>,
+                D: Into<Self>,
+            {
+                let (in_min, in_max): (Self, Self) = (in_min.into(), in_max.into());
+                let (out_min, out_max): (Self, Self) = (out_min.into(), out_max.into());
+
+                out_min + (*self - in_min) / (in_max - in_min) * (out_max - out_min)
+            }
+
+            /// Rounds each component to the nearest multiple of the grid's cell size, with
+            /// halfway cases rounded up.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let cursor = Vec2f32::new(37.0, -12.0);
+            ///
+            /// assert_eq!(cursor.snap((16.0, 16.0)), Vec2f32::new(32.0, -16.0));
+            /// assert_eq!(cursor.snap_floor((16.0, 16.0)), Vec2f32::new(32.0, -16.0));
+            /// assert_eq!(cursor.snap_ceil((16.0, 16.0)), Vec2f32::new(48.0, 0.0));
+            /// ```
+            pub fn snap<V: Into<Self>>(&self, grid: V) -> Self {
+                let grid: Self = grid.into();
+                let one = <$type_ as $crate::macroed::Real>::ONE;
+                let half = one / (one + one);
+
+                (*self / grid + half).floor() * grid
+            }
+
+            /// Rounds each component down to a multiple of the grid's cell size.
+            pub fn snap_floor<V: Into<Self>>(&self, grid: V) -> Self {
+                let grid: Self = grid.into();
+                (*self / grid).floor() * grid
+            }
+
+            /// Rounds each component up to a multiple of the grid's cell size.
+            pub fn snap_ceil<V: Into<Self>>(&self, grid: V) -> Self {
+                let grid: Self = grid.into();
+                (*self / grid).ceil() * grid
+            }
+
+            /// Reflects each component back and forth within `[0, length]`, like the mirrored
+            /// repeat texture addressing mode.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let t = Vec2f32::new(1.5, -0.25);
+            ///
+            /// assert_eq!(t.ping_pong((1.0, 1.0)), Vec2f32::new(0.5, 0.25));
+            /// assert_eq!(Vec2f32::new(2.5, 4.0).ping_pong((1.0, 1.0)), Vec2f32::new(0.5, 0.0));
+            /// ```
+            pub fn ping_pong<V: Into<Self>>(&self, length: V) -> Self {
+                let length: Self = length.into();
+                let t = self.rem_euclid(length + length);
+
+                length - t.abs_diff(length)
+            }
+
+            /// Returns `0` for each component less than `edge`, and `1` otherwise, like GLSL's
+            /// `step`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let v = Vec2f32::new(0.2, 0.7);
+            ///
+            /// assert_eq!(v.step((0.5, 0.5)), Vec2f32::new(0.0, 1.0));
+            /// assert_eq!(v.step((0.1, 0.8)), Vec2f32::new(1.0, 0.0));
+            /// ```
+            pub fn step<V: Into<Self>>(&self, edge: V) -> Self {
+                let edge: Self = edge.into();
+                let step = |x: $type_, edge: $type_| {
+                    if x < edge {
+                        <$type_ as $crate::macroed::Real>::ZERO
+                    } else {
+                        <$type_ as $crate::macroed::Real>::ONE
+                    }
+                };
+
+                Self::new(step(self.x, edge.x), step(self.y, edge.y))
+            }
+
+            /// Smoothly interpolates each component from `0` at `edge0` to `1` at `edge1` with
+            /// a cubic Hermite curve, like GLSL's `smoothstep`.
+            ///
+            /// Components outside the edges are clamped to `0` or `1`. The result is undefined
+            /// if the edges are equal.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2f32;
+            /// let v = Vec2f32::new(-1.0, 0.5);
+            ///
+            /// assert_eq!(v.smoothstep((0.0, 0.0), (1.0, 1.0)), Vec2f32::new(0.0, 0.5));
+            /// assert_eq!(v.smoothstep((-2.0, 0.0), (0.0, 2.0)), Vec2f32::new(0.5, 0.15625));
+            /// ```
+            pub fn smoothstep<A: Into<Self>, B: Into<Self>>(&self, edge0: A, edge1: B) -> Self {
+                let (edge0, edge1): (Self, Self) = (edge0.into(), edge1.into());
+                let smoothstep = |x: $type_, edge0: $type_, edge1: $type_| {
+                    let zero = <$type_ as $crate::macroed::Real>::ZERO;
+                    let one = <$type_ as $crate::macroed::Real>::ONE;
+                    let t = ((x - edge0) / (edge1 - edge0)).max(zero).min(one);
+
+                    t * t * (one + one + one - (one + one) * t)
+                };
+
+                Self::new(
+                    smoothstep(self.x, edge0.x, edge1.x),
+                    smoothstep(self.y, edge0.y, edge1.y),
+                )
+            }
         }
 
+        $crate::impl_format!($name, LowerExp);
+        $crate::impl_format!($name, UpperExp);
+
         // Floating point numbers are usually signed
-        add_vec2_feature!($name, $type_, "signed");
+        $crate::add_vec2_feature!($name, $type_, "signed");
     };
     ($name:ident, $type_:ty, "signed") => {
         impl $name {
@@ -217,9 +762,172 @@ macro_rules! add_vec2_feature {
             pub fn perp(&self) -> Self {
                 Self::new(-self.y, self.x)
             }
+
+            /// Returns the Manhattan (taxicab) length of a vector, which is `|x| + |y|`.
+            ///
+            /// Signed integer vectors return the unsigned type, which holds the magnitude of
+            /// `MIN`.
+            ///
+            /// # Overflow
+            ///
+            /// For integer vectors the sum can overflow, such as at `(MIN, MIN)`. Like the
+            /// arithmetic operators, this panics in debug builds and wraps in release builds. Integer
+            /// vectors can use `checked_manhattan_length` to catch it.
+            pub fn manhattan_length(
+                &self,
+            ) -> <$type_ as $crate::macroed::Magnitude>::Output {
+                $crate::macroed::Magnitude::magnitude(self.x)
+                    + $crate::macroed::Magnitude::magnitude(self.y)
+            }
+
+            /// Returns the Manhattan (taxicab) distance between two vectors, which is the number
+            /// of orthogonal grid steps between them.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2i32;
+            /// let a = Vec2i32::new(1, -2);
+            ///
+            /// assert_eq!(a.manhattan_distance((4, 2)), 7);
+            /// ```
+            ///
+            /// # Overflow
+            ///
+            /// For integer vectors the sum can overflow, such as from `(MIN, MIN)` to `(MAX, MAX)`. Like the
+            /// arithmetic operators, this panics in debug builds and wraps in release builds. Integer
+            /// vectors can use `checked_manhattan_distance` to catch it.
+            pub fn manhattan_distance<V: Into<Self>>(
+                &self,
+                other: V,
+            ) -> <$type_ as $crate::macroed::Magnitude>::Output {
+                let other: Self = other.into();
+
+                // Subtracting first could overflow for signed integers
+                $crate::macroed::Magnitude::magnitude_diff(self.x, other.x)
+                    + $crate::macroed::Magnitude::magnitude_diff(self.y, other.y)
+            }
+
+            /// Returns the Chebyshev length of a vector, which is the larger of `|x|` and `|y|`.
+            ///
+            /// Signed integer vectors return the unsigned type, which holds the magnitude of
+            /// `MIN`.
+            pub fn chebyshev_length(&self) -> <$type_ as $crate::macroed::Magnitude>::Output {
+                let x = $crate::macroed::Magnitude::magnitude(self.x);
+                let y = $crate::macroed::Magnitude::magnitude(self.y);
+
+                if x >= y {
+                    x
+                } else {
+                    y
+                }
+            }
+
+            /// Returns the axis with the larger absolute component, preferring
+            /// [`X`](crate::macroed::Axis2::X) on ties.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::{Axis2, Vec2f32};
+            /// let stick = Vec2f32::new(0.3, -0.8);
+            ///
+            /// assert_eq!(stick.major_axis(), Axis2::Y);
+            /// assert_eq!(stick.abs_max_component(), 0.8);
+            /// ```
+            pub fn major_axis(&self) -> $crate::macroed::Axis2 {
+                if $crate::macroed::Magnitude::magnitude(self.x)
+                    >= $crate::macroed::Magnitude::magnitude(self.y)
+                {
+                    $crate::macroed::Axis2::X
+                } else {
+                    $crate::macroed::Axis2::Y
+                }
+            }
+
+            /// Returns the larger of `|x|` and `|y|`, which is the component along
+            /// [`major_axis`](Self::major_axis) without its sign.
+            pub fn abs_max_component(&self) -> <$type_ as $crate::macroed::Magnitude>::Output {
+                self.chebyshev_length()
+            }
+
+            /// Returns the Chebyshev distance between two vectors, which is the number of steps
+            /// between them when diagonal moves are allowed (like a king in chess).
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2i32;
+            /// let a = Vec2i32::new(1, -2);
+            ///
+            /// assert_eq!(a.chebyshev_distance((4, 2)), 4);
+            /// ```
+            pub fn chebyshev_distance<V: Into<Self>>(
+                &self,
+                other: V,
+            ) -> <$type_ as $crate::macroed::Magnitude>::Output {
+                let other: Self = other.into();
+
+                // Subtracting first could overflow for signed integers
+                let x = $crate::macroed::Magnitude::magnitude_diff(self.x, other.x);
+                let y = $crate::macroed::Magnitude::magnitude_diff(self.y, other.y);
+
+                if x >= y {
+                    x
+                } else {
+                    y
+                }
+            }
+
+            /// Divides each component using Euclidean division, rounding the quotient so that
+            /// the matching [`rem_euclid`](Self::rem_euclid) is never negative.
+            ///
+            /// This is what converts world coordinates into chunk or tile coordinates, where `/`
+            /// would round negative coordinates the wrong way.
+            pub fn div_euclid<V: Into<Self>>(&self, rhs: V) -> Self {
+                let rhs: Self = rhs.into();
+                Self::new(self.x.div_euclid(rhs.x), self.y.div_euclid(rhs.y))
+            }
+
+            /// Returns the non-negative remainder of Euclidean division for each component.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2i32;
+            /// let world = Vec2i32::new(-1, 17);
+            ///
+            /// assert_eq!(world.div_euclid((16, 16)), Vec2i32::new(-1, 1));
+            /// assert_eq!(world.rem_euclid((16, 16)), Vec2i32::new(15, 1));
+            /// assert_eq!(world % Vec2i32::new(16, 16), Vec2i32::new(-1, 1));
+            /// ```
+            pub fn rem_euclid<V: Into<Self>>(&self, rhs: V) -> Self {
+                let rhs: Self = rhs.into();
+                Self::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+            }
+
+            /// Wraps each component into the half-open range from `min` up to `max`, so leaving
+            /// one side re-enters from the other like on a torus.
+            ///
+            /// `max` must be greater than `min` on both axes. Because of rounding, a float
+            /// component just below `min` can wrap to exactly `max`, like with
+            /// [`f32::rem_euclid`].
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2i32;
+            /// let ship = Vec2i32::new(-1, 105);
+            ///
+            /// assert_eq!(ship.wrap((0, 0), (100, 100)), Vec2i32::new(99, 5));
+            /// ```
+            pub fn wrap<A: Into<Self>, B: Into<Self>>(&self, min: A, max: B) -> Self {
+                let (min, max): (Self, Self) = (min.into(), max.into());
+                min + (*self - min).rem_euclid(max - min)
+            }
         }
 
-        impl Neg for $name {
+        impl ::std::ops::Neg for $name {
             type Output = Self;
 
             fn neg(self) -> Self {
@@ -227,49 +935,496 @@ macro_rules! add_vec2_feature {
             }
         }
     };
+    ($name:ident, $type_:ty, "integer") => {
+        impl $name {
+            /// The offsets to the 8 cells around a grid cell, counterclockwise starting from
+            /// `+x`. Every other offset is one of the 4 orthogonal neighbors.
+            const NEIGHBOR_OFFSETS: [(i8, i8); 8] = [
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+            ];
+
+            $crate::apply_overflowing!($name, overflowing_add, "Adds");
+            $crate::apply_overflowing!($name, overflowing_sub, "Subtracts");
+            $crate::apply_overflowing!($name, overflowing_mul, "Multiplies");
+
+            /// Returns the Manhattan (taxicab) length of a vector, or [`None`] if it doesn't fit in
+            /// the magnitude type.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::{Vec2i32, Vec2u8};
+            /// assert_eq!(Vec2u8::new(100, 50).checked_manhattan_length(), Some(150));
+            /// assert_eq!(Vec2u8::new(200, 200).checked_manhattan_length(), None);
+            /// assert_eq!(Vec2i32::new(i32::MIN, 0).checked_manhattan_length(), Some(1 << 31));
+            /// ```
+            pub fn checked_manhattan_length(
+                &self,
+            ) -> Option<<$type_ as $crate::macroed::Magnitude>::Output> {
+                $crate::macroed::Magnitude::magnitude(self.x)
+                    .checked_add($crate::macroed::Magnitude::magnitude(self.y))
+            }
+
+            /// Returns the Manhattan (taxicab) distance between two vectors, or [`None`] if it
+            /// doesn't fit in the magnitude type.
+            pub fn checked_manhattan_distance<V: Into<Self>>(
+                &self,
+                other: V,
+            ) -> Option<<$type_ as $crate::macroed::Magnitude>::Output> {
+                let other: Self = other.into();
+
+                $crate::macroed::Magnitude::magnitude_diff(self.x, other.x)
+                    .checked_add($crate::macroed::Magnitude::magnitude_diff(self.y, other.y))
+            }
+
+            /// Returns the 4 cells sharing an edge with this one, counterclockwise starting from
+            /// `+x`.
+            ///
+            /// Neighbors that don't fit in the component type are skipped, so `(0, 0)` only has
+            /// two neighbors for unsigned vectors.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2i32;
+            /// let cells: Vec<_> = Vec2i32::new(0, 0).neighbors4().collect();
+            ///
+            /// assert_eq!(cells, [
+            ///     Vec2i32::new(1, 0),
+            ///     Vec2i32::new(0, 1),
+            ///     Vec2i32::new(-1, 0),
+            ///     Vec2i32::new(0, -1),
+            /// ]);
+            /// ```
+            pub fn neighbors4(&self) -> impl Iterator<Item = Self> {
+                let v = *self;
+
+                Self::NEIGHBOR_OFFSETS
+                    .iter()
+                    .step_by(2)
+                    .filter_map(move |&(dx, dy)| v.offset_by(dx, dy))
+            }
+
+            /// Returns the 8 cells sharing an edge or corner with this one, counterclockwise
+            /// starting from `+x`.
+            ///
+            /// Neighbors that don't fit in the component type are skipped.
+            pub fn neighbors8(&self) -> impl Iterator<Item = Self> {
+                let v = *self;
+
+                Self::NEIGHBOR_OFFSETS
+                    .iter()
+                    .filter_map(move |&(dx, dy)| v.offset_by(dx, dy))
+            }
+
+            /// Returns the same cells as [`neighbors4`](Self::neighbors4), skipping any outside
+            /// of a grid from `(0, 0)` up to but not including `size`.
+            pub fn neighbors4_within<V: Into<Self>>(&self, size: V) -> impl Iterator<Item = Self> {
+                let size: Self = size.into();
+                self.neighbors4().filter(move |n| n.is_within(size))
+            }
+
+            /// Returns the same cells as [`neighbors8`](Self::neighbors8), skipping any outside
+            /// of a grid from `(0, 0)` up to but not including `size`.
+            pub fn neighbors8_within<V: Into<Self>>(&self, size: V) -> impl Iterator<Item = Self> {
+                let size: Self = size.into();
+                self.neighbors8().filter(move |n| n.is_within(size))
+            }
+
+            /// Returns the grid cells along a line from this cell to `other`, including both
+            /// ends, using Bresenham's line algorithm.
+            ///
+            /// Consecutive cells always share an edge or a corner. This works across the whole
+            /// range of the type, e.g. from `i128::MIN` to `i128::MAX`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2i32;
+            /// let cells: Vec<_> = Vec2i32::new(0, 0).line_to((3, 1)).collect();
+            ///
+            /// assert_eq!(cells, [
+            ///     Vec2i32::new(0, 0),
+            ///     Vec2i32::new(1, 0),
+            ///     Vec2i32::new(2, 1),
+            ///     Vec2i32::new(3, 1),
+            /// ]);
+            /// ```
+            pub fn line_to<V: Into<Self>>(&self, other: V) -> impl Iterator<Item = Self> {
+                let other: Self = other.into();
+
+                // Walk the longer axis one cell at a time, and step the shorter axis whenever
+                // the running remainder wraps. Everything stays in the native type, since the
+                // remainder never reaches the longer span and no cell leaves the two ends.
+                let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+                let x_major = dx >= dy;
+                let (major, minor) = if x_major { (dx, dy) } else { (dy, dx) };
+                let toward = |c: $type_, end: $type_| if c < end { c + 1 } else { c - 1 };
+
+                let mut cell = *self;
+                let mut remaining = major;
+                let mut rem = major / 2;
+                let mut done = false;
+
+                ::std::iter::from_fn(move || {
+                    if done {
+                        return None;
+                    }
+
+                    let current = cell;
+
+                    if remaining == 0 {
+                        done = true;
+                        return Some(current);
+                    }
+
+                    remaining -= 1;
+
+                    let step_minor = rem >= major - minor;
+                    rem = if step_minor { rem - (major - minor) } else { rem + minor };
+
+                    if x_major {
+                        cell.x = toward(cell.x, other.x);
+                        if step_minor {
+                            cell.y = toward(cell.y, other.y);
+                        }
+                    } else {
+                        cell.y = toward(cell.y, other.y);
+                        if step_minor {
+                            cell.x = toward(cell.x, other.x);
+                        }
+                    }
+
+                    Some(current)
+                })
+            }
+
+            /// Moves by a small offset, or returns [`None`] if the result doesn't fit.
+            fn offset_by(&self, dx: i8, dy: i8) -> Option<Self> {
+                let step = |c: $type_, d: i8| match d {
+                    -1 => c.checked_sub(1),
+                    1 => c.checked_add(1),
+                    _ => Some(c),
+                };
+
+                Some(Self::new(step(self.x, dx)?, step(self.y, dy)?))
+            }
+
+            /// Returns true if both components are in `0..size`.
+            fn is_within(&self, size: Self) -> bool {
+                (0..size.x).contains(&self.x) && (0..size.y).contains(&self.y)
+            }
+        }
+
+        // Integers can be compared exactly, so they can be used as keys
+        impl ::std::cmp::Eq for $name {}
+
+        impl ::std::hash::Hash for $name {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.x.hash(state);
+                self.y.hash(state);
+            }
+        }
+
+        // All integers support bitwise operators
+        $crate::add_vec2_feature!($name, $type_, "bitwise");
+    };
+    ($name:ident, $type_:ty, "unsigned") => {
+        impl $name {
+            /// Returns the Manhattan (taxicab) length of a vector, which is `x + y`.
+            ///
+            /// # Overflow
+            ///
+            /// The sum can overflow, such as at `(MAX, MAX)`. Like the
+            /// arithmetic operators, this panics in debug builds and wraps in release builds. Use
+            /// [`checked_manhattan_length`](Self::checked_manhattan_length) to catch it.
+            pub fn manhattan_length(&self) -> $type_ {
+                self.x + self.y
+            }
+
+            /// Returns the axis with the larger component, preferring
+            /// [`X`](crate::macroed::Axis2::X) on ties.
+            pub fn major_axis(&self) -> $crate::macroed::Axis2 {
+                if self.x >= self.y {
+                    $crate::macroed::Axis2::X
+                } else {
+                    $crate::macroed::Axis2::Y
+                }
+            }
+
+            /// Returns the larger of `x` and `y`.
+            pub fn abs_max_component(&self) -> $type_ {
+                self.x.max(self.y)
+            }
+
+            /// Returns the Manhattan (taxicab) distance between two vectors, which is the number
+            /// of orthogonal grid steps between them.
+            ///
+            /// # Overflow
+            ///
+            /// The sum can overflow, such as from `(0, 0)` to `(MAX, MAX)`. Like the
+            /// arithmetic operators, this panics in debug builds and wraps in release builds. Use
+            /// [`checked_manhattan_distance`](Self::checked_manhattan_distance) to catch it.
+            pub fn manhattan_distance<V: Into<Self>>(&self, other: V) -> $type_ {
+                let other: Self = other.into();
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            /// Returns the absolute difference of each component, without underflowing like
+            /// `self - other` would.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2u32;
+            /// let a = Vec2u32::new(3, 10);
+            ///
+            /// assert_eq!(a.abs_diff((5, 4)), Vec2u32::new(2, 6));
+            /// ```
+            pub fn abs_diff<V: Into<Self>>(&self, other: V) -> Self {
+                let other: Self = other.into();
+                Self::new(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+            }
+
+            /// Returns the Chebyshev length of a vector, which is the larger of `x` and `y`.
+            pub fn chebyshev_length(&self) -> $type_ {
+                self.x.max(self.y)
+            }
+
+            /// Returns the Chebyshev distance between two vectors, which is the number of steps
+            /// between them when diagonal moves are allowed (like a king in chess).
+            pub fn chebyshev_distance<V: Into<Self>>(&self, other: V) -> $type_ {
+                let other: Self = other.into();
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+            }
+        }
+    };
     ($name:ident, $type_:ty, "bitwise") => {
-        apply_operator!($name, $type_, BitAnd, bitand, &, BitAndAssign, bitand_assign, &=);
-        apply_operator!($name, $type_, BitOr, bitor, |, BitOrAssign, bitor_assign, |=);
-        apply_operator!($name, $type_, BitXor, bitxor, ^, BitXorAssign, bitxor_assign, ^=);
-        apply_operator!($name, $type_, Shl, shl, <<, ShlAssign, shl_assign, <<=);
-        apply_operator!($name, $type_, Shr, shr, >>, ShrAssign, shr_assign, >>=);
+        $crate::apply_operator!($name, $type_, BitAnd, bitand, &, BitAndAssign, bitand_assign, &=);
+        $crate::apply_operator!($name, $type_, BitOr, bitor, |, BitOrAssign, bitor_assign, |=);
+        $crate::apply_operator!($name, $type_, BitXor, bitxor, ^, BitXorAssign, bitxor_assign, ^=);
+        $crate::apply_operator!($name, $type_, Shl, shl, <<, ShlAssign, shl_assign, <<=);
+        $crate::apply_operator!($name, $type_, Shr, shr, >>, ShrAssign, shr_assign, >>=);
+
+        $crate::impl_format!($name, Binary, "radix");
+        $crate::impl_format!($name, Octal, "radix");
+        $crate::impl_format!($name, LowerHex, "radix");
+        $crate::impl_format!($name, UpperHex, "radix");
+
+        impl $name {
+            /// Returns a view of this vector that formats as just `x, y` in binary, octal, or
+            /// hexadecimal, without the name around it.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use manyvecs::macroed::Vec2u16;
+            /// let v = Vec2u16::new(0xbeef, 10);
+            ///
+            /// assert_eq!(format!("{:#x}", v), "Vec2u16(0xbeef, 0xa)");
+            /// assert_eq!(format!("{:x}", v.bare()), "beef, a");
+            /// ```
+            pub fn bare(
+                &self,
+            ) -> impl ::std::fmt::Binary
+                   + ::std::fmt::Octal
+                   + ::std::fmt::LowerHex
+                   + ::std::fmt::UpperHex
+                   + '_ {
+                struct Bare<'a>(&'a $name);
+
+                $crate::impl_format!(Bare, Binary, "bare");
+                $crate::impl_format!(Bare, Octal, "bare");
+                $crate::impl_format!(Bare, LowerHex, "bare");
+                $crate::impl_format!(Bare, UpperHex, "bare");
+
+                Bare(self)
+            }
+        }
+    };
+}
+
+/// Implements an `overflowing_*` method by applying the scalar version to each component.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! apply_overflowing {
+    ($name:ident, $fn_name:ident, $verb:literal) => {
+        #[doc = concat!($verb, " two vectors component-wise, wrapping around on overflow.")]
+        ///
+        /// Returns the result along with `true` if either component overflowed, like the scalar
+        #[doc = concat!("`", stringify!($fn_name), "`.")]
+        pub fn $fn_name<V: Into<Self>>(&self, other: V) -> (Self, bool) {
+            let other: Self = other.into();
+            let (x, x_overflow) = self.x.$fn_name(other.x);
+            let (y, y_overflow) = self.y.$fn_name(other.y);
+
+            (Self::new(x, y), x_overflow || y_overflow)
+        }
     };
 }
 
+/// Implements a formatting trait by applying it to each component.
+///
+/// Vectors are formatted as `Vec2f32(x, y)`, or as just `x, y` with the alternate flag (`{:#}`).
+/// Precision, width, and fill are applied to each component.
+///
+/// The radix traits instead pass the alternate flag on to each component, where it adds the
+/// usual `0x`-style prefix, so they are always wrapped in the name. Their bare form comes from a
+/// vector's `bare()` method.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_format {
+    ($name:ident, $trait_name:ident) => {
+        impl ::std::fmt::$trait_name for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                if !f.alternate() {
+                    write!(f, concat!(stringify!($name), "("))?;
+                }
+
+                ::std::fmt::$trait_name::fmt(&self.x, f)?;
+                write!(f, ", ")?;
+                ::std::fmt::$trait_name::fmt(&self.y, f)?;
+
+                if !f.alternate() {
+                    write!(f, ")")?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+    ($name:ident, $trait_name:ident, "radix") => {
+        impl ::std::fmt::$trait_name for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, concat!(stringify!($name), "("))?;
+                ::std::fmt::$trait_name::fmt(&self.x, f)?;
+                write!(f, ", ")?;
+                ::std::fmt::$trait_name::fmt(&self.y, f)?;
+                write!(f, ")")
+            }
+        }
+    };
+    // Formats the components of a wrapped vector without its name.
+    ($bare:ident, $trait_name:ident, "bare") => {
+        impl ::std::fmt::$trait_name for $bare<'_> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::$trait_name::fmt(&self.0.x, f)?;
+                write!(f, ", ")?;
+                ::std::fmt::$trait_name::fmt(&self.0.y, f)
+            }
+        }
+    };
+}
+
+/// Implements an operator and its assigning version, for both vectors and scalars.
+#[doc(hidden)]
+#[macro_export]
 macro_rules! apply_operator {
     ($name:ident, $type_:ty, $trait_name:ident, $trait_fn:ident, $op:tt, $atrait_name:ident, $atrait_fn:ident, $aop:tt) => {
-        impl $trait_name<Self> for $name {
+        impl<V: Into<Self>> ::std::ops::$trait_name<V> for $name {
             type Output = Self;
 
-            fn $trait_fn(self, rhs: Self) -> Self {
-                Self::new(self.x $op rhs.x, self.y $op rhs.y)
+            fn $trait_fn(self, rhs: V) -> Self {
+                let rhs: Self = rhs.into();
+                $crate::simd_or!($type_, $trait_fn(self, rhs), Self::new(self.x $op rhs.x, self.y $op rhs.y))
             }
         }
 
-        impl $trait_name<$type_> for $name {
+        impl ::std::ops::$trait_name<$type_> for $name {
             type Output = Self;
 
             fn $trait_fn(self, rhs: $type_) -> Self {
-                Self::new(self.x $op rhs, self.y $op rhs)
+                $crate::simd_or!(
+                    $type_,
+                    $trait_fn(self, Self::new(rhs, rhs)),
+                    Self::new(self.x $op rhs, self.y $op rhs)
+                )
+            }
+        }
+
+        // Scalar on the left, like GLSL's `2.0 * v`
+        impl ::std::ops::$trait_name<$name> for $type_ {
+            type Output = $name;
+
+            fn $trait_fn(self, rhs: $name) -> $name {
+                $name::new(self $op rhs.x, self $op rhs.y)
             }
         }
 
-        impl $atrait_name<Self> for $name {
-            fn $atrait_fn(&mut self, rhs: Self) {
-                self.x $aop rhs.x;
-                self.y $aop rhs.y;
+        impl<V: Into<Self>> ::std::ops::$atrait_name<V> for $name {
+            fn $atrait_fn(&mut self, rhs: V) {
+                let rhs: Self = rhs.into();
+                $crate::simd_or!($type_, $trait_fn(*self, rhs) => *self, {
+                    self.x $aop rhs.x;
+                    self.y $aop rhs.y;
+                });
             }
         }
 
-        impl $atrait_name<$type_> for $name {
+        impl ::std::ops::$atrait_name<$type_> for $name {
             fn $atrait_fn(&mut self, rhs: $type_) {
-                self.x $aop rhs;
-                self.y $aop rhs;
+                $crate::simd_or!($type_, $trait_fn(*self, Self::new(rhs, rhs)) => *self, {
+                    self.x $aop rhs;
+                    self.y $aop rhs;
+                });
             }
         }
     };
 }
 
+/// Uses the SSE2 version of an operation for vectors of [`f32`]s and [`f64`]s, or evaluates the
+/// fallback for every other scalar type.
+///
+/// The `=> $place` form assigns the SIMD result to `$place` instead of returning it, and the
+/// `@scalar` form is for operations that return a single scalar.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! simd_or {
+    (@scalar $type_:ty, $fn_name:ident($($arg:expr),*), $fallback:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::macroed::simd::Fallback as _;
+
+        match $crate::macroed::simd::Lanes::<$type_>::NEW.$fn_name($($crate::simd_or!(@lanes $arg)),*) {
+            Some(result) => result,
+            None => $fallback,
+        }
+    }};
+    ($type_:ty, $fn_name:ident($($arg:expr),*) $(=> $place:expr)?, $fallback:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::macroed::simd::Fallback as _;
+
+        match $crate::macroed::simd::Lanes::<$type_>::NEW.$fn_name($($crate::simd_or!(@lanes $arg)),*) {
+            Some([x, y]) => $($place =)? Self::new(x, y),
+            None => $fallback,
+        }
+    }};
+    (@lanes $arg:expr) => {{
+        let v: Self = $arg;
+        [v.x, v.y]
+    }};
+}
+
+/// Always evaluates the fallback, as SIMD is disabled or unsupported on this target.
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! simd_or {
+    (@scalar $type_:ty, $fn_name:ident($($arg:expr),*), $fallback:expr) => {
+        $fallback
+    };
+    ($type_:ty, $fn_name:ident($($arg:expr),*) $(=> $place:expr)?, $fallback:expr) => {
+        $fallback
+    };
+}
+
 // Floats
 create_vec2!(
     /// A Vec2 containing [`f32`]s.
@@ -299,6 +1454,67 @@ create_vec2!(
 );
 add_vec2_feature!(Vec2f64, f64, "floating");
 
+#[cfg(feature = "extended")]
+create_vec2!(
+    /// A Vec2 containing [`DoubleDouble`](super::DoubleDouble)s, for when [`f64`] is not precise
+    /// enough.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::{DoubleDouble, Vec2dd};
+    /// let _ = Vec2dd::new(DoubleDouble::from(-2.0), DoubleDouble::from(3.0));
+    /// ```
+    Vec2dd,
+    super::DoubleDouble
+);
+#[cfg(feature = "extended")]
+add_vec2_feature!(Vec2dd, super::DoubleDouble, "floating");
+
+#[cfg(feature = "half")]
+create_vec2!(
+    /// A Vec2 containing half-precision [`f16`](half::f16)s, mainly for storing GPU vertex data.
+    ///
+    /// Half-precision numbers only have about 3 decimal digits of precision, so do math with a
+    /// [`Vec2f32`] and convert to a Vec2f16 when storing the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::{Vec2f16, Vec2f32};
+    /// let v = Vec2f16::from(Vec2f32::new(0.5, -2.0));
+    ///
+    /// assert_eq!(Vec2f32::from(v), Vec2f32::new(0.5, -2.0));
+    /// ```
+    Vec2f16,
+    half::f16
+);
+
+#[cfg(feature = "fixed")]
+create_vec2!(
+    /// A Vec2 containing 16.16 fixed-point numbers, which have 16 integer and 16 fractional
+    /// bits.
+    ///
+    /// Fixed-point arithmetic gives identical results on every platform, which makes this
+    /// useful for deterministic lockstep simulations. Operations panic on overflow in debug
+    /// builds, like primitive integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use manyvecs::macroed::Vec2Fixed;
+    /// use fixed::types::I16F16;
+    ///
+    /// let v = Vec2Fixed::new(I16F16::from_num(3), I16F16::from_num(4));
+    ///
+    /// assert_eq!(v.mag(), 5);
+    /// ```
+    Vec2Fixed,
+    fixed::types::I16F16
+);
+#[cfg(feature = "fixed")]
+add_vec2_feature!(Vec2Fixed, fixed::types::I16F16, "floating");
+
 // Unsigned ints
 create_vec2!(
     /// A Vec2 containing [`u8`]s.
@@ -312,7 +1528,8 @@ create_vec2!(
     Vec2u8,
     u8
 );
-add_vec2_feature!(Vec2u8, u8, "bitwise");
+add_vec2_feature!(Vec2u8, u8, "integer");
+add_vec2_feature!(Vec2u8, u8, "unsigned");
 
 create_vec2!(
     /// A Vec2 containing [`u16`]s.
@@ -326,7 +1543,8 @@ create_vec2!(
     Vec2u16,
     u16
 );
-add_vec2_feature!(Vec2u16, u16, "bitwise");
+add_vec2_feature!(Vec2u16, u16, "integer");
+add_vec2_feature!(Vec2u16, u16, "unsigned");
 
 create_vec2!(
     /// A Vec2 containing [u32`]s.
@@ -340,7 +1558,8 @@ create_vec2!(
     Vec2u32,
     u32
 );
-add_vec2_feature!(Vec2u32, u32, "bitwise");
+add_vec2_feature!(Vec2u32, u32, "integer");
+add_vec2_feature!(Vec2u32, u32, "unsigned");
 
 create_vec2!(
     /// A Vec2 containing [`u64`]s.
@@ -354,7 +1573,8 @@ create_vec2!(
     Vec2u64,
     u64
 );
-add_vec2_feature!(Vec2u64, u64, "bitwise");
+add_vec2_feature!(Vec2u64, u64, "integer");
+add_vec2_feature!(Vec2u64, u64, "unsigned");
 
 create_vec2!(
     /// A Vec2 containing [`u128`]s.
@@ -368,7 +1588,8 @@ create_vec2!(
     Vec2u128,
     u128
 );
-add_vec2_feature!(Vec2u128, u128, "bitwise");
+add_vec2_feature!(Vec2u128, u128, "integer");
+add_vec2_feature!(Vec2u128, u128, "unsigned");
 
 create_vec2!(
     /// A Vec2 containing [`usize`]s.
@@ -382,7 +1603,8 @@ create_vec2!(
     Vec2usize,
     usize
 );
-add_vec2_feature!(Vec2usize, usize, "bitwise");
+add_vec2_feature!(Vec2usize, usize, "integer");
+add_vec2_feature!(Vec2usize, usize, "unsigned");
 
 // Signed ints
 create_vec2!(
@@ -397,7 +1619,7 @@ create_vec2!(
     Vec2i8,
     i8
 );
-add_vec2_feature!(Vec2i8, i8, "bitwise");
+add_vec2_feature!(Vec2i8, i8, "integer");
 add_vec2_feature!(Vec2i8, i8, "signed");
 
 create_vec2!(
@@ -412,7 +1634,7 @@ create_vec2!(
     Vec2i16,
     i16
 );
-add_vec2_feature!(Vec2i16, i16, "bitwise");
+add_vec2_feature!(Vec2i16, i16, "integer");
 add_vec2_feature!(Vec2i16, i16, "signed");
 
 create_vec2!(
@@ -427,7 +1649,7 @@ create_vec2!(
     Vec2i32,
     i32
 );
-add_vec2_feature!(Vec2i32, i32, "bitwise");
+add_vec2_feature!(Vec2i32, i32, "integer");
 add_vec2_feature!(Vec2i32, i32, "signed");
 
 create_vec2!(
@@ -442,7 +1664,7 @@ create_vec2!(
     Vec2i64,
     i64
 );
-add_vec2_feature!(Vec2i64, i64, "bitwise");
+add_vec2_feature!(Vec2i64, i64, "integer");
 add_vec2_feature!(Vec2i64, i64, "signed");
 
 create_vec2!(
@@ -457,7 +1679,7 @@ create_vec2!(
     Vec2i128,
     i128
 );
-add_vec2_feature!(Vec2i128, i128, "bitwise");
+add_vec2_feature!(Vec2i128, i128, "integer");
 add_vec2_feature!(Vec2i128, i128, "signed");
 
 create_vec2!(
@@ -472,5 +1694,152 @@ create_vec2!(
     Vec2isize,
     isize
 );
-add_vec2_feature!(Vec2isize, isize, "bitwise");
+add_vec2_feature!(Vec2isize, isize, "integer");
 add_vec2_feature!(Vec2isize, isize, "signed");
+
+// The difference between signed integers always fits in the unsigned type of the same size, like
+// the scalar `abs_diff`
+macro_rules! impl_signed_abs_diff {
+    ($($name:ident => $unsigned:ident),*) => {
+        $(
+            impl $name {
+                /// Returns the absolute difference of each component as an unsigned vector,
+                /// which can't overflow.
+                ///
+                /// # Example
+                ///
+                /// ```
+                #[doc = concat!("# use manyvecs::macroed::{", stringify!($name), ", ", stringify!($unsigned), "};")]
+                #[doc = concat!("let a = ", stringify!($name), "::new(-100, 5);")]
+                ///
+                #[doc = concat!("assert_eq!(a.abs_diff((100, 7)), ", stringify!($unsigned), "::new(200, 2));")]
+                /// ```
+                pub fn abs_diff<V: Into<Self>>(&self, other: V) -> $unsigned {
+                    let other: Self = other.into();
+                    $unsigned::new(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_abs_diff!(
+    Vec2i8 => Vec2u8,
+    Vec2i16 => Vec2u16,
+    Vec2i32 => Vec2u32,
+    Vec2i64 => Vec2u64,
+    Vec2i128 => Vec2u128,
+    Vec2isize => Vec2usize
+);
+
+// Euclid's algorithm on two unsigned integers, where the gcd with zero is the other number
+macro_rules! gcd {
+    ($a:expr, $b:expr) => {{
+        let (mut a, mut b) = ($a, $b);
+
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        a
+    }};
+}
+
+// The gcd is found on the unsigned magnitudes, so `MIN` components don't overflow
+macro_rules! impl_primitive_direction {
+    ($($name:ident => $type_:ident),*) => {
+        $(
+            impl $name {
+                /// Divides both components by their greatest common divisor, returning the
+                /// smallest integer step in the same direction.
+                ///
+                /// Two grid offsets point the same way exactly when their primitive directions
+                /// are equal. The zero vector stays zero, and an axis-aligned vector becomes a
+                /// unit vector.
+                ///
+                /// # Example
+                ///
+                /// ```
+                #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+                #[doc = concat!("let slope = ", stringify!($name), "::new(-4, 6);")]
+                ///
+                #[doc = concat!("assert_eq!(slope.primitive_direction(), ", stringify!($name), "::new(-2, 3));")]
+                #[doc = concat!("assert_eq!(", stringify!($name), "::new(0, -5).primitive_direction(), ", stringify!($name), "::new(0, -1));")]
+                /// ```
+                pub fn primitive_direction(&self) -> Self {
+                    let a = gcd!(self.x.unsigned_abs(), self.y.unsigned_abs());
+
+                    if a == 0 {
+                        return *self;
+                    }
+
+                    // The quotient's magnitude only reaches `MIN` when the divisor is one, and
+                    // negating `MIN` wraps back to itself
+                    let divide = |c: $type_| {
+                        let q = (c.unsigned_abs() / a) as $type_;
+                        if c < 0 {
+                            q.wrapping_neg()
+                        } else {
+                            q
+                        }
+                    };
+
+                    Self::new(divide(self.x), divide(self.y))
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_direction!(
+    Vec2i8 => i8,
+    Vec2i16 => i16,
+    Vec2i32 => i32,
+    Vec2i64 => i64,
+    Vec2i128 => i128,
+    Vec2isize => isize
+);
+
+macro_rules! impl_lattice_points {
+    ($($name:ident => $unsigned:ident),*) => {
+        $(
+            impl $name {
+                /// Returns the number of grid points on the segment from this point to `other`,
+                /// including both ends, or [`None`] if the count doesn't fit in
+                #[doc = concat!("[`", stringify!($unsigned), "`].")]
+                ///
+                /// This is `gcd(|dx|, |dy|) + 1`, so a point to itself counts as one. The count
+                /// only overflows when the segment spans the whole range of the component type.
+                ///
+                /// # Example
+                ///
+                /// ```
+                #[doc = concat!("# use manyvecs::macroed::", stringify!($name), ";")]
+                #[doc = concat!("let a = ", stringify!($name), "::new(1, 1);")]
+                ///
+                /// // Passes through (1, 1), (3, 4), and (5, 7)
+                /// assert_eq!(a.lattice_points_between((5, 7)), Some(3));
+                /// ```
+                pub fn lattice_points_between<V: Into<Self>>(&self, other: V) -> Option<$unsigned> {
+                    let diff = self.abs_diff(other);
+                    gcd!(diff.x, diff.y).checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_lattice_points!(
+    Vec2u8 => u8,
+    Vec2u16 => u16,
+    Vec2u32 => u32,
+    Vec2u64 => u64,
+    Vec2u128 => u128,
+    Vec2usize => usize,
+    Vec2i8 => u8,
+    Vec2i16 => u16,
+    Vec2i32 => u32,
+    Vec2i64 => u64,
+    Vec2i128 => u128,
+    Vec2isize => usize
+);
@@ -0,0 +1,125 @@
+use std::ops::*;
+
+use super::{Vec2f32, Vec2f64, Vec2i32};
+
+/// A position in a large world, split into an integer chunk and a small offset within that chunk.
+///
+/// Floats lose precision the further they get from zero, so storing huge coordinates directly
+/// makes movement jittery far from the origin. WorldPos keeps the float part between `0` and
+/// `CHUNK_SIZE`, where it is always precise.
+///
+/// # Example
+///
+/// ```
+/// # use manyvecs::macroed::{Vec2f32, Vec2f64, Vec2i32, WorldPos};
+/// let mut pos = WorldPos::<16>::new(Vec2i32::new(0, 0), Vec2f32::new(15.0, 1.0));
+///
+/// pos += Vec2f32::new(2.0, -2.0);
+///
+/// assert_eq!(pos.chunk, Vec2i32::new(1, -1));
+/// assert_eq!(pos.local, Vec2f32::new(1.0, 15.0));
+/// assert_eq!(pos.to_world(), Vec2f64::new(17.0, -1.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WorldPos<const CHUNK_SIZE: u32 = 32> {
+    /// The chunk that contains this position.
+    pub chunk: Vec2i32,
+    /// The offset from the chunk's corner, always between `0` and `CHUNK_SIZE`.
+    pub local: Vec2f32,
+}
+
+impl<const CHUNK_SIZE: u32> WorldPos<CHUNK_SIZE> {
+    /// Creates a new WorldPos, moving to a neighboring chunk if `local` is outside of `chunk`.
+    pub fn new(chunk: Vec2i32, local: Vec2f32) -> Self {
+        let mut pos = WorldPos { chunk, local };
+        pos.normalize();
+        pos
+    }
+
+    /// Creates a WorldPos from a position in world space.
+    pub fn from_world(world: Vec2f64) -> Self {
+        let size = CHUNK_SIZE as f64;
+        let chunk = Vec2f64::new(world.x.div_euclid(size), world.y.div_euclid(size));
+        let local = world - chunk * size;
+
+        Self::new(
+            Vec2i32::new(chunk.x as i32, chunk.y as i32),
+            Vec2f32::new(local.x as f32, local.y as f32),
+        )
+    }
+
+    /// Returns this position in world space.
+    pub fn to_world(&self) -> Vec2f64 {
+        let size = CHUNK_SIZE as f64;
+
+        Vec2f64::new(
+            self.chunk.x as f64 * size + self.local.x as f64,
+            self.chunk.y as f64 * size + self.local.y as f64,
+        )
+    }
+
+    /// Moves whole chunks out of `local` and into `chunk`.
+    fn normalize(&mut self) {
+        let (chunk_x, local_x) = Self::split(self.local.x);
+        let (chunk_y, local_y) = Self::split(self.local.y);
+
+        self.chunk += Vec2i32::new(chunk_x, chunk_y);
+        self.local = Vec2f32::new(local_x, local_y);
+    }
+
+    fn split(local: f32) -> (i32, f32) {
+        let size = CHUNK_SIZE as f32;
+        let chunks = local.div_euclid(size);
+        let rest = local - chunks * size;
+
+        // Rounding can leave the rest equal to the chunk size, which belongs to the next chunk
+        if rest >= size {
+            (chunks as i32 + 1, 0.0)
+        } else {
+            (chunks as i32, rest)
+        }
+    }
+}
+
+// Operators //
+
+impl<const CHUNK_SIZE: u32> Add<Vec2f32> for WorldPos<CHUNK_SIZE> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2f32) -> Self {
+        Self::new(self.chunk, self.local + rhs)
+    }
+}
+
+impl<const CHUNK_SIZE: u32> AddAssign<Vec2f32> for WorldPos<CHUNK_SIZE> {
+    fn add_assign(&mut self, rhs: Vec2f32) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const CHUNK_SIZE: u32> Sub<Vec2f32> for WorldPos<CHUNK_SIZE> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2f32) -> Self {
+        Self::new(self.chunk, self.local - rhs)
+    }
+}
+
+impl<const CHUNK_SIZE: u32> SubAssign<Vec2f32> for WorldPos<CHUNK_SIZE> {
+    fn sub_assign(&mut self, rhs: Vec2f32) {
+        *self = *self - rhs;
+    }
+}
+
+/// Returns the offset between two positions, which stays precise as long as they are near each
+/// other, no matter how far they are from the origin.
+impl<const CHUNK_SIZE: u32> Sub<Self> for WorldPos<CHUNK_SIZE> {
+    type Output = Vec2f32;
+
+    fn sub(self, rhs: Self) -> Vec2f32 {
+        let chunks = self.chunk - rhs.chunk;
+
+        Vec2f32::new(chunks.x as f32, chunks.y as f32) * CHUNK_SIZE as f32
+            + (self.local - rhs.local)
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// A 2D vector with components of type `Scalar`, implemented by [`legacy::Vec2`] and every
+/// macroed Vec2.
+///
+/// This lets libraries be generic over whichever ManyVecs backend their users enabled. Vectors
+/// support the component-wise operators with other vectors and with a single scalar.
+///
+/// # Example
+///
+/// ```
+/// use manyvecs::Vector2;
+///
+/// fn midpoint<V: Vector2<f32>>(a: V, b: V) -> V {
+///     (a + b) / 2.0
+/// }
+///
+/// # #[cfg(feature = "legacy")]
+/// # {
+/// use manyvecs::legacy::Vec2;
+/// assert_eq!(midpoint(Vec2::new(0.0, 0.0), Vec2::new(2.0, 4.0)), Vec2::new(1.0, 2.0));
+/// # }
+///
+/// # #[cfg(feature = "macroed")]
+/// # {
+/// use manyvecs::macroed::Vec2f32;
+/// assert_eq!(midpoint(Vec2f32::new(0.0, 0.0), Vec2f32::new(2.0, 4.0)), Vec2f32::new(1.0, 2.0));
+/// # }
+/// ```
+///
+/// [`legacy::Vec2`]: crate::legacy::Vec2
+pub trait Vector2<Scalar>:
+    Copy
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Add<Scalar, Output = Self>
+    + Sub<Scalar, Output = Self>
+    + Mul<Scalar, Output = Self>
+    + Div<Scalar, Output = Self>
+    + Rem<Scalar, Output = Self>
+where
+    Scalar: Copy,
+{
+    /// Creates a new vector.
+    fn new(x: Scalar, y: Scalar) -> Self;
+
+    /// Returns the x component.
+    fn x(&self) -> Scalar;
+
+    /// Returns the y component.
+    fn y(&self) -> Scalar;
+
+    /// Returns `x` squared plus `y` squared.
+    fn mag2(&self) -> Scalar;
+
+    /// Creates a vector with both components set to `v`.
+    fn splat(v: Scalar) -> Self {
+        Self::new(v, v)
+    }
+
+    /// Returns the vector as an array of `[x, y]`.
+    fn to_array(&self) -> [Scalar; 2] {
+        [self.x(), self.y()]
+    }
+}